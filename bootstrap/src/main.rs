@@ -93,6 +93,14 @@ pub unsafe extern "C" fn main() {
     #[cfg(feature = "debug_uart_port")]
     writeln!(&mut Uart::E, "Mirage: Ready!").ok();
 
+    // Report memory accesses that were rejected during initialization.
+    #[cfg(feature = "debug_uart_port")]
+    {
+        if let Some(fault) = mirage_libtegra::mc::fault::take_fault() {
+            writeln!(&mut Uart::E, "Mirage: MC fault: {}", fault).ok();
+        }
+    }
+
     // Display backlight PoC for debugging.
     backlight_poc();
 }
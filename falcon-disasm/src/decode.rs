@@ -0,0 +1,819 @@
+//! Decoding of Falcon v5 machine code.
+//!
+//! # Description
+//!
+//! Falcon instructions are variable-length and between 2 and 4 bytes
+//! long. The first byte selects the operand format and, for most
+//! formats, the operation. The other bytes contain the register
+//! nibbles, a sub-opcode and an 8-bit or 16-bit immediate:
+//!
+//! - `R1` is stored in bits 8..12, `R2` in bits 12..16 and `R3` in
+//! bits 20..24 of the instruction.
+//!
+//! - Opcodes below `0xC0` are sized, bits 6..8 select an operand size of
+//! 8, 16 or 32 bits.
+//!
+//! - Crypto instructions are issued to the SCP through `cxset` and the
+//! `0xF5 0x3C` coprocessor command, which encodes the crypto opcode in
+//! bits 11..16 of its immediate.
+//!
+//! # Implementation
+//!
+//! - [`decode`] decodes a single [`Instruction`] and reports the length
+//! of the format if the operation is unknown, so that decoding can stay
+//! in sync with the instruction stream.
+//!
+//! - The encodings follow the documentation of the
+//! [envytools](https://envytools.readthedocs.io/en/latest/hw/falcon/isa.html)
+//! project.
+//!
+//! [`decode`]: fn.decode.html
+//! [`Instruction`]: struct.Instruction.html
+
+use std::fmt;
+
+/// Enumeration of possible decoding errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The code ends in the middle of an instruction.
+    Truncated,
+    /// The instruction is unknown, the value is its length.
+    UnknownOpcode(usize),
+}
+
+/// The operand size of sized instructions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Size {
+    /// 8-bit operands.
+    B8,
+    /// 16-bit operands.
+    B16,
+    /// 32-bit operands.
+    B32,
+}
+
+impl Size {
+    /// Gets the size of an operand in bytes.
+    pub fn bytes(self) -> u32 {
+        match self {
+            Size::B8 => 1,
+            Size::B16 => 2,
+            Size::B32 => 4,
+        }
+    }
+}
+
+impl fmt::Display for Size {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Size::B8 => write!(f, "b8"),
+            Size::B16 => write!(f, "b16"),
+            Size::B32 => write!(f, "b32"),
+        }
+    }
+}
+
+/// The memory spaces that are addressable by instructions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Space {
+    /// The data segment.
+    Data,
+    /// The I/O space.
+    Io,
+}
+
+/// The offset of a memory operand from its base register.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Offset {
+    /// No offset.
+    None,
+    /// An immediate offset in bytes.
+    Immediate(u32),
+    /// A register offset and its scale.
+    Register(u8, u32),
+}
+
+/// An operand of an instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operand {
+    /// A general-purpose register.
+    Register(u8),
+    /// A special-purpose register.
+    SpecialRegister(u8),
+    /// A crypto register of the SCP.
+    CryptoRegister(u8),
+    /// The stack pointer.
+    StackPointer,
+    /// The flags register.
+    Flags,
+    /// An unsigned immediate.
+    Immediate(u32),
+    /// A sign-extended immediate.
+    SignedImmediate(i32),
+    /// The absolute code address of a branch.
+    Target(u32),
+    /// A memory access relative to a register, or the stack pointer if `None`.
+    Memory(Space, Option<u8>, Offset),
+}
+
+/// The names of the special-purpose registers.
+const SPECIAL_REGISTERS: [&str; 13] = [
+    "iv0", "iv1", "iv2", "tv", "sp", "pc", "xcbase", "xdbase", "flags", "cx", "cauth", "xtargets",
+    "tstatus",
+];
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Operand::Register(index) => write!(f, "$r{}", index),
+            Operand::SpecialRegister(index) => match SPECIAL_REGISTERS.get(index as usize) {
+                Some(name) => write!(f, "${}", name),
+                None => write!(f, "$sr{}", index),
+            },
+            Operand::CryptoRegister(index) => write!(f, "$c{}", index),
+            Operand::StackPointer => write!(f, "$sp"),
+            Operand::Flags => write!(f, "$flags"),
+            Operand::Immediate(value) => write!(f, "{:#x}", value),
+            Operand::SignedImmediate(value) if value < 0 => write!(f, "-{:#x}", -(value as i64)),
+            Operand::SignedImmediate(value) => write!(f, "{:#x}", value),
+            Operand::Target(address) => write!(f, "{:#x}", address),
+            Operand::Memory(space, base, offset) => {
+                let space = match space {
+                    Space::Data => 'D',
+                    Space::Io => 'I',
+                };
+
+                match base {
+                    Some(base) => write!(f, "{}[$r{}", space, base)?,
+                    None => write!(f, "{}[$sp", space)?,
+                }
+
+                match offset {
+                    Offset::None => write!(f, "]"),
+                    Offset::Immediate(offset) => write!(f, "+{:#x}]", offset),
+                    Offset::Register(index, 1) => write!(f, "+$r{}]", index),
+                    Offset::Register(index, scale) => write!(f, "+$r{}*{}]", index, scale),
+                }
+            }
+        }
+    }
+}
+
+/// A decoded instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    /// The code address of the instruction.
+    pub address: u32,
+    /// The length of the instruction in bytes.
+    pub length: usize,
+    /// The mnemonic of the operation.
+    pub mnemonic: &'static str,
+    /// The operand size, for sized instructions.
+    pub size: Option<Size>,
+    /// The operands of the instruction.
+    pub operands: Vec<Operand>,
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.mnemonic)?;
+
+        if let Some(size) = self.size {
+            write!(f, " {}", size)?;
+        }
+
+        for operand in &self.operands {
+            write!(f, " {}", operand)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The operand layouts of the instruction formats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Layout {
+    /// `R2, R1, I8`, sub-opcode in the opcode.
+    R2R1I8,
+    /// `R1, R2, I8`, sub-opcode in the opcode.
+    R1R2I8,
+    /// `R1, R2, I16`, sub-opcode in the opcode.
+    R1R2I16,
+    /// `R2, I8`, sub-opcode in bits 8..12.
+    R2I8,
+    /// `R2, I16`, sub-opcode in bits 8..12.
+    R2I16,
+    /// `R2, R1`, sub-opcode in bits 16..20.
+    R2R1,
+    /// `R1, R2`, sub-opcode in bits 16..20.
+    R1R2,
+    /// `R3, R2, R1`, sub-opcode in bits 16..20.
+    R3R2R1,
+    /// `R1, R2, R3`, sub-opcode in bits 16..20.
+    R1R2R3,
+    /// `R2`, sub-opcode in bits 8..12.
+    R2,
+    /// `I8`, sub-opcode in bits 8..14.
+    I8,
+    /// `I16`, sub-opcode in bits 8..14.
+    I16,
+    /// No operands, sub-opcode in bits 8..12.
+    Nothing,
+}
+
+impl Layout {
+    /// Gets the layout of the format that is selected by the opcode.
+    fn from_opcode(opcode: u8) -> Option<Self> {
+        let sized = opcode < 0xC0;
+
+        let layout = match (sized, opcode & 0x3F) {
+            (true, 0x00..=0x0F) => Layout::R2R1I8,
+            (true, 0x10..=0x1F) => Layout::R1R2I8,
+            (true, 0x20..=0x2F) => Layout::R1R2I16,
+            (true, 0x30) | (true, 0x34) | (true, 0x36) => Layout::R2I8,
+            (true, 0x31) | (true, 0x35) | (true, 0x37) => Layout::R2I16,
+            (true, 0x38) | (true, 0x3A) | (true, 0x3B) => Layout::R2R1,
+            (true, 0x39) => Layout::R1R2,
+            (true, 0x3C) => Layout::R3R2R1,
+            (true, 0x3D) => Layout::R2,
+            (false, 0x00..=0x0F) => Layout::R1R2I8,
+            (false, 0x10..=0x1F) => Layout::R2R1I8,
+            (false, 0x20..=0x2F) => Layout::R1R2I16,
+            (false, 0x30) | (false, 0x32) => Layout::R2I8,
+            (false, 0x31) | (false, 0x33) => Layout::R2I16,
+            (false, 0x34) => Layout::I8,
+            (false, 0x35) => Layout::I16,
+            (false, 0x38) => Layout::Nothing,
+            (false, 0x39) | (false, 0x3C) => Layout::R2,
+            (false, 0x3A) | (false, 0x3E) => Layout::R1R2,
+            (false, 0x3D) => Layout::R2R1,
+            (false, 0x3F) => Layout::R1R2R3,
+            _ => return None,
+        };
+
+        Some(layout)
+    }
+
+    /// Gets the length of an instruction in this format.
+    fn length(self) -> usize {
+        match self {
+            Layout::R2 | Layout::Nothing => 2,
+            Layout::R1R2I16 | Layout::R2I16 | Layout::I16 => 4,
+            _ => 3,
+        }
+    }
+}
+
+/// The fields of an instruction, extracted according to its layout.
+struct Fields {
+    opcode: u8,
+    sub: u8,
+    r1: u8,
+    r2: u8,
+    r3: u8,
+    imm: u32,
+    imm_bits: u32,
+}
+
+impl Fields {
+    /// Extracts the fields of an instruction.
+    fn extract(layout: Layout, bytes: &[u8]) -> Self {
+        let opcode = bytes[0];
+        let byte = |index: usize| u32::from(*bytes.get(index).unwrap_or(&0));
+
+        let (sub, imm, imm_bits) = match layout {
+            Layout::R2R1I8 | Layout::R1R2I8 => (opcode & 0xF, byte(2), 8),
+            Layout::R1R2I16 => (opcode & 0xF, byte(2) | byte(3) << 8, 16),
+            Layout::R2I8 => (bytes[1] & 0xF, byte(2), 8),
+            Layout::R2I16 => (bytes[1] & 0xF, byte(2) | byte(3) << 8, 16),
+            Layout::I8 => (bytes[1] & 0x3F, byte(2), 8),
+            Layout::I16 => (bytes[1] & 0x3F, byte(2) | byte(3) << 8, 16),
+            Layout::R2 | Layout::Nothing => (bytes[1] & 0xF, 0, 0),
+            Layout::R2R1 | Layout::R1R2 | Layout::R3R2R1 | Layout::R1R2R3 => (bytes[2] & 0xF, 0, 0),
+        };
+
+        Fields {
+            opcode,
+            sub,
+            r1: bytes[1] & 0xF,
+            r2: bytes[1] >> 4,
+            r3: *bytes.get(2).unwrap_or(&0) >> 4,
+            imm,
+            imm_bits,
+        }
+    }
+
+    /// Gets the sign-extended immediate.
+    fn simm(&self) -> i32 {
+        let shift = 32 - self.imm_bits;
+
+        ((self.imm << shift) as i32) >> shift
+    }
+}
+
+/// Gets the mnemonic of an arithmetic operation on sized operands.
+fn sized_alu(sub: u8) -> Option<&'static str> {
+    Some(match sub {
+        0x0 => "add",
+        0x1 => "adc",
+        0x2 => "sub",
+        0x3 => "sbb",
+        0x4 => "shl",
+        0x5 => "shr",
+        0x7 => "sar",
+        0xC => "shlc",
+        0xD => "shrc",
+        _ => return None,
+    })
+}
+
+/// Gets the mnemonic of an arithmetic operation on unsized operands.
+fn unsized_alu(sub: u8) -> Option<&'static str> {
+    Some(match sub {
+        0x0 => "mulu",
+        0x1 => "muls",
+        0x2 => "sext",
+        0x3 => "extrs",
+        0x4 => "and",
+        0x5 => "or",
+        0x6 => "xor",
+        0x7 => "extr",
+        0x8 => "xbit",
+        0xB => "ins",
+        0xC => "div",
+        0xD => "mod",
+        _ => return None,
+    })
+}
+
+/// Gets the mnemonic of a comparison.
+fn compare(sub: u8) -> Option<&'static str> {
+    Some(match sub {
+        0x4 => "cmpu",
+        0x5 => "cmps",
+        0x6 => "cmp",
+        _ => return None,
+    })
+}
+
+/// Gets the mnemonic of a unary operation.
+fn unary(sub: u8) -> Option<&'static str> {
+    Some(match sub {
+        0x0 => "not",
+        0x1 => "neg",
+        0x2 => "movf",
+        0x3 => "hswap",
+        _ => return None,
+    })
+}
+
+/// Gets the mnemonic of an operation with a register and an unsized immediate.
+fn immediate(sub: u8) -> Option<&'static str> {
+    Some(match sub {
+        0x0 => "mulu",
+        0x1 => "muls",
+        0x2 => "sext",
+        0x3 => "sethi",
+        0x4 => "and",
+        0x5 => "or",
+        0x6 => "xor",
+        0x7 => "mov",
+        0x9 => "bset",
+        0xA => "bclr",
+        0xB => "btgl",
+        0xC => "xbit",
+        _ => return None,
+    })
+}
+
+/// Gets the mnemonic of a conditional branch.
+fn branch(condition: u8) -> Option<&'static str> {
+    const CONDITIONS: [&str; 32] = [
+        "bra p0", "bra p1", "bra p2", "bra p3", "bra p4", "bra p5", "bra p6", "bra p7", "bra c",
+        "bra o", "bra s", "bra z", "bra a", "bra na", "bra", "", "bra np0", "bra np1", "bra np2",
+        "bra np3", "bra np4", "bra np5", "bra np6", "bra np7", "bra nc", "bra no", "bra ns",
+        "bra nz", "bra g", "bra le", "bra l", "bra ge",
+    ];
+
+    CONDITIONS
+        .get(condition as usize)
+        .cloned()
+        .filter(|name| !name.is_empty())
+}
+
+/// Gets the mnemonic and operands of an SCP command.
+fn crypto(command: u32) -> Option<(&'static str, Vec<Operand>)> {
+    let c1 = Operand::CryptoRegister((command & 7) as u8);
+    let c2 = Operand::CryptoRegister(((command >> 4) & 7) as u8);
+    let imm = Operand::Immediate((command >> 4) & 0x3F);
+
+    Some(match command >> 11 {
+        0x00 => ("cnop", vec![]),
+        0x01 => ("cmov", vec![c1, c2]),
+        0x02 => ("cxsin", vec![c1]),
+        0x03 => ("cxsout", vec![c1]),
+        0x04 => ("crnd", vec![c1]),
+        0x05 => ("cs0begin", vec![imm]),
+        0x06 => ("cs0exec", vec![imm]),
+        0x07 => ("cs1begin", vec![imm]),
+        0x08 => ("cs1exec", vec![imm]),
+        0x0A => ("cchmod", vec![c1, imm]),
+        0x0B => ("cxor", vec![c1, c2]),
+        0x0C => ("cadd", vec![c1, imm]),
+        0x0D => ("cand", vec![c1, c2]),
+        0x0E => ("crev", vec![c1, c2]),
+        0x0F => ("cprecmac", vec![c1, c2]),
+        0x10 => ("csecret", vec![c1, imm]),
+        0x11 => ("ckeyreg", vec![c1]),
+        0x12 => ("ckexp", vec![c1, c2]),
+        0x13 => ("ckrexp", vec![c1, c2]),
+        0x14 => ("cenc", vec![c1, c2]),
+        0x15 => ("cdec", vec![c1, c2]),
+        0x16 => ("csigcmp", vec![c1, c2]),
+        0x17 => ("csigenc", vec![c1, c2]),
+        0x18 => ("csigclr", vec![]),
+        _ => return None,
+    })
+}
+
+/// Decodes a sized instruction.
+fn decode_sized(fields: &Fields, size: Size) -> Option<(&'static str, Vec<Operand>)> {
+    use self::Operand::*;
+
+    let scale = size.bytes();
+    let (r1, r2, r3) = (
+        Register(fields.r1),
+        Register(fields.r2),
+        Register(fields.r3),
+    );
+    let imm = Immediate(fields.imm);
+
+    Some(match (fields.opcode & 0x3F, fields.sub) {
+        (0x00..=0x0F, 0x0) => (
+            "st",
+            vec![
+                Memory(
+                    Space::Data,
+                    Some(fields.r1),
+                    Offset::Immediate(fields.imm * scale),
+                ),
+                r2,
+            ],
+        ),
+        (0x10..=0x1F, 0x8) => (
+            "ld",
+            vec![
+                r1,
+                Memory(
+                    Space::Data,
+                    Some(fields.r2),
+                    Offset::Immediate(fields.imm * scale),
+                ),
+            ],
+        ),
+        (0x10..=0x2F, sub) => (sized_alu(sub)?, vec![r1, r2, imm]),
+        (0x30, 0x1) => (
+            "st",
+            vec![
+                Memory(Space::Data, None, Offset::Immediate(fields.imm * scale)),
+                r2,
+            ],
+        ),
+        (0x30, sub) | (0x31, sub) => (compare(sub)?, vec![r2, imm]),
+        (0x34, 0x0) => (
+            "ld",
+            vec![
+                r2,
+                Memory(Space::Data, None, Offset::Immediate(fields.imm * scale)),
+            ],
+        ),
+        (0x36, sub) | (0x37, sub) => (sized_alu(sub)?, vec![r2, imm]),
+        (0x38, 0x0) => (
+            "st",
+            vec![Memory(Space::Data, Some(fields.r1), Offset::None), r2],
+        ),
+        (0x38, 0x1) => (
+            "st",
+            vec![
+                Memory(Space::Data, None, Offset::Register(fields.r1, scale)),
+                r2,
+            ],
+        ),
+        (0x38, sub) => (compare(sub)?, vec![r2, r1]),
+        (0x39, sub) => (unary(sub)?, vec![r1, r2]),
+        (0x3A, 0x0) => (
+            "ld",
+            vec![
+                r2,
+                Memory(Space::Data, None, Offset::Register(fields.r1, scale)),
+            ],
+        ),
+        (0x3B, sub) => (sized_alu(sub)?, vec![r2, r1]),
+        (0x3C, 0x8) => (
+            "ld",
+            vec![
+                r3,
+                Memory(
+                    Space::Data,
+                    Some(fields.r2),
+                    Offset::Register(fields.r1, scale),
+                ),
+            ],
+        ),
+        (0x3C, sub) => (sized_alu(sub)?, vec![r3, r2, r1]),
+        (0x3D, 0x4) => ("clear", vec![r2]),
+        (0x3D, 0x5) => ("setf", vec![r2]),
+        (0x3D, sub) => (unary(sub)?, vec![r2]),
+        _ => return None,
+    })
+}
+
+/// Decodes an unsized instruction.
+fn decode_unsized(address: u32, fields: &Fields) -> Option<(&'static str, Vec<Operand>)> {
+    use self::Operand::*;
+
+    let (r1, r2, r3) = (
+        Register(fields.r1),
+        Register(fields.r2),
+        Register(fields.r3),
+    );
+    let imm = Immediate(fields.imm);
+
+    Some(match (fields.opcode, fields.sub) {
+        (0xCF, _) => (
+            "iord",
+            vec![
+                r1,
+                Memory(
+                    Space::Io,
+                    Some(fields.r2),
+                    Offset::Immediate(fields.imm * 4),
+                ),
+            ],
+        ),
+        (0xC0..=0xCF, sub) | (0xE0..=0xEF, sub) => (unsized_alu(sub)?, vec![r1, r2, imm]),
+        (0xD0, _) | (0xD1, _) => (
+            if fields.opcode == 0xD0 {
+                "iowr"
+            } else {
+                "iowrs"
+            },
+            vec![
+                Memory(
+                    Space::Io,
+                    Some(fields.r1),
+                    Offset::Immediate(fields.imm * 4),
+                ),
+                r2,
+            ],
+        ),
+        (0xF0, sub) | (0xF1, sub) => (immediate(sub)?, vec![r2, imm]),
+        (0xF4, condition @ 0x00..=0x1F) | (0xF5, condition @ 0x00..=0x1F) => (
+            branch(condition)?,
+            vec![Target(address.wrapping_add(fields.simm() as u32))],
+        ),
+        (0xF4, 0x20) | (0xF5, 0x20) => ("jmp", vec![Target(fields.imm)]),
+        (0xF4, 0x21) | (0xF5, 0x21) => ("call", vec![Target(fields.imm)]),
+        (0xF4, 0x28) | (0xF5, 0x28) => ("sleep", vec![imm]),
+        (0xF4, 0x30) | (0xF5, 0x30) => ("add", vec![StackPointer, SignedImmediate(fields.simm())]),
+        (0xF4, 0x31) | (0xF5, 0x31) => ("bset", vec![Flags, imm]),
+        (0xF4, 0x32) | (0xF5, 0x32) => ("bclr", vec![Flags, imm]),
+        (0xF4, 0x33) | (0xF5, 0x33) => ("btgl", vec![Flags, imm]),
+        (0xF4, 0x3C) => ("cxset", vec![imm]),
+        (0xF5, 0x3C) => crypto(fields.imm)?,
+        (0xF8, 0x0) => ("ret", vec![]),
+        (0xF8, 0x1) => ("reti", vec![]),
+        (0xF8, 0x2) => ("halt", vec![]),
+        (0xF8, 0x3) => ("xdwait", vec![]),
+        (0xF8, 0x6) => ("xdfence", vec![]),
+        (0xF8, 0x7) => ("xcwait", vec![]),
+        (0xF8, trap @ 0x8..=0xB) => ("trap", vec![Immediate(u32::from(trap - 8))]),
+        (0xF9, 0x0) => ("push", vec![r2]),
+        (0xF9, 0x4) => ("jmp", vec![r2]),
+        (0xF9, 0x5) => ("call", vec![r2]),
+        (0xF9, 0x8) => ("itlb", vec![r2]),
+        (0xFA, 0x0) => (
+            "iowr",
+            vec![Memory(Space::Io, Some(fields.r1), Offset::None), r2],
+        ),
+        (0xFA, 0x1) => (
+            "iowrs",
+            vec![Memory(Space::Io, Some(fields.r1), Offset::None), r2],
+        ),
+        (0xFA, 0x4) => ("xcld", vec![r1, r2]),
+        (0xFA, 0x5) => ("xdld", vec![r1, r2]),
+        (0xFA, 0x6) => ("xdst", vec![r1, r2]),
+        (0xFA, 0x8) => ("setp", vec![r2, r1]),
+        (0xFC, 0x0) => ("pop", vec![r2]),
+        (0xFD, 0x9) => ("bset", vec![r2, r1]),
+        (0xFD, 0xA) => ("bclr", vec![r2, r1]),
+        (0xFD, 0xB) => ("btgl", vec![r2, r1]),
+        (0xFD, sub) => (unsized_alu(sub)?, vec![r2, r1]),
+        (0xFE, 0x0) => ("wspr", vec![SpecialRegister(fields.r1), r2]),
+        (0xFE, 0x1) => ("rspr", vec![r1, SpecialRegister(fields.r2)]),
+        (0xFE, 0x2) => ("mov", vec![r1, r2]),
+        (0xFE, 0x8) => ("ptlb", vec![r1, r2]),
+        (0xFE, 0x9) => ("vtlb", vec![r1, r2]),
+        (0xFF, 0xF) => (
+            "iord",
+            vec![
+                r3,
+                Memory(Space::Io, Some(fields.r1), Offset::Register(fields.r2, 4)),
+            ],
+        ),
+        (0xFF, sub) => (unsized_alu(sub)?, vec![r3, r1, r2]),
+        _ => return None,
+    })
+}
+
+/// Decodes the instruction at the start of `code`, located at `address`.
+pub fn decode(code: &[u8], address: u32) -> Result<Instruction, Error> {
+    let opcode = *code.first().ok_or(Error::Truncated)?;
+    let layout = Layout::from_opcode(opcode).ok_or(Error::UnknownOpcode(1))?;
+
+    let length = layout.length();
+    if code.len() < length {
+        return Err(Error::Truncated);
+    }
+
+    let fields = Fields::extract(layout, &code[..length]);
+
+    let size = match opcode >> 6 {
+        0 => Some(Size::B8),
+        1 => Some(Size::B16),
+        2 => Some(Size::B32),
+        _ => None,
+    };
+
+    let decoded = match size {
+        Some(size) => decode_sized(&fields, size),
+        None => decode_unsized(address, &fields),
+    };
+
+    let (mnemonic, operands) = decoded.ok_or(Error::UnknownOpcode(length))?;
+
+    Ok(Instruction {
+        address,
+        length,
+        mnemonic,
+        size,
+        operands,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decodes a single instruction that spans all of `code` and formats it.
+    fn disasm(code: &[u8], address: u32) -> String {
+        let instruction = decode(code, address).unwrap();
+        assert_eq!(instruction.length, code.len());
+
+        instruction.to_string()
+    }
+
+    /// Tests the instruction formats of sized operations.
+    #[test]
+    fn sized_layouts() {
+        let golden: [(&[u8], &str); 11] = [
+            (&[0x80, 0x21, 0x03], "st b32 D[$r1+0xc] $r2"),
+            (&[0x18, 0x21, 0x04], "ld b8 $r1 D[$r2+0x4]"),
+            (&[0x50, 0x21, 0xFF], "add b16 $r1 $r2 0xff"),
+            (&[0xA1, 0x21, 0x34, 0x12], "adc b32 $r1 $r2 0x1234"),
+            (&[0xB0, 0x21, 0x02], "st b32 D[$sp+0x8] $r2"),
+            (&[0xB0, 0x26, 0x10], "cmp b32 $r2 0x10"),
+            (&[0xB1, 0x24, 0x34, 0x12], "cmpu b32 $r2 0x1234"),
+            (&[0xBB, 0x21, 0x00], "add b32 $r2 $r1"),
+            (&[0xB9, 0x21, 0x01], "neg b32 $r1 $r2"),
+            (&[0xBC, 0x21, 0x38], "ld b32 $r3 D[$r2+$r1*4]"),
+            (&[0xBD, 0x24], "clear b32 $r2"),
+        ];
+
+        for (code, text) in golden.iter() {
+            assert_eq!(disasm(code, 0), *text);
+        }
+    }
+
+    /// Tests the instruction formats of unsized operations.
+    #[test]
+    fn unsized_layouts() {
+        let golden: [(&[u8], &str); 14] = [
+            (&[0xC4, 0x21, 0xFF], "and $r1 $r2 0xff"),
+            (&[0xCF, 0x21, 0x02], "iord $r1 I[$r2+0x8]"),
+            (&[0xD0, 0x21, 0x01], "iowr I[$r1+0x4] $r2"),
+            (&[0xE5, 0x21, 0x34, 0x12], "or $r1 $r2 0x1234"),
+            (&[0xF0, 0x27, 0x10], "mov $r2 0x10"),
+            (&[0xF1, 0x27, 0x34, 0x12], "mov $r2 0x1234"),
+            (&[0xF4, 0x30, 0xF0], "add $sp -0x10"),
+            (&[0xF5, 0x21, 0x00, 0x01], "call 0x100"),
+            (&[0xF8, 0x00], "ret"),
+            (&[0xF9, 0x20], "push $r2"),
+            (&[0xFE, 0x21, 0x01], "rspr $r1 $iv2"),
+            (&[0xFE, 0x14, 0x00], "wspr $sp $r1"),
+            (&[0xFD, 0x21, 0x09], "bset $r2 $r1"),
+            (&[0xFF, 0x21, 0x34], "and $r3 $r1 $r2"),
+        ];
+
+        for (code, text) in golden.iter() {
+            assert_eq!(disasm(code, 0), *text);
+        }
+    }
+
+    /// Tests that the top bits of the opcode select the operand size.
+    #[test]
+    fn sized_and_unsized_forms() {
+        let sizes = [
+            (0x10, Some(Size::B8)),
+            (0x50, Some(Size::B16)),
+            (0x90, Some(Size::B32)),
+            (0xD0, None),
+        ];
+
+        for (opcode, size) in sizes.iter() {
+            let instruction = decode(&[*opcode, 0x21, 0x01], 0).unwrap();
+            assert_eq!(instruction.size, *size);
+        }
+
+        // The immediate offsets of sized memory accesses are scaled.
+        assert_eq!(disasm(&[0x00, 0x21, 0x03], 0), "st b8 D[$r1+0x3] $r2");
+        assert_eq!(disasm(&[0x40, 0x21, 0x03], 0), "st b16 D[$r1+0x6] $r2");
+
+        // The same low bits select a different format when unsized.
+        assert_eq!(disasm(&[0xC0, 0x21, 0x03], 0), "mulu $r1 $r2 0x3");
+    }
+
+    /// Tests the sign extension of relative branch targets.
+    #[test]
+    fn branch_targets() {
+        assert_eq!(disasm(&[0xF4, 0x0B, 0x10], 0x100), "bra z 0x110");
+        assert_eq!(disasm(&[0xF4, 0x0E, 0xFE], 0x100), "bra 0xfe");
+        assert_eq!(disasm(&[0xF5, 0x1B, 0x00, 0xFF], 0x1000), "bra nz 0xf00");
+        assert_eq!(disasm(&[0xF5, 0x0E, 0xFF, 0x7F], 0), "bra 0x7fff");
+        assert_eq!(disasm(&[0xF4, 0x0E, 0xFE], 0), "bra 0xfffffffe");
+
+        // Absolute targets are not sign-extended.
+        assert_eq!(disasm(&[0xF5, 0x20, 0x00, 0xFF], 0x1000), "jmp 0xff00");
+    }
+
+    /// Tests the crypto instructions of the SCP.
+    #[test]
+    fn crypto_instructions() {
+        assert_eq!(disasm(&[0xF4, 0x3C, 0x02], 0), "cxset 0x2");
+        assert_eq!(disasm(&[0xF5, 0x3C, 0x00, 0x00], 0), "cnop");
+        assert_eq!(disasm(&[0xF5, 0x3C, 0x21, 0x08], 0), "cmov $c1 $c2");
+        assert_eq!(disasm(&[0xF5, 0x3C, 0x30, 0x28], 0), "cs0begin 0x3");
+        assert_eq!(disasm(&[0xF5, 0x3C, 0x63, 0x82], 0), "csecret $c3 0x26");
+        assert_eq!(disasm(&[0xF5, 0x3C, 0x45, 0xA8], 0), "cdec $c5 $c4");
+        assert_eq!(disasm(&[0xF5, 0x3C, 0x00, 0xC0], 0), "csigclr");
+
+        assert_eq!(
+            decode(&[0xF5, 0x3C, 0x00, 0xC8], 0),
+            Err(Error::UnknownOpcode(4))
+        );
+    }
+
+    /// Tests that errors report enough to resync with the instruction stream.
+    #[test]
+    fn decode_errors() {
+        // Opcodes without a format are skipped byte by byte.
+        assert_eq!(decode(&[0x32, 0x00, 0x00], 0), Err(Error::UnknownOpcode(1)));
+        assert_eq!(decode(&[0xF6, 0x00, 0x00], 0), Err(Error::UnknownOpcode(1)));
+
+        // Unknown operations of a known format are skipped entirely.
+        assert_eq!(decode(&[0xF8, 0x04], 0), Err(Error::UnknownOpcode(2)));
+        assert_eq!(decode(&[0xBD, 0x2F], 0), Err(Error::UnknownOpcode(2)));
+        assert_eq!(
+            decode(&[0xF1, 0x2E, 0x00, 0x00], 0),
+            Err(Error::UnknownOpcode(4))
+        );
+
+        assert_eq!(decode(&[], 0), Err(Error::Truncated));
+        assert_eq!(decode(&[0xF8], 0), Err(Error::Truncated));
+        assert_eq!(decode(&[0xF1, 0x27, 0x34], 0), Err(Error::Truncated));
+    }
+
+    /// Tests that decoding stays in sync after an unknown instruction.
+    #[test]
+    fn resync_after_unknown() {
+        let code = [0xF8, 0x04, 0xF0, 0x27, 0x10, 0xF6, 0xF8, 0x02];
+        let mut offset = 0;
+        let mut listing = Vec::new();
+
+        while offset < code.len() {
+            match decode(&code[offset..], offset as u32) {
+                Ok(instruction) => {
+                    listing.push(instruction.to_string());
+                    offset += instruction.length;
+                }
+                Err(Error::UnknownOpcode(length)) => {
+                    listing.push(String::from("(unknown)"));
+                    offset += length;
+                }
+                Err(Error::Truncated) => unreachable!(),
+            }
+        }
+
+        assert_eq!(listing, ["(unknown)", "mov $r2 0x10", "(unknown)", "halt"]);
+    }
+}
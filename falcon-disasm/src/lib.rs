@@ -0,0 +1,26 @@
+//! Disassembler for NVIDIA Falcon v5 machine code.
+//!
+//! **Note:** This crate is a host-side debugging tool. It is not
+//! part of the firmware and requires `std`.
+//!
+//! # Description
+//!
+//! The TSEC is powered by a Falcon microprocessor with crypto
+//! extensions. When the TSEC firmware fails, the code that is
+//! handed to `Tsec::load_firmware` can be disassembled with this
+//! crate to inspect what it does.
+//!
+//! # Implementation
+//!
+//! - The [`decode`] module decodes single instructions, including
+//! the crypto instructions of the SCP.
+//!
+//! - The [`listing`] module writes annotated listings of raw code
+//! and of TSEC firmware images, using the firmware parser from
+//! `mirage-libtegra` to mark the stage boundaries.
+//!
+//! [`decode`]: decode/index.html
+//! [`listing`]: listing/index.html
+
+pub mod decode;
+pub mod listing;
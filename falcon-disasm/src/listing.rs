@@ -0,0 +1,168 @@
+//! Annotated listings of Falcon code and TSEC firmware images.
+//!
+//! # Description
+//!
+//! A listing shows each instruction with its address and raw bytes.
+//! For TSEC firmware images, the stage boundaries that are found by
+//! the firmware parser of `mirage-libtegra` are marked and the key
+//! table is annotated with the hashes and sizes it describes.
+//!
+//! # Implementation
+//!
+//! - [`write_code`] disassembles a raw blob of code.
+//!
+//! - [`write_firmware`] disassembles a parsed [`Firmware`] stage by
+//! stage. The key table and the encrypted Keygen stage are dumped
+//! as data instead, since they do not contain plain code.
+//!
+//! [`write_code`]: fn.write_code.html
+//! [`write_firmware`]: fn.write_firmware.html
+//! [`Firmware`]: ../../mirage_libtegra/tsec/firmware/struct.Firmware.html
+
+use std::io::{self, Write};
+
+use mirage_libtegra::tsec::firmware::{Firmware, KeyTable, Stage, BOOT_SIZE, KEY_TABLE_SIZE};
+
+use crate::decode::{decode, Error};
+
+/// The number of bytes per line of a data dump.
+const DATA_BYTES_PER_LINE: usize = 0x10;
+
+/// The width of the raw bytes column.
+const BYTES_COLUMN_WIDTH: usize = 12;
+
+/// Formats bytes as space-separated hex.
+fn hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Writes a listing of the code in `code`, which is located at `base`.
+pub fn write_code<W: Write>(out: &mut W, code: &[u8], base: u32) -> io::Result<()> {
+    let mut offset = 0;
+
+    while offset < code.len() {
+        let address = base + offset as u32;
+
+        let (length, text) = match decode(&code[offset..], address) {
+            Ok(instruction) => (instruction.length, instruction.to_string()),
+            Err(Error::UnknownOpcode(length)) => (length, String::from("(unknown)")),
+            Err(Error::Truncated) => (code.len() - offset, String::from("(truncated)")),
+        };
+
+        writeln!(
+            out,
+            "{:08x}:  {:<width$}  {}",
+            address,
+            hex(&code[offset..offset + length]),
+            text,
+            width = BYTES_COLUMN_WIDTH
+        )?;
+
+        offset += length;
+    }
+
+    Ok(())
+}
+
+/// Writes a hex dump of the data in `data`, which is located at `base`.
+pub fn write_data<W: Write>(out: &mut W, data: &[u8], base: u32) -> io::Result<()> {
+    for (i, line) in data.chunks(DATA_BYTES_PER_LINE).enumerate() {
+        writeln!(
+            out,
+            "{:08x}:  .db {}",
+            base as usize + i * DATA_BYTES_PER_LINE,
+            hex(line)
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Writes the annotations for the key table.
+fn write_key_table<W: Write>(out: &mut W, key_table: &KeyTable) -> io::Result<()> {
+    let entries = [
+        ("debug key", &key_table.debug_key),
+        ("KeygenLdr hash", &key_table.keygen_ldr_hash),
+        ("Keygen hash", &key_table.keygen_hash),
+        ("SecureBoot hash", &key_table.secure_boot_hash),
+        ("Keygen IV", &key_table.keygen_iv),
+        ("HOVI EKS seed", &key_table.hovi_eks_seed),
+        ("HOVI common seed", &key_table.hovi_common_seed),
+    ];
+
+    for &(name, value) in entries.iter() {
+        writeln!(out, ";   {:<18} {}", format!("{}:", name), hex(value))?;
+    }
+
+    for (i, size) in key_table.blob_sizes.iter().enumerate() {
+        writeln!(out, ";   {:<18} {:#x}", format!("blob {} size:", i), size)?;
+    }
+
+    Ok(())
+}
+
+/// Writes an annotated listing of a TSEC firmware image.
+pub fn write_firmware<W: Write>(out: &mut W, firmware: &Firmware<'_>) -> io::Result<()> {
+    writeln!(out, "; TSEC firmware, {:?} revision", firmware.revision())?;
+    writeln!(out, ";")?;
+
+    let stages = [
+        ("Boot", Stage::Boot),
+        ("KeygenLdr", Stage::KeygenLdr),
+        ("Keygen", Stage::Keygen),
+        ("SecureBoot", Stage::SecureBoot),
+    ];
+
+    let mut end = 0;
+    for &(name, stage) in stages.iter() {
+        let range = match firmware.stage_range(stage) {
+            Some(range) => range,
+            None => continue,
+        };
+        let data = &firmware.data()[range.clone()];
+
+        writeln!(out)?;
+        writeln!(out, "; {} ({:#x} - {:#x})", name, range.start, range.end)?;
+
+        if stage == Stage::Keygen {
+            writeln!(out, ";   encrypted, not disassembled")?;
+            write_data(out, data, range.start as u32)?;
+        } else {
+            write_code(out, data, range.start as u32)?;
+        }
+
+        if stage == Stage::Boot {
+            let key_table = &firmware.data()[BOOT_SIZE..BOOT_SIZE + KEY_TABLE_SIZE];
+
+            writeln!(out)?;
+            writeln!(
+                out,
+                "; Key table ({:#x} - {:#x})",
+                BOOT_SIZE,
+                BOOT_SIZE + KEY_TABLE_SIZE
+            )?;
+            write_key_table(out, firmware.key_table())?;
+            write_data(out, key_table, BOOT_SIZE as u32)?;
+        }
+
+        end = range.end;
+    }
+
+    let padding = &firmware.data()[end.max(BOOT_SIZE + KEY_TABLE_SIZE)..];
+    if !padding.is_empty() {
+        writeln!(out)?;
+        writeln!(
+            out,
+            "; Trailing data ({:#x} - {:#x})",
+            firmware.data().len() - padding.len(),
+            firmware.data().len()
+        )?;
+        write_data(out, padding, (firmware.data().len() - padding.len()) as u32)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+//! Command-line interface of the Falcon disassembler.
+//!
+//! Usage: `mirage-falcon-disasm [--raw] <file>`
+//!
+//! By default, the file is parsed as a TSEC firmware image. With
+//! `--raw`, it is disassembled as plain code starting at address 0.
+
+use std::{env, fs, io, process};
+
+use mirage_falcon_disasm::listing::{write_code, write_firmware};
+use mirage_libtegra::tsec::firmware::Firmware;
+
+/// Prints the usage of the tool and exits.
+fn usage() -> ! {
+    eprintln!("usage: mirage-falcon-disasm [--raw] <file>");
+    process::exit(2);
+}
+
+fn main() {
+    let mut raw = false;
+    let mut path = None;
+
+    for argument in env::args().skip(1) {
+        match argument.as_str() {
+            "--raw" => raw = true,
+            _ if path.is_none() => path = Some(argument),
+            _ => usage(),
+        }
+    }
+
+    let path = path.unwrap_or_else(|| usage());
+    let data = fs::read(&path).unwrap_or_else(|error| {
+        eprintln!("failed to read {}: {}", path, error);
+        process::exit(1);
+    });
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+
+    let result = if raw {
+        write_code(&mut out, &data, 0)
+    } else {
+        match Firmware::parse(&data) {
+            Ok(firmware) => write_firmware(&mut out, &firmware),
+            Err(error) => {
+                eprintln!("invalid TSEC firmware: {:?}", error);
+                process::exit(1);
+            }
+        }
+    };
+
+    if let Err(error) = result {
+        eprintln!("failed to write listing: {}", error);
+        process::exit(1);
+    }
+}
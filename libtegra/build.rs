@@ -0,0 +1,94 @@
+//! Build script of `mirage-libtegra`.
+//!
+//! Compresses the base SDRAM configuration table from `src/sdram/config.rs`
+//! into `$OUT_DIR/dram_config.rs`, so that only the compressed table
+//! ends up in the payload. The format is described in `src/sdram/lz.rs`.
+
+use std::{env, fmt::Write as _, fs, path::Path};
+
+include!("src/sdram/config.rs");
+
+/// Gets the number of bytes of `value` as a variable-size integer.
+fn size_length(mut value: usize) -> usize {
+    let mut length = 1;
+    while value >= 0x80 {
+        value >>= 7;
+        length += 1;
+    }
+
+    length
+}
+
+/// Writes `value` as a variable-size integer.
+fn write_size(output: &mut Vec<u8>, value: usize) {
+    for group in (0..size_length(value)).rev() {
+        let byte = (value >> (group * 7)) as u8 & 0x7F;
+        output.push(if group == 0 { byte } else { byte | 0x80 });
+    }
+}
+
+/// Finds the longest back-reference for the data at `position`.
+fn find_match(input: &[u8], position: usize) -> (usize, usize) {
+    let mut best = (0, 0);
+
+    for offset in 1..=position {
+        let length = (position..input.len())
+            .take_while(|&i| input[i] == input[i - offset])
+            .count();
+
+        if length > best.0 {
+            best = (length, offset);
+        }
+    }
+
+    best
+}
+
+/// Compresses `input` with a greedy LZ77 encoder.
+fn compress(input: &[u8]) -> Vec<u8> {
+    let mut histogram = [0usize; 0x100];
+    for &byte in input {
+        histogram[byte as usize] += 1;
+    }
+    let marker = (0..=0xFF)
+        .min_by_key(|&byte| histogram[byte as usize])
+        .unwrap();
+
+    let mut output = vec![marker];
+    let mut position = 0;
+
+    while position < input.len() {
+        let (length, offset) = find_match(input, position);
+
+        if length > 1 + size_length(length) + size_length(offset) {
+            output.push(marker);
+            write_size(&mut output, length);
+            write_size(&mut output, offset);
+            position += length;
+        } else {
+            let byte = input[position];
+            output.push(byte);
+            if byte == marker {
+                output.push(0);
+            }
+            position += 1;
+        }
+    }
+
+    output
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/sdram/config.rs");
+
+    let mut source = String::from("/// The LZ77-compressed base SDRAM configuration values.\n");
+    source.push_str("const BASE_DRAM_CONFIG_LZ: &[u8] = &[");
+    for byte in compress(&BASE_DRAM_CONFIG) {
+        write!(source, "{:#04x}, ", byte).unwrap();
+    }
+    source.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("dram_config.rs"), source).unwrap();
+}
@@ -0,0 +1,42 @@
+//! CRC32 checksum calculation.
+//!
+//! # Description
+//!
+//! Checksums over fuse dumps and KFUSE images use the CRC32 variant of
+//! IEEE 802.3 (reflected polynomial `0xEDB88320`, initial value and final
+//! XOR of `0xFFFFFFFF`), which is also used by zlib and friends. This
+//! allows the checksums to be verified with standard host tools.
+
+/// The reflected CRC32 polynomial of IEEE 802.3.
+const CRC32_POLYNOMIAL: u32 = 0xEDB8_8320;
+
+/// Calculates the CRC32 (IEEE 802.3) checksum of a buffer.
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0;
+
+    for byte in data {
+        crc ^= *byte as u32;
+
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ CRC32_POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests the standard check value of the algorithm.
+    #[test]
+    fn crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(&[]), 0);
+    }
+}
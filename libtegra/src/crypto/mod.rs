@@ -0,0 +1,703 @@
+//! Backend-agnostic cryptographic primitives.
+//!
+//! # Description
+//!
+//! Code that builds upon cryptographic primitives, e.g. key derivation or
+//! package parsing, should not depend on the Security Engine directly, as
+//! it can't be tested without hardware then. The [`Crypto`] trait abstracts
+//! over the primitives of the SE, which are AES (ECB, CBC, CTR and XTS),
+//! AES-CMAC, SHA-256 and RSA.
+//!
+//! # Implementation
+//!
+//! - AES-XTS is implemented generically on top of AES-ECB, both with the
+//! little-endian sector tweak of IEEE 1619 and the big-endian sector tweak
+//! Nintendo uses for the BIS partitions.
+//!
+//! - Keys are referenced through keyslots, modeled after the SE. Backends
+//! provide 16 AES and 2 RSA keyslots.
+//!
+//! - [`SeCrypto`] implements the trait on top of the Security Engine and is
+//! used on hardware.
+//!
+//! - [`SoftwareCrypto`] is a pure-Rust implementation which emulates the
+//! keyslots in memory and allows for testing on the host.
+//!
+//! - [`self_test`] runs known-answer tests against any backend. It is used
+//! by the unit tests for the software backend and may be run on hardware
+//! to verify the SE backend against the very same vectors.
+//!
+//! [`Crypto`]: trait.Crypto.html
+//! [`SeCrypto`]: se/struct.SeCrypto.html
+//! [`SoftwareCrypto`]: soft/struct.SoftwareCrypto.html
+//! [`self_test`]: fn.self_test.html
+
+pub mod se;
+pub mod soft;
+mod vectors;
+
+/// The size of an AES block.
+pub const AES_BLOCK_SIZE: usize = 0x10;
+
+/// The size of a SHA-256 digest.
+pub const SHA256_SIZE: usize = 0x20;
+
+/// The number of AES keyslots provided by a backend.
+pub const AES_KEYSLOT_COUNT: usize = 0x10;
+
+/// The number of RSA keyslots provided by a backend.
+pub const RSA_KEYSLOT_COUNT: usize = 0x2;
+
+/// The size of the chunks XTS sectors are processed in.
+const XTS_CHUNK_SIZE: usize = 0x200;
+
+/// The sector size of the BIS partitions, which use Nintendo's XTS tweak.
+pub const NINTENDO_XTS_SECTOR_SIZE: usize = 0x4000;
+
+/// The encoding of the sector number in the initial XTS tweak.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum XtsTweak {
+    /// A little-endian sector number, as specified by IEEE 1619.
+    Standard,
+    /// A big-endian sector number, as used by Nintendo.
+    Nintendo,
+}
+
+/// Enumeration of possible errors of cryptographic operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The keyslot doesn't exist.
+    InvalidKeyslot,
+    /// No key has been set in the keyslot.
+    KeyNotSet,
+    /// The key has an unsupported size.
+    InvalidKeySize,
+    /// The size of a buffer is invalid for the operation.
+    InvalidLength,
+    /// The Security Engine reported an error.
+    Engine(crate::se::Error),
+}
+
+impl From<crate::se::Error> for Error {
+    fn from(error: crate::se::Error) -> Self {
+        Error::Engine(error)
+    }
+}
+
+/// A provider of cryptographic primitives.
+pub trait Crypto {
+    /// Sets an AES key of 128, 192 or 256 bits in a keyslot.
+    fn set_aes_key(&mut self, keyslot: usize, key: &[u8]) -> Result<(), Error>;
+
+    /// Decrypts a wrapped key with the AES-128 key in `source` into `destination`.
+    fn unwrap_aes_key(
+        &mut self,
+        destination: usize,
+        source: usize,
+        wrapped_key: &[u8],
+    ) -> Result<(), Error>;
+
+    /// Encrypts a block-aligned buffer with AES-ECB.
+    fn aes_ecb_encrypt(
+        &mut self,
+        keyslot: usize,
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error>;
+
+    /// Decrypts a block-aligned buffer with AES-ECB.
+    fn aes_ecb_decrypt(
+        &mut self,
+        keyslot: usize,
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error>;
+
+    /// Encrypts a block-aligned buffer with AES-CBC.
+    fn aes_cbc_encrypt(
+        &mut self,
+        keyslot: usize,
+        iv: &[u8; AES_BLOCK_SIZE],
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error>;
+
+    /// Decrypts a block-aligned buffer with AES-CBC.
+    fn aes_cbc_decrypt(
+        &mut self,
+        keyslot: usize,
+        iv: &[u8; AES_BLOCK_SIZE],
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error>;
+
+    /// Encrypts or decrypts a buffer of arbitrary length with AES-CTR.
+    fn aes_ctr(
+        &mut self,
+        keyslot: usize,
+        ctr: &[u8; AES_BLOCK_SIZE],
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error>;
+
+    /// Encrypts whole sectors with AES-XTS, starting at the given sector number.
+    fn aes_xts_encrypt(
+        &mut self,
+        keyslots: (usize, usize),
+        sector: u64,
+        sector_size: usize,
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        aes_xts(
+            self,
+            true,
+            XtsTweak::Standard,
+            keyslots,
+            sector,
+            sector_size,
+            destination,
+            source,
+        )
+    }
+
+    /// Decrypts whole sectors with AES-XTS, starting at the given sector number.
+    fn aes_xts_decrypt(
+        &mut self,
+        keyslots: (usize, usize),
+        sector: u64,
+        sector_size: usize,
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        aes_xts(
+            self,
+            false,
+            XtsTweak::Standard,
+            keyslots,
+            sector,
+            sector_size,
+            destination,
+            source,
+        )
+    }
+
+    /// Encrypts whole sectors with AES-XTS using Nintendo's big-endian sector tweak.
+    fn aes_nintendo_xts_encrypt(
+        &mut self,
+        keyslots: (usize, usize),
+        sector: u64,
+        sector_size: usize,
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        aes_xts(
+            self,
+            true,
+            XtsTweak::Nintendo,
+            keyslots,
+            sector,
+            sector_size,
+            destination,
+            source,
+        )
+    }
+
+    /// Decrypts whole sectors with AES-XTS using Nintendo's big-endian sector tweak.
+    fn aes_nintendo_xts_decrypt(
+        &mut self,
+        keyslots: (usize, usize),
+        sector: u64,
+        sector_size: usize,
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        aes_xts(
+            self,
+            false,
+            XtsTweak::Nintendo,
+            keyslots,
+            sector,
+            sector_size,
+            destination,
+            source,
+        )
+    }
+
+    /// Calculates the AES-CMAC of a message.
+    fn aes_cmac(&mut self, keyslot: usize, data: &[u8]) -> Result<[u8; AES_BLOCK_SIZE], Error>;
+
+    /// Calculates the SHA-256 digest of a message.
+    fn sha256(&mut self, data: &[u8]) -> Result<[u8; SHA256_SIZE], Error>;
+
+    /// Sets a big-endian RSA modulus and exponent in a keyslot.
+    ///
+    /// The modulus size must be a multiple of 512 bits and the exponent
+    /// size a multiple of 32 bits, both with a maximum of 2048 bits.
+    fn set_rsa_key(&mut self, keyslot: usize, modulus: &[u8], exponent: &[u8])
+        -> Result<(), Error>;
+
+    /// Computes `input ^ exponent mod modulus` with big-endian numbers.
+    ///
+    /// Both buffers must be exactly as large as the modulus.
+    fn rsa_exp_mod(&mut self, keyslot: usize, input: &[u8], output: &mut [u8])
+        -> Result<(), Error>;
+}
+
+/// Multiplies an XTS tweak by x in GF(2^128).
+fn xts_double(tweak: u128) -> u128 {
+    (tweak << 1) ^ ((tweak >> 127) * 0x87)
+}
+
+/// Implements AES-XTS on top of AES-ECB.
+///
+/// The tweaks are computed in software, while the data is processed in
+/// chunks so that backends can make use of multi-block operations.
+#[allow(clippy::too_many_arguments)]
+fn aes_xts<C: Crypto + ?Sized>(
+    crypto: &mut C,
+    encrypt: bool,
+    tweak_encoding: XtsTweak,
+    (data_keyslot, tweak_keyslot): (usize, usize),
+    mut sector: u64,
+    sector_size: usize,
+    destination: &mut [u8],
+    source: &[u8],
+) -> Result<(), Error> {
+    if sector_size == 0
+        || sector_size % AES_BLOCK_SIZE != 0
+        || source.len() % sector_size != 0
+        || destination.len() != source.len()
+    {
+        return Err(Error::InvalidLength);
+    }
+
+    for (destination, source) in destination
+        .chunks_mut(sector_size)
+        .zip(source.chunks(sector_size))
+    {
+        let sector_bytes = match tweak_encoding {
+            XtsTweak::Standard => u128::from(sector).to_le_bytes(),
+            XtsTweak::Nintendo => u128::from(sector).to_be_bytes(),
+        };
+
+        let mut tweak = [0; AES_BLOCK_SIZE];
+        crypto.aes_ecb_encrypt(tweak_keyslot, &mut tweak, &sector_bytes)?;
+        let mut tweak = u128::from_le_bytes(tweak);
+
+        for (destination, source) in destination
+            .chunks_mut(XTS_CHUNK_SIZE)
+            .zip(source.chunks(XTS_CHUNK_SIZE))
+        {
+            let mut tweaks = [0; XTS_CHUNK_SIZE];
+            let mut buffer = [0; XTS_CHUNK_SIZE];
+
+            for ((t, b), s) in tweaks
+                .chunks_mut(AES_BLOCK_SIZE)
+                .zip(buffer.chunks_mut(AES_BLOCK_SIZE))
+                .zip(source.chunks(AES_BLOCK_SIZE))
+            {
+                t.copy_from_slice(&tweak.to_le_bytes());
+                for i in 0..AES_BLOCK_SIZE {
+                    b[i] = s[i] ^ t[i];
+                }
+
+                tweak = xts_double(tweak);
+            }
+
+            let buffer = &buffer[..source.len()];
+            if encrypt {
+                crypto.aes_ecb_encrypt(data_keyslot, destination, buffer)?;
+            } else {
+                crypto.aes_ecb_decrypt(data_keyslot, destination, buffer)?;
+            }
+
+            for (byte, t) in destination.iter_mut().zip(tweaks.iter()) {
+                *byte ^= *t;
+            }
+        }
+
+        sector += 1;
+    }
+
+    Ok(())
+}
+
+/// Enumeration of possible self-test failures.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelfTestError {
+    /// An operation failed.
+    Crypto(Error),
+    /// The result of an algorithm didn't match the expected result.
+    Mismatch(&'static str),
+}
+
+impl From<Error> for SelfTestError {
+    fn from(error: Error) -> Self {
+        SelfTestError::Crypto(error)
+    }
+}
+
+/// Fails with a mismatch if `actual` and `expected` differ.
+fn expect(name: &'static str, actual: &[u8], expected: &[u8]) -> Result<(), SelfTestError> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(SelfTestError::Mismatch(name))
+    }
+}
+
+/// Verifies AES-ECB with all key sizes.
+fn check_aes_ecb<C: Crypto + ?Sized>(crypto: &mut C, keyslot: usize) -> Result<(), SelfTestError> {
+    let vectors: [(&[u8], &[u8]); 3] = [
+        (&vectors::AES128_KEY, &vectors::AES128_CIPHERTEXT),
+        (&vectors::AES192_KEY, &vectors::AES192_CIPHERTEXT),
+        (&vectors::AES256_KEY, &vectors::AES256_CIPHERTEXT),
+    ];
+
+    for (key, ciphertext) in vectors.iter() {
+        let mut buffer = [0; AES_BLOCK_SIZE];
+        let mut plaintext = [0; AES_BLOCK_SIZE];
+
+        crypto.set_aes_key(keyslot, key)?;
+        crypto.aes_ecb_encrypt(keyslot, &mut buffer, &vectors::AES_PLAINTEXT)?;
+        expect("AES-ECB encryption", &buffer, ciphertext)?;
+
+        crypto.aes_ecb_decrypt(keyslot, &mut plaintext, &buffer)?;
+        expect("AES-ECB decryption", &plaintext, &vectors::AES_PLAINTEXT)?;
+    }
+
+    Ok(())
+}
+
+/// Verifies AES-CBC and AES-CTR.
+fn check_aes_cbc_ctr<C: Crypto + ?Sized>(
+    crypto: &mut C,
+    keyslot: usize,
+) -> Result<(), SelfTestError> {
+    let mut buffer = [0; 0x40];
+    let mut plaintext = [0; 0x40];
+
+    crypto.set_aes_key(keyslot, &vectors::SP800_38A_KEY)?;
+
+    crypto.aes_cbc_encrypt(
+        keyslot,
+        &vectors::CBC_IV,
+        &mut buffer,
+        &vectors::SP800_38A_PLAINTEXT,
+    )?;
+    expect("AES-CBC encryption", &buffer, &vectors::CBC_CIPHERTEXT)?;
+
+    crypto.aes_cbc_decrypt(keyslot, &vectors::CBC_IV, &mut plaintext, &buffer)?;
+    expect(
+        "AES-CBC decryption",
+        &plaintext,
+        &vectors::SP800_38A_PLAINTEXT,
+    )?;
+
+    crypto.aes_ctr(
+        keyslot,
+        &vectors::CTR_COUNTER,
+        &mut buffer,
+        &vectors::SP800_38A_PLAINTEXT,
+    )?;
+    expect("AES-CTR", &buffer, &vectors::CTR_CIPHERTEXT)?;
+
+    // Exercise the handling of a trailing partial block.
+    let mut partial = [0; 0x3C];
+    crypto.aes_ctr(
+        keyslot,
+        &vectors::CTR_COUNTER,
+        &mut partial,
+        &vectors::SP800_38A_PLAINTEXT[..0x3C],
+    )?;
+    expect(
+        "AES-CTR partial block",
+        &partial,
+        &vectors::CTR_CIPHERTEXT[..0x3C],
+    )?;
+
+    Ok(())
+}
+
+/// Verifies AES-XTS.
+fn check_aes_xts<C: Crypto + ?Sized>(
+    crypto: &mut C,
+    keyslots: (usize, usize),
+) -> Result<(), SelfTestError> {
+    let mut buffer = [0; 0x20];
+    let mut plaintext = [0; 0x20];
+
+    crypto.set_aes_key(keyslots.0, &vectors::XTS_KEY1)?;
+    crypto.set_aes_key(keyslots.1, &vectors::XTS_KEY2)?;
+
+    crypto.aes_xts_encrypt(
+        keyslots,
+        vectors::XTS_SECTOR,
+        0x20,
+        &mut buffer,
+        &vectors::XTS_PLAINTEXT,
+    )?;
+    expect("AES-XTS encryption", &buffer, &vectors::XTS_CIPHERTEXT)?;
+
+    crypto.aes_xts_decrypt(keyslots, vectors::XTS_SECTOR, 0x20, &mut plaintext, &buffer)?;
+    expect("AES-XTS decryption", &plaintext, &vectors::XTS_PLAINTEXT)?;
+
+    // Nintendo's tweak, over two sectors.
+    let mut buffer = [0; 0x40];
+    let mut plaintext = [0; 0x40];
+    let source = [vectors::XTS_PLAINTEXT[0]; 0x40];
+
+    crypto.aes_nintendo_xts_encrypt(keyslots, vectors::XTS_SECTOR, 0x20, &mut buffer, &source)?;
+    expect(
+        "AES-XTS encryption with Nintendo's tweak",
+        &buffer,
+        &vectors::NINTENDO_XTS_CIPHERTEXT,
+    )?;
+
+    crypto.aes_nintendo_xts_decrypt(
+        keyslots,
+        vectors::XTS_SECTOR,
+        0x20,
+        &mut plaintext,
+        &buffer,
+    )?;
+    expect(
+        "AES-XTS decryption with Nintendo's tweak",
+        &plaintext,
+        &source,
+    )?;
+
+    Ok(())
+}
+
+/// Verifies AES-CMAC.
+fn check_aes_cmac<C: Crypto + ?Sized>(crypto: &mut C, keyslot: usize) -> Result<(), SelfTestError> {
+    crypto.set_aes_key(keyslot, &vectors::SP800_38A_KEY)?;
+
+    for (size, tag) in vectors::CMAC_TAGS.iter() {
+        let mac = crypto.aes_cmac(keyslot, &vectors::SP800_38A_PLAINTEXT[..*size])?;
+        expect("AES-CMAC", &mac, tag)?;
+    }
+
+    Ok(())
+}
+
+/// Verifies SHA-256.
+fn check_sha256<C: Crypto + ?Sized>(crypto: &mut C) -> Result<(), SelfTestError> {
+    for (message, digest) in vectors::SHA256_DIGESTS.iter() {
+        expect("SHA-256", &crypto.sha256(message)?, digest)?;
+    }
+
+    Ok(())
+}
+
+/// Verifies RSA modular exponentiation.
+fn check_rsa<C: Crypto + ?Sized>(crypto: &mut C, keyslot: usize) -> Result<(), SelfTestError> {
+    let mut message = [0; 0x40];
+
+    crypto.set_rsa_key(keyslot, &vectors::RSA_MODULUS, &vectors::RSA_EXPONENT)?;
+    crypto.rsa_exp_mod(keyslot, &vectors::RSA_SIGNATURE, &mut message)?;
+    expect("RSA", &message, &vectors::RSA_MESSAGE)?;
+
+    Ok(())
+}
+
+/// Runs known-answer tests for all primitives against a backend.
+///
+/// The contents of the given AES and RSA keyslots are overwritten.
+pub fn self_test<C: Crypto + ?Sized>(
+    crypto: &mut C,
+    aes_keyslots: (usize, usize),
+    rsa_keyslot: usize,
+) -> Result<(), SelfTestError> {
+    check_aes_ecb(crypto, aes_keyslots.0)?;
+    check_aes_cbc_ctr(crypto, aes_keyslots.0)?;
+    check_aes_xts(crypto, aes_keyslots)?;
+    check_aes_cmac(crypto, aes_keyslots.0)?;
+    check_sha256(crypto)?;
+    check_rsa(crypto, rsa_keyslot)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{soft::SoftwareCrypto, *};
+
+    /// Tests AES-ECB with all key sizes.
+    #[test]
+    fn software_aes_ecb() {
+        assert_eq!(check_aes_ecb(&mut SoftwareCrypto::new(), 0), Ok(()));
+    }
+
+    /// Tests AES-CBC and AES-CTR.
+    #[test]
+    fn software_aes_cbc_ctr() {
+        assert_eq!(check_aes_cbc_ctr(&mut SoftwareCrypto::new(), 0), Ok(()));
+    }
+
+    /// Tests AES-XTS.
+    #[test]
+    fn software_aes_xts() {
+        assert_eq!(check_aes_xts(&mut SoftwareCrypto::new(), (0, 1)), Ok(()));
+    }
+
+    /// Tests AES-CMAC.
+    #[test]
+    fn software_aes_cmac() {
+        assert_eq!(check_aes_cmac(&mut SoftwareCrypto::new(), 0), Ok(()));
+    }
+
+    /// Tests SHA-256.
+    #[test]
+    fn software_sha256() {
+        assert_eq!(check_sha256(&mut SoftwareCrypto::new()), Ok(()));
+    }
+
+    /// Tests RSA modular exponentiation.
+    #[test]
+    fn software_rsa() {
+        assert_eq!(check_rsa(&mut SoftwareCrypto::new(), 1), Ok(()));
+    }
+
+    /// Tests that the full self-test passes.
+    #[test]
+    fn software_self_test() {
+        assert_eq!(self_test(&mut SoftwareCrypto::new(), (2, 3), 0), Ok(()));
+    }
+
+    /// Tests that multi-sector XTS equals sector-wise XTS.
+    #[test]
+    fn xts_multiple_sectors() {
+        let mut crypto = SoftwareCrypto::new();
+        crypto.set_aes_key(0, &vectors::XTS_KEY1).unwrap();
+        crypto.set_aes_key(1, &vectors::XTS_KEY2).unwrap();
+
+        let mut source = [0; 0x600];
+        for (i, byte) in source.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+
+        let mut whole = [0; 0x600];
+        crypto
+            .aes_xts_encrypt((0, 1), 5, 0x300, &mut whole, &source)
+            .unwrap();
+
+        let mut sector = [0; 0x300];
+        crypto
+            .aes_xts_encrypt((0, 1), 6, 0x300, &mut sector, &source[0x300..])
+            .unwrap();
+        assert_eq!(&whole[0x300..], &sector[..]);
+
+        let mut plaintext = [0; 0x600];
+        crypto
+            .aes_xts_decrypt((0, 1), 5, 0x300, &mut plaintext, &whole)
+            .unwrap();
+        assert_eq!(&plaintext[..], &source[..]);
+    }
+
+    /// Tests Nintendo's XTS tweak with the sector size of the BIS partitions.
+    #[test]
+    fn nintendo_xts_sectors() {
+        let mut crypto = SoftwareCrypto::new();
+        crypto.set_aes_key(0, &vectors::XTS_KEY1).unwrap();
+        crypto.set_aes_key(1, &vectors::XTS_KEY2).unwrap();
+
+        let mut source = [0; 2 * NINTENDO_XTS_SECTOR_SIZE];
+        for (i, byte) in source.iter_mut().enumerate() {
+            *byte = (i * 7) as u8;
+        }
+
+        let mut whole = [0; 2 * NINTENDO_XTS_SECTOR_SIZE];
+        crypto
+            .aes_nintendo_xts_encrypt((0, 1), 1, NINTENDO_XTS_SECTOR_SIZE, &mut whole, &source)
+            .unwrap();
+
+        let mut sector = [0; NINTENDO_XTS_SECTOR_SIZE];
+        crypto
+            .aes_nintendo_xts_encrypt(
+                (0, 1),
+                2,
+                NINTENDO_XTS_SECTOR_SIZE,
+                &mut sector,
+                &source[NINTENDO_XTS_SECTOR_SIZE..],
+            )
+            .unwrap();
+        assert_eq!(&whole[NINTENDO_XTS_SECTOR_SIZE..], &sector[..]);
+
+        let mut standard = [0; 2 * NINTENDO_XTS_SECTOR_SIZE];
+        crypto
+            .aes_xts_encrypt((0, 1), 1, NINTENDO_XTS_SECTOR_SIZE, &mut standard, &source)
+            .unwrap();
+        assert_ne!(&whole[..], &standard[..]);
+
+        let mut plaintext = [0; 2 * NINTENDO_XTS_SECTOR_SIZE];
+        crypto
+            .aes_nintendo_xts_decrypt((0, 1), 1, NINTENDO_XTS_SECTOR_SIZE, &mut plaintext, &whole)
+            .unwrap();
+        assert_eq!(&plaintext[..], &source[..]);
+    }
+
+    /// Tests that invalid arguments are rejected.
+    #[test]
+    fn invalid_arguments() {
+        let mut crypto = SoftwareCrypto::new();
+        let mut buffer = [0; 0x20];
+
+        assert_eq!(
+            crypto.set_aes_key(AES_KEYSLOT_COUNT, &vectors::AES128_KEY),
+            Err(Error::InvalidKeyslot)
+        );
+        assert_eq!(
+            crypto.set_aes_key(0, &vectors::AES128_KEY[..0xF]),
+            Err(Error::InvalidKeySize)
+        );
+        assert_eq!(
+            crypto.aes_ecb_encrypt(0, &mut buffer, &[0; 0x20]),
+            Err(Error::KeyNotSet)
+        );
+
+        crypto.set_aes_key(0, &vectors::AES128_KEY).unwrap();
+        assert_eq!(
+            crypto.aes_ecb_encrypt(0, &mut buffer[..0x18], &[0; 0x18]),
+            Err(Error::InvalidLength)
+        );
+        assert_eq!(
+            crypto.aes_cbc_encrypt(0, &[0; AES_BLOCK_SIZE], &mut buffer, &[0; 0x10]),
+            Err(Error::InvalidLength)
+        );
+        assert_eq!(
+            crypto.aes_xts_encrypt((0, 0), 0, 0x18, &mut buffer, &[0; 0x20]),
+            Err(Error::InvalidLength)
+        );
+        assert_eq!(
+            crypto.set_rsa_key(0, &vectors::RSA_MODULUS[..0x3C], &vectors::RSA_EXPONENT),
+            Err(Error::InvalidKeySize)
+        );
+    }
+
+    /// Tests that wrapped keys are unwrapped into the destination keyslot.
+    #[test]
+    fn unwrap_key() {
+        let mut crypto = SoftwareCrypto::new();
+        let mut wrapped = [0; 0x10];
+
+        crypto.set_aes_key(0, &vectors::AES128_KEY).unwrap();
+        crypto
+            .aes_ecb_encrypt(0, &mut wrapped, &vectors::SP800_38A_KEY)
+            .unwrap();
+        crypto.unwrap_aes_key(1, 0, &wrapped).unwrap();
+
+        let mut ciphertext = [0; 0x40];
+        crypto
+            .aes_cbc_encrypt(
+                1,
+                &vectors::CBC_IV,
+                &mut ciphertext,
+                &vectors::SP800_38A_PLAINTEXT,
+            )
+            .unwrap();
+        assert_eq!(&ciphertext[..], &vectors::CBC_CIPHERTEXT[..]);
+    }
+}
@@ -0,0 +1,288 @@
+//! Security Engine backend for the crypto primitives.
+//!
+//! # Description
+//!
+//! This backend forwards all operations to the [`SecurityEngine`]. As the SE
+//! doesn't track the sizes of the keys stored in its AES keyslots, they are
+//! recorded by the backend whenever a key is set. Keys that were set through
+//! other means, e.g. the SBK, must be announced using [`register_aes_key`].
+//!
+//! Keys are written through the [`KeyslotManager`], so the backend can only
+//! modify keyslots which have been reserved or allocated beforehand, and
+//! never keyslots that are locked or owned by the Boot ROM.
+//!
+//! Arguments are validated before they are passed to the SE. Errors reported
+//! by the SE itself are forwarded as [`Error::Engine`].
+//!
+//! [`SecurityEngine`]: ../../se/struct.SecurityEngine.html
+//! [`KeyslotManager`]: ../../se/keyslot/struct.KeyslotManager.html
+//! [`register_aes_key`]: struct.SeCrypto.html#method.register_aes_key
+//! [`Error::Engine`]: ../enum.Error.html#variant.Engine
+
+use super::{Crypto, Error, AES_BLOCK_SIZE, SHA256_SIZE};
+use crate::se::{
+    aes::KeySize, keyslot::KeyslotManager, SecurityEngine, KEYSIZE_RSA_MAX, KEYSLOT_AES_MAX,
+    KEYSLOT_RSA_MAX,
+};
+
+/// Gets the key size for a key of the given length.
+fn key_size(length: usize) -> Result<KeySize, Error> {
+    match length {
+        0x10 => Ok(KeySize::Aes128),
+        0x18 => Ok(KeySize::Aes192),
+        0x20 => Ok(KeySize::Aes256),
+        _ => Err(Error::InvalidKeySize),
+    }
+}
+
+/// A Security Engine backend for the [`Crypto`] trait.
+///
+/// [`Crypto`]: ../trait.Crypto.html
+pub struct SeCrypto<'a> {
+    /// The Security Engine to use.
+    engine: &'a mut SecurityEngine,
+    /// The manager of the keyslots.
+    manager: &'a mut KeyslotManager,
+    /// The sizes of the keys in the AES keyslots.
+    key_sizes: [Option<KeySize>; KEYSLOT_AES_MAX],
+    /// The sizes of the moduli in the RSA keyslots.
+    modulus_sizes: [usize; KEYSLOT_RSA_MAX],
+}
+
+impl<'a> SeCrypto<'a> {
+    /// Creates a new backend for the Security Engine.
+    ///
+    /// Keyslots have to be allocated from `manager` before they can be used
+    /// with the backend.
+    pub fn new(engine: &'a mut SecurityEngine, manager: &'a mut KeyslotManager) -> Self {
+        SeCrypto {
+            engine,
+            manager,
+            key_sizes: [None; KEYSLOT_AES_MAX],
+            modulus_sizes: [0; KEYSLOT_RSA_MAX],
+        }
+    }
+
+    /// Announces a key that has been loaded into a keyslot by other means.
+    pub fn register_aes_key(&mut self, keyslot: usize, key_size: KeySize) -> Result<(), Error> {
+        let slot = self
+            .key_sizes
+            .get_mut(keyslot)
+            .ok_or(Error::InvalidKeyslot)?;
+
+        *slot = Some(key_size);
+
+        Ok(())
+    }
+
+    /// Gets the size of the key in an AES keyslot.
+    fn key_size(&self, keyslot: usize) -> Result<KeySize, Error> {
+        self.key_sizes
+            .get(keyslot)
+            .ok_or(Error::InvalidKeyslot)?
+            .ok_or(Error::KeyNotSet)
+    }
+
+    /// Gets the size of the key in an AES keyslot and validates a block-aligned operation.
+    fn key_size_for(
+        &self,
+        keyslot: usize,
+        destination: &[u8],
+        source: &[u8],
+    ) -> Result<KeySize, Error> {
+        let key_size = self.key_size(keyslot)?;
+
+        if destination.len() != source.len() || source.len() % AES_BLOCK_SIZE != 0 {
+            return Err(Error::InvalidLength);
+        }
+
+        Ok(key_size)
+    }
+}
+
+impl<'a> Crypto for SeCrypto<'a> {
+    fn set_aes_key(&mut self, keyslot: usize, key: &[u8]) -> Result<(), Error> {
+        if keyslot >= KEYSLOT_AES_MAX {
+            return Err(Error::InvalidKeyslot);
+        }
+
+        let key_size = key_size(key.len())?;
+
+        let handle = self.manager.aes_keyslot(keyslot)?;
+        self.manager.set_aes_key(self.engine, &handle, key)?;
+        self.key_sizes[keyslot] = Some(key_size);
+
+        Ok(())
+    }
+
+    fn unwrap_aes_key(
+        &mut self,
+        destination: usize,
+        source: usize,
+        wrapped_key: &[u8],
+    ) -> Result<(), Error> {
+        if destination >= KEYSLOT_AES_MAX {
+            return Err(Error::InvalidKeyslot);
+        }
+
+        if self.key_size(source)? != KeySize::Aes128 {
+            return Err(Error::InvalidKeySize);
+        }
+
+        let key_size = key_size(wrapped_key.len())?;
+        if wrapped_key.len() % AES_BLOCK_SIZE != 0 {
+            return Err(Error::InvalidKeySize);
+        }
+
+        let destination_handle = self.manager.aes_keyslot(destination)?;
+        let source_handle = self.manager.aes_keyslot(source)?;
+        self.manager.unwrap_aes_key(
+            self.engine,
+            &destination_handle,
+            &source_handle,
+            wrapped_key,
+        )?;
+        self.key_sizes[destination] = Some(key_size);
+
+        Ok(())
+    }
+
+    fn aes_ecb_encrypt(
+        &mut self,
+        keyslot: usize,
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        let key_size = self.key_size_for(keyslot, destination, source)?;
+
+        self.engine
+            .aes_ecb_encrypt(keyslot, key_size, destination, source)?;
+
+        Ok(())
+    }
+
+    fn aes_ecb_decrypt(
+        &mut self,
+        keyslot: usize,
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        let key_size = self.key_size_for(keyslot, destination, source)?;
+
+        self.engine
+            .aes_ecb_decrypt(keyslot, key_size, destination, source)?;
+
+        Ok(())
+    }
+
+    fn aes_cbc_encrypt(
+        &mut self,
+        keyslot: usize,
+        iv: &[u8; AES_BLOCK_SIZE],
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        let key_size = self.key_size_for(keyslot, destination, source)?;
+
+        self.engine
+            .aes_cbc_encrypt(keyslot, key_size, iv, destination, source)?;
+
+        Ok(())
+    }
+
+    fn aes_cbc_decrypt(
+        &mut self,
+        keyslot: usize,
+        iv: &[u8; AES_BLOCK_SIZE],
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        let key_size = self.key_size_for(keyslot, destination, source)?;
+
+        self.engine
+            .aes_cbc_decrypt(keyslot, key_size, iv, destination, source)?;
+
+        Ok(())
+    }
+
+    fn aes_ctr(
+        &mut self,
+        keyslot: usize,
+        ctr: &[u8; AES_BLOCK_SIZE],
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        let key_size = self.key_size(keyslot)?;
+
+        if destination.len() != source.len() {
+            return Err(Error::InvalidLength);
+        }
+
+        self.engine
+            .aes_ctr(keyslot, key_size, ctr, destination, source)?;
+
+        Ok(())
+    }
+
+    fn aes_cmac(&mut self, keyslot: usize, data: &[u8]) -> Result<[u8; AES_BLOCK_SIZE], Error> {
+        let key_size = self.key_size(keyslot)?;
+
+        Ok(self.engine.aes_cmac(keyslot, key_size, data)?)
+    }
+
+    fn sha256(&mut self, data: &[u8]) -> Result<[u8; SHA256_SIZE], Error> {
+        Ok(self.engine.sha256(data)?)
+    }
+
+    fn set_rsa_key(
+        &mut self,
+        keyslot: usize,
+        modulus: &[u8],
+        exponent: &[u8],
+    ) -> Result<(), Error> {
+        if keyslot >= KEYSLOT_RSA_MAX {
+            return Err(Error::InvalidKeyslot);
+        }
+
+        if modulus.is_empty()
+            || modulus.len() > KEYSIZE_RSA_MAX
+            || modulus.len() % 0x40 != 0
+            || exponent.is_empty()
+            || exponent.len() > KEYSIZE_RSA_MAX
+            || exponent.len() % 4 != 0
+        {
+            return Err(Error::InvalidKeySize);
+        }
+
+        let handle = self.manager.rsa_keyslot(keyslot)?;
+        self.manager
+            .set_rsa_key(self.engine, &handle, modulus, exponent)?;
+        self.modulus_sizes[keyslot] = modulus.len();
+
+        Ok(())
+    }
+
+    fn rsa_exp_mod(
+        &mut self,
+        keyslot: usize,
+        input: &[u8],
+        output: &mut [u8],
+    ) -> Result<(), Error> {
+        let modulus_size = *self
+            .modulus_sizes
+            .get(keyslot)
+            .ok_or(Error::InvalidKeyslot)?;
+
+        if modulus_size == 0 {
+            return Err(Error::KeyNotSet);
+        }
+
+        if input.len() != modulus_size || output.len() != modulus_size {
+            return Err(Error::InvalidLength);
+        }
+
+        self.engine.rsa_exp_mod(keyslot, input, output)?;
+
+        Ok(())
+    }
+}
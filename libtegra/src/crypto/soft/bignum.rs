@@ -0,0 +1,128 @@
+//! Minimal fixed-size big integer arithmetic for RSA.
+//!
+//! Numbers are stored as little-endian arrays of 32-bit limbs, large enough
+//! to hold 2048-bit values. Only the operations required for public key RSA
+//! operations are implemented, with no attempt at being constant-time.
+
+/// The number of limbs in a big integer.
+pub const LIMBS: usize = 0x40;
+
+/// A 2048-bit unsigned integer.
+pub type Uint = [u32; LIMBS];
+
+/// Parses a big-endian byte buffer of at most 256 bytes into an integer.
+pub fn from_be_bytes(bytes: &[u8]) -> Uint {
+    let mut value = [0; LIMBS];
+
+    for (i, byte) in bytes.iter().rev().enumerate() {
+        value[i / 4] |= u32::from(*byte) << (8 * (i % 4));
+    }
+
+    value
+}
+
+/// Writes an integer into a big-endian byte buffer, truncating high bytes.
+pub fn to_be_bytes(value: &Uint, bytes: &mut [u8]) {
+    for (i, byte) in bytes.iter_mut().rev().enumerate() {
+        *byte = (value[i / 4] >> (8 * (i % 4))) as u8;
+    }
+}
+
+/// Checks whether `a >= b`.
+fn greater_or_equal(a: &Uint, b: &Uint) -> bool {
+    for (x, y) in a.iter().zip(b.iter()).rev() {
+        if x != y {
+            return x > y;
+        }
+    }
+
+    true
+}
+
+/// Computes `a -= b`, wrapping on underflow.
+fn sub_assign(a: &mut Uint, b: &Uint) {
+    let mut borrow = 0;
+
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        let difference = u64::from(*x)
+            .wrapping_sub(u64::from(*y))
+            .wrapping_sub(borrow);
+
+        *x = difference as u32;
+        borrow = (difference >> 63) & 1;
+    }
+}
+
+/// Computes `a += b` and returns the carry.
+fn add_assign(a: &mut Uint, b: &Uint) -> bool {
+    let mut carry = 0;
+
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        let sum = u64::from(*x) + u64::from(*y) + carry;
+
+        *x = sum as u32;
+        carry = sum >> 32;
+    }
+
+    carry != 0
+}
+
+/// Computes `a <<= 1` and returns the bit shifted out.
+fn shl1_assign(a: &mut Uint) -> bool {
+    let mut carry = 0;
+
+    for x in a.iter_mut() {
+        let next = *x >> 31;
+
+        *x = (*x << 1) | carry;
+        carry = next;
+    }
+
+    carry != 0
+}
+
+/// Computes `a * b mod modulus` for `b < modulus`.
+fn mod_mul(a: &Uint, b: &Uint, modulus: &Uint) -> Uint {
+    let mut result = [0; LIMBS];
+
+    for i in (0..LIMBS * 32).rev() {
+        if shl1_assign(&mut result) || greater_or_equal(&result, modulus) {
+            sub_assign(&mut result, modulus);
+        }
+
+        if (a[i / 32] >> (i % 32)) & 1 != 0
+            && (add_assign(&mut result, b) || greater_or_equal(&result, modulus))
+        {
+            sub_assign(&mut result, modulus);
+        }
+    }
+
+    result
+}
+
+/// Computes `base ^ exponent mod modulus`.
+pub fn mod_exp(base: &Uint, exponent: &Uint, modulus: &Uint) -> Uint {
+    let mut result = [0; LIMBS];
+    result[0] = 1;
+
+    // Reduce the base by multiplying it with one.
+    let base = mod_mul(base, &result, modulus);
+
+    let mut started = false;
+    for i in (0..LIMBS * 32).rev() {
+        if started {
+            result = mod_mul(&result, &result, modulus);
+        }
+
+        if (exponent[i / 32] >> (i % 32)) & 1 != 0 {
+            result = if started {
+                mod_mul(&result, &base, modulus)
+            } else {
+                base
+            };
+            started = true;
+        }
+    }
+
+    result
+}
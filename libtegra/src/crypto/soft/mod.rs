@@ -0,0 +1,363 @@
+//! Pure-Rust software implementation of the crypto primitives.
+//!
+//! # Description
+//!
+//! The software backend emulates the keyslots of the Security Engine in
+//! memory and mirrors its restrictions on key and buffer sizes. It allows
+//! code built on top of the [`Crypto`] trait to be tested on the host.
+//!
+//! # Implementation
+//!
+//! - AES is implemented in the [`aes`] module, SHA-256 in [`sha256`] and
+//! the big integer arithmetic for RSA in [`bignum`].
+//!
+//! - None of the implementations are hardened against side channels, so
+//! this backend should not be used with secret keys on the device.
+//!
+//! [`Crypto`]: ../trait.Crypto.html
+//! [`aes`]: aes/index.html
+//! [`sha256`]: sha256/index.html
+//! [`bignum`]: bignum/index.html
+
+pub mod aes;
+pub mod bignum;
+pub mod sha256;
+
+use self::{
+    aes::Aes,
+    bignum::{from_be_bytes, mod_exp, to_be_bytes, Uint},
+};
+use super::{Crypto, Error, AES_BLOCK_SIZE, AES_KEYSLOT_COUNT, RSA_KEYSLOT_COUNT, SHA256_SIZE};
+
+/// An RSA key stored in a keyslot.
+#[derive(Clone, Copy)]
+struct RsaKey {
+    /// The modulus.
+    modulus: Uint,
+    /// The exponent.
+    exponent: Uint,
+    /// The size of the modulus in bytes.
+    size: usize,
+}
+
+/// A software backend for the [`Crypto`] trait.
+///
+/// [`Crypto`]: ../trait.Crypto.html
+pub struct SoftwareCrypto {
+    /// The expanded keys of the AES keyslots.
+    aes_keys: [Option<Aes>; AES_KEYSLOT_COUNT],
+    /// The keys of the RSA keyslots.
+    rsa_keys: [Option<RsaKey>; RSA_KEYSLOT_COUNT],
+}
+
+impl SoftwareCrypto {
+    /// Creates a new software backend with empty keyslots.
+    pub fn new() -> Self {
+        SoftwareCrypto {
+            aes_keys: [None; AES_KEYSLOT_COUNT],
+            rsa_keys: [None; RSA_KEYSLOT_COUNT],
+        }
+    }
+
+    /// Gets the AES key of a keyslot.
+    fn aes_key(&self, keyslot: usize) -> Result<&Aes, Error> {
+        self.aes_keys
+            .get(keyslot)
+            .ok_or(Error::InvalidKeyslot)?
+            .as_ref()
+            .ok_or(Error::KeyNotSet)
+    }
+
+    /// Gets the AES key of a keyslot and validates a block-aligned operation.
+    fn aes_key_for(
+        &self,
+        keyslot: usize,
+        destination: &[u8],
+        source: &[u8],
+    ) -> Result<&Aes, Error> {
+        let key = self.aes_key(keyslot)?;
+
+        if destination.len() != source.len() || source.len() % AES_BLOCK_SIZE != 0 {
+            return Err(Error::InvalidLength);
+        }
+
+        Ok(key)
+    }
+}
+
+impl Default for SoftwareCrypto {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Copies a block out of a block-sized slice.
+fn to_block(slice: &[u8]) -> [u8; AES_BLOCK_SIZE] {
+    let mut block = [0; AES_BLOCK_SIZE];
+    block.copy_from_slice(slice);
+    block
+}
+
+impl Crypto for SoftwareCrypto {
+    fn set_aes_key(&mut self, keyslot: usize, key: &[u8]) -> Result<(), Error> {
+        let slot = self
+            .aes_keys
+            .get_mut(keyslot)
+            .ok_or(Error::InvalidKeyslot)?;
+
+        *slot = Some(Aes::new(key).ok_or(Error::InvalidKeySize)?);
+
+        Ok(())
+    }
+
+    fn unwrap_aes_key(
+        &mut self,
+        destination: usize,
+        source: usize,
+        wrapped_key: &[u8],
+    ) -> Result<(), Error> {
+        if destination >= AES_KEYSLOT_COUNT {
+            return Err(Error::InvalidKeyslot);
+        }
+
+        if self.aes_key(source)?.key_size() != 0x10 {
+            return Err(Error::InvalidKeySize);
+        }
+
+        let mut key = [0; 0x20];
+        let key = key
+            .get_mut(..wrapped_key.len())
+            .ok_or(Error::InvalidKeySize)?;
+        self.aes_ecb_decrypt(source, key, wrapped_key)?;
+
+        self.set_aes_key(destination, key)
+    }
+
+    fn aes_ecb_encrypt(
+        &mut self,
+        keyslot: usize,
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        let key = self.aes_key_for(keyslot, destination, source)?;
+
+        for (dst, src) in destination
+            .chunks_mut(AES_BLOCK_SIZE)
+            .zip(source.chunks(AES_BLOCK_SIZE))
+        {
+            let mut block = to_block(src);
+            key.encrypt_block(&mut block);
+            dst.copy_from_slice(&block);
+        }
+
+        Ok(())
+    }
+
+    fn aes_ecb_decrypt(
+        &mut self,
+        keyslot: usize,
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        let key = self.aes_key_for(keyslot, destination, source)?;
+
+        for (dst, src) in destination
+            .chunks_mut(AES_BLOCK_SIZE)
+            .zip(source.chunks(AES_BLOCK_SIZE))
+        {
+            let mut block = to_block(src);
+            key.decrypt_block(&mut block);
+            dst.copy_from_slice(&block);
+        }
+
+        Ok(())
+    }
+
+    fn aes_cbc_encrypt(
+        &mut self,
+        keyslot: usize,
+        iv: &[u8; AES_BLOCK_SIZE],
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        let key = self.aes_key_for(keyslot, destination, source)?;
+        let mut iv = *iv;
+
+        for (dst, src) in destination
+            .chunks_mut(AES_BLOCK_SIZE)
+            .zip(source.chunks(AES_BLOCK_SIZE))
+        {
+            for (byte, input) in iv.iter_mut().zip(src.iter()) {
+                *byte ^= *input;
+            }
+
+            key.encrypt_block(&mut iv);
+            dst.copy_from_slice(&iv);
+        }
+
+        Ok(())
+    }
+
+    fn aes_cbc_decrypt(
+        &mut self,
+        keyslot: usize,
+        iv: &[u8; AES_BLOCK_SIZE],
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        let key = self.aes_key_for(keyslot, destination, source)?;
+        let mut iv = *iv;
+
+        for (dst, src) in destination
+            .chunks_mut(AES_BLOCK_SIZE)
+            .zip(source.chunks(AES_BLOCK_SIZE))
+        {
+            let mut block = to_block(src);
+            key.decrypt_block(&mut block);
+
+            for (byte, input) in block.iter_mut().zip(iv.iter()) {
+                *byte ^= *input;
+            }
+
+            iv = to_block(src);
+            dst.copy_from_slice(&block);
+        }
+
+        Ok(())
+    }
+
+    fn aes_ctr(
+        &mut self,
+        keyslot: usize,
+        ctr: &[u8; AES_BLOCK_SIZE],
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        let key = self.aes_key(keyslot)?;
+
+        if destination.len() != source.len() {
+            return Err(Error::InvalidLength);
+        }
+
+        let mut ctr = u128::from_be_bytes(*ctr);
+
+        for (dst, src) in destination
+            .chunks_mut(AES_BLOCK_SIZE)
+            .zip(source.chunks(AES_BLOCK_SIZE))
+        {
+            let mut keystream = ctr.to_be_bytes();
+            key.encrypt_block(&mut keystream);
+
+            for ((byte, input), key) in dst.iter_mut().zip(src.iter()).zip(keystream.iter()) {
+                *byte = *input ^ *key;
+            }
+
+            ctr = ctr.wrapping_add(1);
+        }
+
+        Ok(())
+    }
+
+    fn aes_cmac(&mut self, keyslot: usize, data: &[u8]) -> Result<[u8; AES_BLOCK_SIZE], Error> {
+        let key = self.aes_key(keyslot)?;
+
+        let double = |block: u128| (block << 1) ^ ((block >> 127) * 0x87);
+
+        // Derive the subkeys.
+        let mut l = [0; AES_BLOCK_SIZE];
+        key.encrypt_block(&mut l);
+        let k1 = double(u128::from_be_bytes(l));
+        let k2 = double(k1);
+
+        // Split off the last block, which may be incomplete or even empty.
+        let last_size = match data.len() % AES_BLOCK_SIZE {
+            0 if !data.is_empty() => AES_BLOCK_SIZE,
+            size => size,
+        };
+        let (blocks, last) = data.split_at(data.len() - last_size);
+
+        let mut mac = [0; AES_BLOCK_SIZE];
+        for block in blocks.chunks(AES_BLOCK_SIZE) {
+            for (byte, input) in mac.iter_mut().zip(block.iter()) {
+                *byte ^= *input;
+            }
+
+            key.encrypt_block(&mut mac);
+        }
+
+        let mut block = [0; AES_BLOCK_SIZE];
+        block[..last.len()].copy_from_slice(last);
+
+        let subkey = if last.len() == AES_BLOCK_SIZE {
+            k1
+        } else {
+            block[last.len()] = 0x80;
+            k2
+        };
+
+        let block = (u128::from_be_bytes(block) ^ subkey).to_be_bytes();
+        for (byte, input) in mac.iter_mut().zip(block.iter()) {
+            *byte ^= *input;
+        }
+        key.encrypt_block(&mut mac);
+
+        Ok(mac)
+    }
+
+    fn sha256(&mut self, data: &[u8]) -> Result<[u8; SHA256_SIZE], Error> {
+        Ok(sha256::sha256(data))
+    }
+
+    fn set_rsa_key(
+        &mut self,
+        keyslot: usize,
+        modulus: &[u8],
+        exponent: &[u8],
+    ) -> Result<(), Error> {
+        let slot = self
+            .rsa_keys
+            .get_mut(keyslot)
+            .ok_or(Error::InvalidKeyslot)?;
+
+        if modulus.is_empty()
+            || modulus.len() > 0x100
+            || modulus.len() % 0x40 != 0
+            || exponent.is_empty()
+            || exponent.len() > 0x100
+            || exponent.len() % 4 != 0
+        {
+            return Err(Error::InvalidKeySize);
+        }
+
+        *slot = Some(RsaKey {
+            modulus: from_be_bytes(modulus),
+            exponent: from_be_bytes(exponent),
+            size: modulus.len(),
+        });
+
+        Ok(())
+    }
+
+    fn rsa_exp_mod(
+        &mut self,
+        keyslot: usize,
+        input: &[u8],
+        output: &mut [u8],
+    ) -> Result<(), Error> {
+        let key = self
+            .rsa_keys
+            .get(keyslot)
+            .ok_or(Error::InvalidKeyslot)?
+            .as_ref()
+            .ok_or(Error::KeyNotSet)?;
+
+        if input.len() != key.size || output.len() != key.size {
+            return Err(Error::InvalidLength);
+        }
+
+        let result = mod_exp(&from_be_bytes(input), &key.exponent, &key.modulus);
+        to_be_bytes(&result, output);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+//! Known-answer test vectors for the crypto self-test.
+//!
+//! The vectors are taken from FIPS-197, NIST SP 800-38A, RFC 4493,
+//! FIPS 180-4 and IEEE 1619. The RSA vector uses a 512-bit test key. The
+//! vector for Nintendo's XTS tweak has been computed with OpenSSL.
+
+/// The AES-128 key from FIPS-197, appendix C.1.
+pub const AES128_KEY: [u8; 0x10] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+];
+
+/// The AES-192 key from FIPS-197, appendix C.2.
+pub const AES192_KEY: [u8; 0x18] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+];
+
+/// The AES-256 key from FIPS-197, appendix C.3.
+pub const AES256_KEY: [u8; 0x20] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F,
+];
+
+/// The plaintext from FIPS-197, appendix C.
+pub const AES_PLAINTEXT: [u8; 0x10] = [
+    0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF,
+];
+
+/// The AES-128 ciphertext from FIPS-197, appendix C.1.
+pub const AES128_CIPHERTEXT: [u8; 0x10] = [
+    0x69, 0xC4, 0xE0, 0xD8, 0x6A, 0x7B, 0x04, 0x30, 0xD8, 0xCD, 0xB7, 0x80, 0x70, 0xB4, 0xC5, 0x5A,
+];
+
+/// The AES-192 ciphertext from FIPS-197, appendix C.2.
+pub const AES192_CIPHERTEXT: [u8; 0x10] = [
+    0xDD, 0xA9, 0x7C, 0xA4, 0x86, 0x4C, 0xDF, 0xE0, 0x6E, 0xAF, 0x70, 0xA0, 0xEC, 0x0D, 0x71, 0x91,
+];
+
+/// The AES-256 ciphertext from FIPS-197, appendix C.3.
+pub const AES256_CIPHERTEXT: [u8; 0x10] = [
+    0x8E, 0xA2, 0xB7, 0xCA, 0x51, 0x67, 0x45, 0xBF, 0xEA, 0xFC, 0x49, 0x90, 0x4B, 0x49, 0x60, 0x89,
+];
+
+/// The AES-128 key from NIST SP 800-38A and RFC 4493.
+pub const SP800_38A_KEY: [u8; 0x10] = [
+    0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C,
+];
+
+/// The plaintext from NIST SP 800-38A and RFC 4493.
+pub const SP800_38A_PLAINTEXT: [u8; 0x40] = [
+    0x6B, 0xC1, 0xBE, 0xE2, 0x2E, 0x40, 0x9F, 0x96, 0xE9, 0x3D, 0x7E, 0x11, 0x73, 0x93, 0x17, 0x2A,
+    0xAE, 0x2D, 0x8A, 0x57, 0x1E, 0x03, 0xAC, 0x9C, 0x9E, 0xB7, 0x6F, 0xAC, 0x45, 0xAF, 0x8E, 0x51,
+    0x30, 0xC8, 0x1C, 0x46, 0xA3, 0x5C, 0xE4, 0x11, 0xE5, 0xFB, 0xC1, 0x19, 0x1A, 0x0A, 0x52, 0xEF,
+    0xF6, 0x9F, 0x24, 0x45, 0xDF, 0x4F, 0x9B, 0x17, 0xAD, 0x2B, 0x41, 0x7B, 0xE6, 0x6C, 0x37, 0x10,
+];
+
+/// The IV from NIST SP 800-38A, F.2.1.
+pub const CBC_IV: [u8; 0x10] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+];
+
+/// The AES-128-CBC ciphertext from NIST SP 800-38A, F.2.1.
+pub const CBC_CIPHERTEXT: [u8; 0x40] = [
+    0x76, 0x49, 0xAB, 0xAC, 0x81, 0x19, 0xB2, 0x46, 0xCE, 0xE9, 0x8E, 0x9B, 0x12, 0xE9, 0x19, 0x7D,
+    0x50, 0x86, 0xCB, 0x9B, 0x50, 0x72, 0x19, 0xEE, 0x95, 0xDB, 0x11, 0x3A, 0x91, 0x76, 0x78, 0xB2,
+    0x73, 0xBE, 0xD6, 0xB8, 0xE3, 0xC1, 0x74, 0x3B, 0x71, 0x16, 0xE6, 0x9E, 0x22, 0x22, 0x95, 0x16,
+    0x3F, 0xF1, 0xCA, 0xA1, 0x68, 0x1F, 0xAC, 0x09, 0x12, 0x0E, 0xCA, 0x30, 0x75, 0x86, 0xE1, 0xA7,
+];
+
+/// The initial counter from NIST SP 800-38A, F.5.1.
+pub const CTR_COUNTER: [u8; 0x10] = [
+    0xF0, 0xF1, 0xF2, 0xF3, 0xF4, 0xF5, 0xF6, 0xF7, 0xF8, 0xF9, 0xFA, 0xFB, 0xFC, 0xFD, 0xFE, 0xFF,
+];
+
+/// The AES-128-CTR ciphertext from NIST SP 800-38A, F.5.1.
+pub const CTR_CIPHERTEXT: [u8; 0x40] = [
+    0x87, 0x4D, 0x61, 0x91, 0xB6, 0x20, 0xE3, 0x26, 0x1B, 0xEF, 0x68, 0x64, 0x99, 0x0D, 0xB6, 0xCE,
+    0x98, 0x06, 0xF6, 0x6B, 0x79, 0x70, 0xFD, 0xFF, 0x86, 0x17, 0x18, 0x7B, 0xB9, 0xFF, 0xFD, 0xFF,
+    0x5A, 0xE4, 0xDF, 0x3E, 0xDB, 0xD5, 0xD3, 0x5E, 0x5B, 0x4F, 0x09, 0x02, 0x0D, 0xB0, 0x3E, 0xAB,
+    0x1E, 0x03, 0x1D, 0xDA, 0x2F, 0xBE, 0x03, 0xD1, 0x79, 0x21, 0x70, 0xA0, 0xF3, 0x00, 0x9C, 0xEE,
+];
+
+/// The message lengths and AES-CMAC tags from RFC 4493, section 4.
+pub const CMAC_TAGS: [(usize, [u8; 0x10]); 4] = [
+    (
+        0,
+        [
+            0xBB, 0x1D, 0x69, 0x29, 0xE9, 0x59, 0x37, 0x28, 0x7F, 0xA3, 0x7D, 0x12, 0x9B, 0x75,
+            0x67, 0x46,
+        ],
+    ),
+    (
+        16,
+        [
+            0x07, 0x0A, 0x16, 0xB4, 0x6B, 0x4D, 0x41, 0x44, 0xF7, 0x9B, 0xDD, 0x9D, 0xD0, 0x4A,
+            0x28, 0x7C,
+        ],
+    ),
+    (
+        40,
+        [
+            0xDF, 0xA6, 0x67, 0x47, 0xDE, 0x9A, 0xE6, 0x30, 0x30, 0xCA, 0x32, 0x61, 0x14, 0x97,
+            0xC8, 0x27,
+        ],
+    ),
+    (
+        64,
+        [
+            0x51, 0xF0, 0xBE, 0xBF, 0x7E, 0x3B, 0x9D, 0x92, 0xFC, 0x49, 0x74, 0x17, 0x79, 0x36,
+            0x3C, 0xFE,
+        ],
+    ),
+];
+
+/// The messages and SHA-256 digests from FIPS 180-4.
+pub const SHA256_DIGESTS: [(&[u8], [u8; 0x20]); 3] = [
+    (
+        b"",
+        [
+            0xE3, 0xB0, 0xC4, 0x42, 0x98, 0xFC, 0x1C, 0x14, 0x9A, 0xFB, 0xF4, 0xC8, 0x99, 0x6F,
+            0xB9, 0x24, 0x27, 0xAE, 0x41, 0xE4, 0x64, 0x9B, 0x93, 0x4C, 0xA4, 0x95, 0x99, 0x1B,
+            0x78, 0x52, 0xB8, 0x55,
+        ],
+    ),
+    (
+        b"abc",
+        [
+            0xBA, 0x78, 0x16, 0xBF, 0x8F, 0x01, 0xCF, 0xEA, 0x41, 0x41, 0x40, 0xDE, 0x5D, 0xAE,
+            0x22, 0x23, 0xB0, 0x03, 0x61, 0xA3, 0x96, 0x17, 0x7A, 0x9C, 0xB4, 0x10, 0xFF, 0x61,
+            0xF2, 0x00, 0x15, 0xAD,
+        ],
+    ),
+    (
+        b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+        [
+            0x24, 0x8D, 0x6A, 0x61, 0xD2, 0x06, 0x38, 0xB8, 0xE5, 0xC0, 0x26, 0x93, 0x0C, 0x3E,
+            0x60, 0x39, 0xA3, 0x3C, 0xE4, 0x59, 0x64, 0xFF, 0x21, 0x67, 0xF6, 0xEC, 0xED, 0xD4,
+            0x19, 0xDB, 0x06, 0xC1,
+        ],
+    ),
+];
+
+/// The data key of IEEE 1619 XTS-AES-128 vector 2.
+pub const XTS_KEY1: [u8; 0x10] = [
+    0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11,
+];
+
+/// The tweak key of IEEE 1619 XTS-AES-128 vector 2.
+pub const XTS_KEY2: [u8; 0x10] = [
+    0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22,
+];
+
+/// The data unit sequence number of IEEE 1619 XTS-AES-128 vector 2.
+pub const XTS_SECTOR: u64 = 0x33_3333_3333;
+
+/// The plaintext of IEEE 1619 XTS-AES-128 vector 2.
+pub const XTS_PLAINTEXT: [u8; 0x20] = [
+    0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44,
+    0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x44,
+];
+
+/// The ciphertext of IEEE 1619 XTS-AES-128 vector 2.
+pub const XTS_CIPHERTEXT: [u8; 0x20] = [
+    0xC4, 0x54, 0x18, 0x5E, 0x6A, 0x16, 0x93, 0x6E, 0x39, 0x33, 0x40, 0x38, 0xAC, 0xEF, 0x83, 0x8B,
+    0xFB, 0x18, 0x6F, 0xFF, 0x74, 0x80, 0xAD, 0xC4, 0x28, 0x93, 0x82, 0xEC, 0xD6, 0xD3, 0x94, 0xF0,
+];
+
+/// The ciphertext of two sectors of the IEEE 1619 vector 2 plaintext,
+/// encrypted with Nintendo's big-endian sector tweak.
+pub const NINTENDO_XTS_CIPHERTEXT: [u8; 0x40] = [
+    0x44, 0xBE, 0xC8, 0x2F, 0xFB, 0x76, 0xAE, 0xFD, 0xFB, 0xC9, 0x6D, 0xFE, 0x61, 0xE1, 0x92, 0xCC,
+    0xFA, 0x22, 0x13, 0x67, 0x7C, 0x8F, 0x4F, 0xD6, 0xE4, 0xF1, 0x8F, 0x7E, 0xBB, 0x69, 0x38, 0x2F,
+    0x5A, 0x2C, 0xF8, 0xCE, 0x76, 0xA4, 0x97, 0x09, 0x46, 0x8E, 0x07, 0xCD, 0x8F, 0x05, 0xB5, 0xFB,
+    0x29, 0x6E, 0x36, 0xD0, 0x0E, 0x5C, 0xD9, 0x3B, 0xC4, 0x58, 0x3F, 0x3D, 0x2C, 0xC0, 0x5A, 0xE5,
+];
+
+/// The modulus of the 512-bit RSA test key.
+pub const RSA_MODULUS: [u8; 0x40] = [
+    0xE6, 0x5A, 0x96, 0x81, 0x2D, 0x4C, 0xE8, 0xD4, 0xD1, 0x3E, 0xDC, 0x1F, 0x9B, 0xC6, 0xA5, 0x83,
+    0x26, 0xBE, 0x6F, 0xBF, 0xF2, 0x3F, 0xAF, 0x8B, 0xF5, 0xB7, 0x84, 0x0A, 0x90, 0x81, 0x14, 0xCC,
+    0xA5, 0x54, 0x79, 0x5A, 0x2E, 0x19, 0xE2, 0x65, 0x37, 0xAE, 0x45, 0x22, 0xE5, 0xCD, 0xBC, 0xEE,
+    0x99, 0xBE, 0xA1, 0xAF, 0x69, 0x27, 0x14, 0x01, 0xAE, 0x8B, 0x01, 0xC1, 0x28, 0xA2, 0x5B, 0xE5,
+];
+
+/// The public exponent of the RSA test key.
+pub const RSA_EXPONENT: [u8; 0x4] = [0x00, 0x01, 0x00, 0x01];
+
+/// A message signed with the private RSA test key.
+pub const RSA_SIGNATURE: [u8; 0x40] = [
+    0x9A, 0x15, 0x99, 0x27, 0x52, 0xFA, 0x5A, 0xE6, 0x09, 0x22, 0xFF, 0xEE, 0xB2, 0x86, 0x18, 0xB0,
+    0x60, 0xEB, 0xFD, 0x9F, 0xA5, 0x6C, 0x3C, 0xA2, 0x82, 0xC2, 0x24, 0xDE, 0x98, 0xD7, 0xD4, 0x32,
+    0x9C, 0x1E, 0xA5, 0xC4, 0x03, 0xF0, 0x8C, 0x9E, 0x1A, 0xBE, 0xC5, 0xC5, 0x1C, 0xB7, 0x82, 0x39,
+    0x70, 0x65, 0x70, 0x53, 0xC4, 0x61, 0xF8, 0xF4, 0x83, 0x7D, 0xAC, 0x12, 0x62, 0xCF, 0xA6, 0xE5,
+];
+
+/// The message recovered from `RSA_SIGNATURE`.
+pub const RSA_MESSAGE: [u8; 0x40] = [
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10,
+    0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F, 0x20,
+    0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2A, 0x2B, 0x2C, 0x2D, 0x2E, 0x2F, 0x30,
+    0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3A, 0x3B, 0x3C, 0x3D, 0x3E, 0x3F, 0x40,
+];
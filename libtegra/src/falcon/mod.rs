@@ -0,0 +1,521 @@
+//! NVIDIA Falcon microcontroller driver.
+//!
+//! # Description
+//!
+//! The Falcon is a microcontroller which is embedded into various
+//! engines of the Tegra210, most notably the TSEC. Each engine maps
+//! the same block of Falcon registers at offset `0x1000` within its
+//! own register space.
+//!
+//! Code is loaded into IMEM and data into DMEM via DMA from physical
+//! memory. IMEM pages can be marked as secure, in which case the code
+//! has to be authenticated by the Falcon before it runs in Heavy
+//! Secure (HS) mode. The host communicates with running code through
+//! the two mailbox registers.
+//!
+//! # Implementation
+//!
+//! - The [`Registers`] struct represents the Falcon registers relative
+//! to the start of the Falcon register block.
+//!
+//! - The [`Falcon`] struct holds a pointer to the [`Registers`] of an
+//! engine. [`Falcon::TSEC`] should be preferred over creating instances
+//! manually through [`Falcon::from_base`].
+//!
+//! - [`Falcon::load_imem`] and [`Falcon::load_dmem`] transfer DMA blocks
+//! of [`DMA_BLOCK_SIZE`] bytes and thus require the source to be aligned
+//! to and padded to that size.
+//!
+//! - [`Falcon::boot`] starts the CPU, using the `CPUCTL` alias register
+//! when the engine has protected `CPUCTL`, which is the case after HS
+//! code has been executed.
+//!
+//! - [`Falcon::status`] reports whether the CPU is halted and the cause
+//! of the last exception, [`Falcon::read_dmem`] allows reading back DMEM.
+//!
+//! [`Registers`]: struct.Registers.html
+//! [`Falcon`]: struct.Falcon.html
+//! [`Falcon::TSEC`]: struct.Falcon.html#associatedconstant.TSEC
+//! [`Falcon::from_base`]: struct.Falcon.html#method.from_base
+//! [`Falcon::load_imem`]: struct.Falcon.html#method.load_imem
+//! [`Falcon::load_dmem`]: struct.Falcon.html#method.load_dmem
+//! [`Falcon::boot`]: struct.Falcon.html#method.boot
+//! [`Falcon::status`]: struct.Falcon.html#method.status
+//! [`Falcon::read_dmem`]: struct.Falcon.html#method.read_dmem
+//! [`DMA_BLOCK_SIZE`]: constant.DMA_BLOCK_SIZE.html
+
+use mirage_mmio::Mmio;
+
+use crate::{timer::get_milliseconds, tsec::TSEC_BASE};
+
+/// The offset of the Falcon registers within the register space of an engine.
+pub const FALCON_OFFSET: u32 = 0x1000;
+
+/// The size of a single DMA transfer.
+pub const DMA_BLOCK_SIZE: usize = 0x100;
+
+/// The timeout for DMA transfers, in milliseconds.
+const DMA_TIMEOUT: u32 = 10000;
+
+/// `DMATRFCMD` flag that indicates an idle DMA engine.
+const DMATRFCMD_IDLE: u32 = 1 << 1;
+
+/// `DMATRFCMD` flag that marks transferred IMEM pages as secure.
+const DMATRFCMD_SEC: u32 = 1 << 2;
+
+/// `DMATRFCMD` flag that selects IMEM as the transfer target.
+const DMATRFCMD_IMEM: u32 = 1 << 4;
+
+/// `DMATRFCMD` transfer size of 256 bytes.
+const DMATRFCMD_SIZE_256B: u32 = 6 << 8;
+
+/// `DMEMC` flag that enables auto-increment on writes.
+const DMEMC_AINCW: u32 = 1 << 24;
+
+/// `DMEMC` flag that enables auto-increment on reads.
+const DMEMC_AINCR: u32 = 1 << 25;
+
+bitflags! {
+    /// Representation of the `FALCON_CPUCTL` register.
+    pub struct CpuControl: u32 {
+        /// Invalidates the IMEM.
+        const IINVAL = 1 << 0;
+        /// Starts the CPU at the boot vector.
+        const STARTCPU = 1 << 1;
+        /// Soft-resets the CPU.
+        const SRESET = 1 << 2;
+        /// Hard-resets the CPU.
+        const HRESET = 1 << 3;
+        /// The CPU has been halted.
+        const HALTED = 1 << 4;
+        /// The CPU has been stopped.
+        const STOPPED = 1 << 5;
+        /// `CPUCTL` is protected and must be accessed through `CPUCTL_ALIAS`.
+        const ALIAS_EN = 1 << 6;
+    }
+}
+
+/// Enumeration of possible Falcon errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The DMA engine did not become idle in time.
+    DmaTimeout,
+    /// The CPU did not respond in time.
+    Timeout,
+    /// A buffer is not aligned to or padded to the DMA block size,
+    /// or is not located within the first 4GiB of memory.
+    Misaligned,
+    /// The CPU halted because of an exception.
+    Exception(Exception),
+}
+
+/// An exception that was raised by the Falcon CPU.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Exception {
+    /// The exception cause, as reported in `FALCON_EXCI`.
+    pub cause: u8,
+    /// The program counter of the faulting instruction.
+    pub pc: u32,
+}
+
+impl Exception {
+    /// Whether the exception was raised by a software trap.
+    pub fn is_trap(&self) -> bool {
+        self.cause < 8
+    }
+
+    /// Whether the exception was raised by an invalid instruction.
+    pub fn is_invalid_opcode(&self) -> bool {
+        self.cause == 8
+    }
+}
+
+/// The execution state of the Falcon CPU.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Status {
+    /// Whether the CPU has been halted.
+    pub halted: bool,
+    /// Whether the CPU has been stopped.
+    pub stopped: bool,
+    /// The last exception that was raised, if any.
+    pub exception: Option<Exception>,
+}
+
+/// Representation of the Falcon registers.
+#[repr(C)]
+pub struct Registers {
+    pub irqsset: Mmio<u32>,   // 0x0
+    pub irqsclr: Mmio<u32>,   // 0x4
+    pub irqstat: Mmio<u32>,   // 0x8
+    pub irqmode: Mmio<u32>,   // 0xc
+    pub irqmset: Mmio<u32>,   // 0x10
+    pub irqmclr: Mmio<u32>,   // 0x14
+    pub irqmask: Mmio<u32>,   // 0x18
+    pub irqdest: Mmio<u32>,   // 0x1c
+    pub gptmrint: Mmio<u32>,  // 0x20
+    pub gptmrval: Mmio<u32>,  // 0x24
+    pub gptmrctl: Mmio<u32>,  // 0x28
+    pub ptimer0: Mmio<u32>,   // 0x2c
+    pub ptimer1: Mmio<u32>,   // 0x30
+    pub wdtmrval: Mmio<u32>,  // 0x34
+    pub wdtmrctl: Mmio<u32>,  // 0x38
+    pub irqdest2: Mmio<u32>,  // 0x3c
+    pub mailbox0: Mmio<u32>,  // 0x40
+    pub mailbox1: Mmio<u32>,  // 0x44
+    pub itfen: Mmio<u32>,     // 0x48
+    pub idlestate: Mmio<u32>, // 0x4c
+    pub curctx: Mmio<u32>,    // 0x50
+    pub nxtctx: Mmio<u32>,    // 0x54
+    pub ctxack: Mmio<u32>,    // 0x58
+    pub fhstate: Mmio<u32>,   // 0x5c
+    pub privstate: Mmio<u32>, // 0x60
+    pub mthddata: Mmio<u32>,  // 0x64
+    pub mthdid: Mmio<u32>,    // 0x68
+    pub mthdwdat: Mmio<u32>,  // 0x6c
+    pub mthdcount: Mmio<u32>, // 0x70
+    pub mthdpop: Mmio<u32>,   // 0x74
+    pub mthdramsz: Mmio<u32>, // 0x78
+    pub sftreset: Mmio<u32>,  // 0x7c
+    pub os: Mmio<u32>,        // 0x80
+    pub rm: Mmio<u32>,        // 0x84
+    pub soft_pm: Mmio<u32>,   // 0x88
+    pub soft_mode: Mmio<u32>, // 0x8c
+    pub debug1: Mmio<u32>,    // 0x90
+    pub debuginfo: Mmio<u32>, // 0x94
+    pub ibrkpt1: Mmio<u32>,   // 0x98
+    pub ibrkpt2: Mmio<u32>,   // 0x9c
+    pub cgctl: Mmio<u32>,     // 0xa0
+    pub engctl: Mmio<u32>,    // 0xa4
+    pub pmm: Mmio<u32>,       // 0xa8
+    pub addr: Mmio<u32>,      // 0xac
+    pub ibrkpt3: Mmio<u32>,   // 0xb0
+    pub ibrkpt4: Mmio<u32>,   // 0xb4
+    pub ibrkpt5: Mmio<u32>,   // 0xb8
+    _reserved0: [Mmio<u8>; 0x14],
+    pub exci: Mmio<u32>,     // 0xd0
+    pub svec_spr: Mmio<u32>, // 0xd4
+    pub rstat0: Mmio<u32>,   // 0xd8
+    pub rstat3: Mmio<u32>,   // 0xdc
+    pub unk_e0: Mmio<u32>,   // 0xe0
+    _reserved1: [Mmio<u8>; 0x1C],
+    pub cpuctl: Mmio<u32>,       // 0x100
+    pub bootvec: Mmio<u32>,      // 0x104
+    pub hwcfg: Mmio<u32>,        // 0x108
+    pub dmactl: Mmio<u32>,       // 0x10c
+    pub dmatrfbase: Mmio<u32>,   // 0x110
+    pub dmatrfmoffs: Mmio<u32>,  // 0x114
+    pub dmatrfcmd: Mmio<u32>,    // 0x118
+    pub dmatrffboffs: Mmio<u32>, // 0x11c
+    pub dmapoll_fb: Mmio<u32>,   // 0x120
+    pub dmapoll_cp: Mmio<u32>,   // 0x124
+    pub dbg_state: Mmio<u32>,    // 0x128
+    pub hwcfg1: Mmio<u32>,       // 0x12c
+    pub cpuctl_alias: Mmio<u32>, // 0x130
+    _reserved2: [Mmio<u8>; 0x4],
+    pub stackcfg: Mmio<u32>, // 0x138
+    _reserved3: [Mmio<u8>; 0x4],
+    pub imctl: Mmio<u32>,       // 0x140
+    pub imstat: Mmio<u32>,      // 0x144
+    pub traceidx: Mmio<u32>,    // 0x148
+    pub tracepc: Mmio<u32>,     // 0x14c
+    pub imfillrng0: Mmio<u32>,  // 0x150
+    pub imfillrng1: Mmio<u32>,  // 0x154
+    pub imfillctl: Mmio<u32>,   // 0x158
+    pub imctl_debug: Mmio<u32>, // 0x15c
+    pub cmembase: Mmio<u32>,    // 0x160
+    pub dmemapert: Mmio<u32>,   // 0x164
+    pub exterraddr: Mmio<u32>,  // 0x168
+    pub exterrstat: Mmio<u32>,  // 0x16c
+    _reserved4: [Mmio<u8>; 0xC],
+    pub cg2: Mmio<u32>,    // 0x17c
+    pub imemc0: Mmio<u32>, // 0x180
+    pub imemd0: Mmio<u32>, // 0x184
+    pub imemt0: Mmio<u32>, // 0x188
+    _reserved5: [Mmio<u8>; 0x4],
+    pub imemc1: Mmio<u32>, // 0x190
+    pub imemd1: Mmio<u32>, // 0x194
+    pub imemt1: Mmio<u32>, // 0x198
+    _reserved6: [Mmio<u8>; 0x4],
+    pub imemc2: Mmio<u32>, // 0x1a0
+    pub imemd2: Mmio<u32>, // 0x1a4
+    pub imemt2: Mmio<u32>, // 0x1a8
+    _reserved7: [Mmio<u8>; 0x4],
+    pub imemc3: Mmio<u32>, // 0x1b0
+    pub imemd3: Mmio<u32>, // 0x1b4
+    pub imemt3: Mmio<u32>, // 0x1b8
+    _reserved8: [Mmio<u8>; 0x4],
+    pub dmemc0: Mmio<u32>,    // 0x1c0
+    pub dmemd0: Mmio<u32>,    // 0x1c4
+    pub dmemc1: Mmio<u32>,    // 0x1c8
+    pub dmemd1: Mmio<u32>,    // 0x1cc
+    pub dmemc2: Mmio<u32>,    // 0x1d0
+    pub dmemd2: Mmio<u32>,    // 0x1d4
+    pub dmemc3: Mmio<u32>,    // 0x1d8
+    pub dmemd3: Mmio<u32>,    // 0x1dc
+    pub dmemc4: Mmio<u32>,    // 0x1e0
+    pub dmemd4: Mmio<u32>,    // 0x1e4
+    pub dmemc5: Mmio<u32>,    // 0x1e8
+    pub dmemd5: Mmio<u32>,    // 0x1ec
+    pub dmemc6: Mmio<u32>,    // 0x1f0
+    pub dmemd6: Mmio<u32>,    // 0x1f4
+    pub dmemc7: Mmio<u32>,    // 0x1f8
+    pub dmemd7: Mmio<u32>,    // 0x1fc
+    pub icd_cmd: Mmio<u32>,   // 0x200
+    pub icd_addr: Mmio<u32>,  // 0x204
+    pub icd_wdata: Mmio<u32>, // 0x208
+    pub icd_rdata: Mmio<u32>, // 0x20c
+    _reserved9: [Mmio<u8>; 0x30],
+    pub sctl: Mmio<u32>,    // 0x240
+    pub sstat: Mmio<u32>,   // 0x244
+    pub unk_248: Mmio<u32>, // 0x248
+    pub unk_24c: Mmio<u32>, // 0x24c
+    pub unk_250: Mmio<u32>, // 0x250
+    _reserved10: [Mmio<u8>; 0xC],
+    pub unk_260: Mmio<u32>, // 0x260
+    _reserved11: [Mmio<u8>; 0x1C],
+    pub sprot_imem: Mmio<u32>,   // 0x280
+    pub sprot_dmem: Mmio<u32>,   // 0x284
+    pub sprot_cpuctl: Mmio<u32>, // 0x288
+    pub sprot_misc: Mmio<u32>,   // 0x28c
+    pub sprot_irq: Mmio<u32>,    // 0x290
+    pub sprot_mthd: Mmio<u32>,   // 0x294
+    pub sprot_sctl: Mmio<u32>,   // 0x298
+    pub sprot_wdtmr: Mmio<u32>,  // 0x29c
+    _reserved12: [Mmio<u8>; 0x20],
+    pub dmainfo_finished_fbrd_low: Mmio<u32>,  // 0x2c0
+    pub dmainfo_finished_fbrd_high: Mmio<u32>, // 0x2c4
+    pub dmainfo_finished_fbwr_low: Mmio<u32>,  // 0x2c8
+    pub dmainfo_finished_fbwr_high: Mmio<u32>, // 0x2cc
+    pub dmainfo_current_fbrd_low: Mmio<u32>,   // 0x2d0
+    pub dmainfo_current_fbrd_high: Mmio<u32>,  // 0x2d4
+    pub dmainfo_current_fbwr_low: Mmio<u32>,   // 0x2d8
+    pub dmainfo_current_fbwr_high: Mmio<u32>,  // 0x2dc
+    pub dmainfo_ctl: Mmio<u32>,                // 0x2e0
+}
+
+/// Representation of a Falcon microcontroller.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Falcon {
+    /// A pointer to the respective registers.
+    registers: *const Registers,
+}
+
+// Definitions of known Falcon engines.
+impl Falcon {
+    /// Representation of the Falcon inside the TSEC.
+    pub const TSEC: Self = Falcon {
+        registers: (TSEC_BASE + FALCON_OFFSET) as *const _,
+    };
+}
+
+impl Falcon {
+    /// Creates a Falcon from the base address of the engine which embeds it.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be the base address of the register space of an engine
+    /// that maps the Falcon registers at [`FALCON_OFFSET`].
+    ///
+    /// [`FALCON_OFFSET`]: constant.FALCON_OFFSET.html
+    pub const unsafe fn from_base(base: u32) -> Self {
+        Falcon {
+            registers: (base + FALCON_OFFSET) as *const _,
+        }
+    }
+
+    /// Gets the registers of the Falcon.
+    pub fn registers(&self) -> &Registers {
+        unsafe { &*self.registers }
+    }
+
+    /// Configures the Falcon for DMA transfers and interrupts.
+    pub fn init(&self) {
+        let registers = self.registers();
+
+        registers.dmactl.write(0);
+        registers.irqmset.write(0xFFF2);
+        registers.irqdest.write(0xFFF0);
+        registers.itfen.write(3);
+    }
+
+    /// Waits until DMA has entered an idle state.
+    pub fn dma_wait_idle(&self) -> Result<(), Error> {
+        let registers = self.registers();
+
+        let timeout = get_milliseconds() + DMA_TIMEOUT;
+
+        while (registers.dmatrfcmd.read() & DMATRFCMD_IDLE) == 0 {
+            if get_milliseconds() > timeout {
+                return Err(Error::DmaTimeout);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Transfers a buffer from physical memory to the Falcon in DMA blocks.
+    fn dma_load(&self, data: &[u8], offset: u32, cmd: u32) -> Result<(), Error> {
+        let registers = self.registers();
+
+        let address = data.as_ptr() as usize;
+        if address % DMA_BLOCK_SIZE != 0
+            || data.len() % DMA_BLOCK_SIZE != 0
+            || offset as usize % DMA_BLOCK_SIZE != 0
+            || (address + data.len()) as u64 > 1 << 32
+        {
+            return Err(Error::Misaligned);
+        }
+
+        self.dma_wait_idle()?;
+
+        registers.dmatrfbase.write((address >> 8) as u32);
+
+        for block in (0..data.len()).step_by(DMA_BLOCK_SIZE) {
+            registers.dmatrfmoffs.write(offset + block as u32);
+            registers.dmatrffboffs.write(block as u32);
+            registers.dmatrfcmd.write(cmd);
+
+            self.dma_wait_idle()?;
+        }
+
+        Ok(())
+    }
+
+    /// Loads code into IMEM at the given offset.
+    ///
+    /// Secure pages must be authenticated by the Falcon before they are
+    /// executed in Heavy Secure mode.
+    pub fn load_imem(&self, code: &[u8], offset: u32, secure: bool) -> Result<(), Error> {
+        let cmd = if secure {
+            DMATRFCMD_IMEM | DMATRFCMD_SEC
+        } else {
+            DMATRFCMD_IMEM
+        };
+
+        self.dma_load(code, offset, cmd)
+    }
+
+    /// Loads data into DMEM at the given offset.
+    pub fn load_dmem(&self, data: &[u8], offset: u32) -> Result<(), Error> {
+        self.dma_load(data, offset, DMATRFCMD_SIZE_256B)
+    }
+
+    /// Reads words from DMEM, starting at the given offset.
+    pub fn read_dmem(&self, offset: u32, buffer: &mut [u32]) {
+        let registers = self.registers();
+
+        registers.dmemc0.write((offset & !3) | DMEMC_AINCR);
+        for word in buffer.iter_mut() {
+            *word = registers.dmemd0.read();
+        }
+    }
+
+    /// Writes words to DMEM, starting at the given offset.
+    pub fn write_dmem(&self, offset: u32, buffer: &[u32]) {
+        let registers = self.registers();
+
+        registers.dmemc0.write((offset & !3) | DMEMC_AINCW);
+        for &word in buffer.iter() {
+            registers.dmemd0.write(word);
+        }
+    }
+
+    /// Gets the values of both mailbox registers.
+    pub fn mailboxes(&self) -> (u32, u32) {
+        let registers = self.registers();
+
+        (registers.mailbox0.read(), registers.mailbox1.read())
+    }
+
+    /// Sets the values of both mailbox registers.
+    pub fn set_mailboxes(&self, mailbox0: u32, mailbox1: u32) {
+        let registers = self.registers();
+
+        registers.mailbox0.write(mailbox0);
+        registers.mailbox1.write(mailbox1);
+    }
+
+    /// Waits until the running code writes a non-zero value to `MAILBOX1`.
+    ///
+    /// Fails early if the CPU halts because of an exception. `EXCI` keeps the
+    /// cause of the last exception, possibly from an earlier run, so it is
+    /// only taken into account once the CPU has halted.
+    pub fn wait_mailbox1(&self, timeout_ms: u32) -> Result<u32, Error> {
+        let registers = self.registers();
+
+        let timeout = get_milliseconds() + timeout_ms;
+        loop {
+            // Read the status first, so that a value which is written right
+            // before the CPU halts isn't missed.
+            let status = self.status();
+
+            let value = registers.mailbox1.read();
+            if value != 0 {
+                return Ok(value);
+            }
+
+            if let (true, Some(exception)) = (status.halted, status.exception) {
+                return Err(Error::Exception(exception));
+            }
+
+            if get_milliseconds() > timeout {
+                return Err(Error::Timeout);
+            }
+        }
+    }
+
+    /// Starts the CPU at the given boot vector.
+    pub fn boot(&self, boot_vector: u32) {
+        let registers = self.registers();
+
+        registers.bootvec.write(boot_vector);
+
+        if CpuControl::from_bits_truncate(registers.cpuctl.read()).contains(CpuControl::ALIAS_EN) {
+            registers.cpuctl_alias.write(CpuControl::STARTCPU.bits());
+        } else {
+            registers.cpuctl.write(CpuControl::STARTCPU.bits());
+        }
+    }
+
+    /// Gets the execution state of the CPU.
+    pub fn status(&self) -> Status {
+        let registers = self.registers();
+
+        let cpuctl = CpuControl::from_bits_truncate(registers.cpuctl.read());
+        let exci = registers.exci.read();
+
+        let exception = if exci != 0 {
+            Some(Exception {
+                cause: ((exci >> 20) & 0xF) as u8,
+                pc: exci & 0xF_FFFF,
+            })
+        } else {
+            None
+        };
+
+        Status {
+            halted: cpuctl.contains(CpuControl::HALTED),
+            stopped: cpuctl.contains(CpuControl::STOPPED),
+            exception,
+        }
+    }
+
+    /// Waits until the CPU has halted.
+    pub fn wait_halted(&self, timeout_ms: u32) -> Result<Status, Error> {
+        let timeout = get_milliseconds() + timeout_ms;
+        loop {
+            let status = self.status();
+            if status.halted {
+                return Ok(status);
+            }
+
+            if get_milliseconds() > timeout {
+                return Err(Error::Timeout);
+            }
+        }
+    }
+}
+
+unsafe impl Send for Falcon {}
+
+unsafe impl Sync for Falcon {}
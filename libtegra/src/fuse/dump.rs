@@ -0,0 +1,490 @@
+//! Raw fuse array dumping and shadow cache verification.
+//!
+//! # Description
+//!
+//! The fuse cache at `0x7000F900` is nothing more than a copy of the
+//! contents of the physical fuse array which is populated by the
+//! hardware during a sense operation. Individual fields of the cache
+//! are scattered across the raw array words at arbitrary bit offsets.
+//!
+//! # Implementation
+//!
+//! - [`FuseDump::capture`] senses the fuse array and captures the raw
+//! array words along with the shadow cache words.
+//!
+//! - [`FuseDump::verify`] compares the captured raw array against the
+//! shadow cache, according to a table of [`FuseMapping`]s, and reports
+//! every [`Discrepancy`] it finds. [`TEGRA210_FUSE_MAPPINGS`] describes
+//! the fields of the Tegra210 shadow cache.
+//!
+//! - [`FuseDump::serialize`] emits the dump in a binary format which
+//! consists of a [`DumpHeader`], followed by the raw array words and
+//! the shadow cache words in little-endian byte order. The header
+//! stores a CRC32 checksum over everything that follows it.
+//! [`FuseDump::deserialize`] performs the opposite conversion.
+//!
+//! [`FuseDump::capture`]: struct.FuseDump.html#method.capture
+//! [`FuseDump::verify`]: struct.FuseDump.html#method.verify
+//! [`FuseDump::serialize`]: struct.FuseDump.html#method.serialize
+//! [`FuseDump::deserialize`]: struct.FuseDump.html#method.deserialize
+//! [`FuseMapping`]: struct.FuseMapping.html
+//! [`Discrepancy`]: struct.Discrepancy.html
+//! [`TEGRA210_FUSE_MAPPINGS`]: constant.TEGRA210_FUSE_MAPPINGS.html
+//! [`DumpHeader`]: struct.DumpHeader.html
+
+use core::{convert::TryInto, mem::size_of};
+
+use mirage_mmio::{Mmio, VolatileStorage};
+
+use crate::crc::crc32;
+
+use super::{hardware_read, hardware_sense, make_registers_visible, FuseChip};
+
+/// The number of 32-bit words in the raw Tegra210 fuse array.
+pub const FUSE_ARRAY_WORDS: usize = 192;
+
+/// The number of 32-bit words in the fuse shadow cache.
+pub const FUSE_CACHE_WORDS: usize = size_of::<FuseChip>() / 4;
+
+/// The magic of a fuse dump, `FUSE` in ASCII.
+pub const DUMP_MAGIC: u32 = 0x4553_5546;
+
+/// The version of the fuse dump format.
+pub const DUMP_VERSION: u16 = 1;
+
+/// The size of a serialized fuse dump, in bytes.
+pub const DUMP_SIZE: usize = DumpHeader::SIZE + (FUSE_ARRAY_WORDS + FUSE_CACHE_WORDS) * 4;
+
+/// Enumeration of possible errors when (de)serializing fuse dumps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The supplied buffer is too small to hold the dump.
+    BufferTooSmall,
+    /// The dump header does not start with [`DUMP_MAGIC`].
+    ///
+    /// [`DUMP_MAGIC`]: constant.DUMP_MAGIC.html
+    InvalidMagic,
+    /// The dump was created with an unsupported format version.
+    UnsupportedVersion,
+    /// The word counts stored in the header do not match the hardware.
+    InvalidLayout,
+    /// The checksum over the dump contents doesn't match the header.
+    ChecksumMismatch,
+}
+
+/// The header that precedes serialized fuse dumps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DumpHeader {
+    /// The magic of the dump, always [`DUMP_MAGIC`].
+    ///
+    /// [`DUMP_MAGIC`]: constant.DUMP_MAGIC.html
+    pub magic: u32,
+    /// The version of the format, always [`DUMP_VERSION`].
+    ///
+    /// [`DUMP_VERSION`]: constant.DUMP_VERSION.html
+    pub version: u16,
+    /// The size of the header in bytes.
+    pub header_size: u16,
+    /// The number of raw fuse array words in the dump.
+    pub array_words: u16,
+    /// The number of shadow cache words in the dump.
+    pub cache_words: u16,
+    /// The CRC32 checksum over all data following the header.
+    pub checksum: u32,
+}
+
+impl DumpHeader {
+    /// The size of a serialized header, in bytes.
+    pub const SIZE: usize = 0x10;
+
+    /// Writes the header in little-endian byte order to a buffer.
+    fn write(&self, buffer: &mut [u8]) {
+        buffer[0x0..0x4].copy_from_slice(&self.magic.to_le_bytes());
+        buffer[0x4..0x6].copy_from_slice(&self.version.to_le_bytes());
+        buffer[0x6..0x8].copy_from_slice(&self.header_size.to_le_bytes());
+        buffer[0x8..0xA].copy_from_slice(&self.array_words.to_le_bytes());
+        buffer[0xA..0xC].copy_from_slice(&self.cache_words.to_le_bytes());
+        buffer[0xC..0x10].copy_from_slice(&self.checksum.to_le_bytes());
+    }
+
+    /// Reads a header in little-endian byte order from a buffer.
+    fn read(buffer: &[u8]) -> Self {
+        DumpHeader {
+            magic: u32::from_le_bytes(buffer[0x0..0x4].try_into().unwrap()),
+            version: u16::from_le_bytes(buffer[0x4..0x6].try_into().unwrap()),
+            header_size: u16::from_le_bytes(buffer[0x6..0x8].try_into().unwrap()),
+            array_words: u16::from_le_bytes(buffer[0x8..0xA].try_into().unwrap()),
+            cache_words: u16::from_le_bytes(buffer[0xA..0xC].try_into().unwrap()),
+            checksum: u32::from_le_bytes(buffer[0xC..0x10].try_into().unwrap()),
+        }
+    }
+}
+
+/// Describes where a shadow cache field is stored in the raw fuse array.
+///
+/// The field starts at bit `bit` of the array word `array_address` and may
+/// span into the following array word. In the shadow cache, the field is
+/// stored right-aligned in the word at byte offset `cache_offset`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FuseMapping {
+    /// The byte offset of the field in the shadow cache.
+    pub cache_offset: u32,
+    /// The address of the first raw array word holding the field.
+    pub array_address: u32,
+    /// The bit in the first raw array word where the field starts.
+    pub bit: u32,
+    /// The width of the field in bits. Must not exceed 32.
+    pub width: u32,
+}
+
+impl FuseMapping {
+    /// Creates a new mapping.
+    pub const fn new(cache_offset: u32, array_address: u32, bit: u32, width: u32) -> Self {
+        FuseMapping {
+            cache_offset,
+            array_address,
+            bit,
+            width,
+        }
+    }
+}
+
+/// The locations of the [`FuseChip`] fields in the raw Tegra210 fuse array.
+///
+/// The locations are taken from the Tegra210 fuse burning data of
+/// NVIDIA's Linux for Tegra kernel. Only fields that may be read back
+/// by software are listed, i.e. neither the Secure Boot Key nor the
+/// device key, which are hidden once `privatekeydisable` is set.
+///
+/// Multi-word fields are stored as a contiguous bit stream, so each of
+/// their words starts at the same bit of consecutive array words.
+///
+/// [`FuseChip`]: ../struct.FuseChip.html
+pub const TEGRA210_FUSE_MAPPINGS: [FuseMapping; 17] = [
+    // public_key
+    FuseMapping::new(0x64, 0x0C, 6, 32),
+    FuseMapping::new(0x68, 0x0D, 6, 32),
+    FuseMapping::new(0x6C, 0x0E, 6, 32),
+    FuseMapping::new(0x70, 0x0F, 6, 32),
+    FuseMapping::new(0x74, 0x10, 6, 32),
+    FuseMapping::new(0x78, 0x11, 6, 32),
+    FuseMapping::new(0x7C, 0x12, 6, 32),
+    FuseMapping::new(0x80, 0x13, 6, 32),
+    // reserved_odm
+    FuseMapping::new(0xC8, 0x2E, 17, 32),
+    FuseMapping::new(0xCC, 0x2F, 17, 32),
+    FuseMapping::new(0xD0, 0x30, 17, 32),
+    FuseMapping::new(0xD4, 0x31, 17, 32),
+    FuseMapping::new(0xD8, 0x32, 17, 32),
+    FuseMapping::new(0xDC, 0x33, 17, 32),
+    FuseMapping::new(0xE0, 0x34, 17, 32),
+    FuseMapping::new(0xE4, 0x35, 17, 32),
+    // pkc_disable
+    FuseMapping::new(0x168, 0x52, 7, 1),
+];
+
+/// A mismatch between the raw fuse array and the shadow cache.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Discrepancy {
+    /// The mapping that failed verification.
+    pub mapping: FuseMapping,
+    /// The value of the field as read from the raw fuse array.
+    pub array_value: u32,
+    /// The value of the field as read from the shadow cache.
+    pub cache_value: u32,
+}
+
+/// A snapshot of the raw fuse array and the fuse shadow cache.
+#[derive(Clone, Copy)]
+pub struct FuseDump {
+    /// The words of the raw fuse array.
+    pub array: [u32; FUSE_ARRAY_WORDS],
+    /// The words of the fuse shadow cache.
+    pub cache: [u32; FUSE_CACHE_WORDS],
+}
+
+impl FuseDump {
+    /// Senses the fuse array and captures its raw contents along
+    /// with the freshly populated shadow cache.
+    pub fn capture() -> Self {
+        let cache = unsafe { FuseChip::get() as *const FuseChip as *const Mmio<u32> };
+        let mut dump = FuseDump {
+            array: [0; FUSE_ARRAY_WORDS],
+            cache: [0; FUSE_CACHE_WORDS],
+        };
+
+        make_registers_visible(true);
+        hardware_sense();
+
+        for (address, word) in dump.array.iter_mut().enumerate() {
+            *word = hardware_read(address as u32);
+        }
+
+        for (index, word) in dump.cache.iter_mut().enumerate() {
+            *word = unsafe { (*cache.add(index)).read() };
+        }
+
+        dump
+    }
+
+    /// Extracts the value of a field described by a mapping from the raw array.
+    pub fn array_value(&self, mapping: &FuseMapping) -> Option<u32> {
+        let address = mapping.array_address as usize;
+        let mask = field_mask(mapping)?;
+
+        if mapping.bit >= 32 || address >= FUSE_ARRAY_WORDS {
+            return None;
+        }
+
+        // Fields may cross the boundary between two array words.
+        let mut value = self.array[address] as u64 >> mapping.bit;
+        if mapping.bit + mapping.width > 32 {
+            value |= (*self.array.get(address + 1)? as u64) << (32 - mapping.bit);
+        }
+
+        Some(value as u32 & mask)
+    }
+
+    /// Extracts the value of a field described by a mapping from the shadow cache.
+    pub fn cache_value(&self, mapping: &FuseMapping) -> Option<u32> {
+        let mask = field_mask(mapping)?;
+
+        Some(*self.cache.get(mapping.cache_offset as usize / 4)? & mask)
+    }
+
+    /// Verifies the shadow cache against the raw fuse array.
+    ///
+    /// Every mapping whose values differ, or which is out of bounds, is
+    /// reported as a [`Discrepancy`] in `discrepancies`. Returns the total
+    /// number of discrepancies, which may exceed the length of the buffer.
+    ///
+    /// [`Discrepancy`]: struct.Discrepancy.html
+    pub fn verify(&self, mappings: &[FuseMapping], discrepancies: &mut [Discrepancy]) -> usize {
+        let mut count = 0;
+
+        for mapping in mappings {
+            let array_value = self.array_value(mapping);
+            let cache_value = self.cache_value(mapping);
+
+            if array_value.is_none() || cache_value.is_none() || array_value != cache_value {
+                if let Some(entry) = discrepancies.get_mut(count) {
+                    *entry = Discrepancy {
+                        mapping: *mapping,
+                        array_value: array_value.unwrap_or(0),
+                        cache_value: cache_value.unwrap_or(0),
+                    };
+                }
+
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    /// Serializes the dump into a buffer and returns the number of bytes written.
+    pub fn serialize(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        if buffer.len() < DUMP_SIZE {
+            return Err(Error::BufferTooSmall);
+        }
+
+        let (header, data) = buffer[..DUMP_SIZE].split_at_mut(DumpHeader::SIZE);
+        for (chunk, word) in data
+            .chunks_exact_mut(4)
+            .zip(self.array.iter().chain(self.cache.iter()))
+        {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+
+        DumpHeader {
+            magic: DUMP_MAGIC,
+            version: DUMP_VERSION,
+            header_size: DumpHeader::SIZE as u16,
+            array_words: FUSE_ARRAY_WORDS as u16,
+            cache_words: FUSE_CACHE_WORDS as u16,
+            checksum: crc32(data),
+        }
+        .write(header);
+
+        Ok(DUMP_SIZE)
+    }
+
+    /// Deserializes a dump from a buffer, validating its header and checksum.
+    pub fn deserialize(buffer: &[u8]) -> Result<Self, Error> {
+        if buffer.len() < DumpHeader::SIZE {
+            return Err(Error::BufferTooSmall);
+        }
+
+        let header = DumpHeader::read(buffer);
+        if header.magic != DUMP_MAGIC {
+            return Err(Error::InvalidMagic);
+        }
+
+        if header.version != DUMP_VERSION {
+            return Err(Error::UnsupportedVersion);
+        }
+
+        if header.header_size as usize != DumpHeader::SIZE
+            || header.array_words as usize != FUSE_ARRAY_WORDS
+            || header.cache_words as usize != FUSE_CACHE_WORDS
+        {
+            return Err(Error::InvalidLayout);
+        }
+
+        if buffer.len() < DUMP_SIZE {
+            return Err(Error::BufferTooSmall);
+        }
+
+        let data = &buffer[DumpHeader::SIZE..DUMP_SIZE];
+        if crc32(data) != header.checksum {
+            return Err(Error::ChecksumMismatch);
+        }
+
+        let mut dump = FuseDump {
+            array: [0; FUSE_ARRAY_WORDS],
+            cache: [0; FUSE_CACHE_WORDS],
+        };
+
+        for (word, chunk) in dump
+            .array
+            .iter_mut()
+            .chain(dump.cache.iter_mut())
+            .zip(data.chunks_exact(4))
+        {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+
+        Ok(dump)
+    }
+}
+
+/// Computes the bit mask for the field described by a mapping.
+fn field_mask(mapping: &FuseMapping) -> Option<u32> {
+    match mapping.width {
+        1..=31 => Some((1 << mapping.width) - 1),
+        32 => Some(!0),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates a dump with distinct values in every word.
+    fn sample_dump() -> FuseDump {
+        let mut dump = FuseDump {
+            array: [0; FUSE_ARRAY_WORDS],
+            cache: [0; FUSE_CACHE_WORDS],
+        };
+
+        for (index, word) in dump
+            .array
+            .iter_mut()
+            .chain(dump.cache.iter_mut())
+            .enumerate()
+        {
+            *word = (index as u32).wrapping_mul(0x9E37_79B9);
+        }
+
+        dump
+    }
+
+    /// Tests that a serialized dump deserializes into the original dump.
+    #[test]
+    fn serialize_round_trip() {
+        let dump = sample_dump();
+        let mut buffer = [0; DUMP_SIZE];
+
+        assert_eq!(dump.serialize(&mut buffer), Ok(DUMP_SIZE));
+        assert_eq!(&buffer[..4], b"FUSE");
+
+        let parsed = FuseDump::deserialize(&buffer).unwrap();
+        assert_eq!(&parsed.array[..], &dump.array[..]);
+        assert_eq!(&parsed.cache[..], &dump.cache[..]);
+
+        let mut small = [0; DUMP_SIZE - 1];
+        assert_eq!(dump.serialize(&mut small), Err(Error::BufferTooSmall));
+        assert_eq!(
+            FuseDump::deserialize(&buffer[..DUMP_SIZE - 1]).err(),
+            Some(Error::BufferTooSmall)
+        );
+    }
+
+    /// Tests that corrupted headers and contents are rejected.
+    #[test]
+    fn deserialize_errors() {
+        let mut buffer = [0; DUMP_SIZE];
+        sample_dump().serialize(&mut buffer).unwrap();
+
+        let mut corrupted = buffer;
+        corrupted[0] ^= 1;
+        assert_eq!(
+            FuseDump::deserialize(&corrupted).err(),
+            Some(Error::InvalidMagic)
+        );
+
+        let mut corrupted = buffer;
+        corrupted[4] = 2;
+        assert_eq!(
+            FuseDump::deserialize(&corrupted).err(),
+            Some(Error::UnsupportedVersion)
+        );
+
+        let mut corrupted = buffer;
+        corrupted[8] = 0;
+        assert_eq!(
+            FuseDump::deserialize(&corrupted).err(),
+            Some(Error::InvalidLayout)
+        );
+
+        let mut corrupted = buffer;
+        corrupted[DUMP_SIZE - 1] ^= 0x80;
+        assert_eq!(
+            FuseDump::deserialize(&corrupted).err(),
+            Some(Error::ChecksumMismatch)
+        );
+    }
+
+    /// Tests the extraction of fields that cross array word boundaries.
+    #[test]
+    fn verify_mappings() {
+        let mut dump = FuseDump {
+            array: [0; FUSE_ARRAY_WORDS],
+            cache: [0; FUSE_CACHE_WORDS],
+        };
+
+        // reserved_odm[0] = 0x1234_5678, stored at bit 17 of word 0x2E.
+        dump.array[0x2E] = 0x5678 << 17;
+        dump.array[0x2F] = 0x1234_5678 >> 15;
+        dump.cache[0xC8 / 4] = 0x1234_5678;
+
+        // pkc_disable, stored at bit 7 of word 0x52.
+        dump.array[0x52] = 1 << 7;
+        dump.cache[0x168 / 4] = 1;
+
+        let mut discrepancies = [Discrepancy {
+            mapping: TEGRA210_FUSE_MAPPINGS[0],
+            array_value: 0,
+            cache_value: 0,
+        }; 2];
+        assert_eq!(dump.verify(&TEGRA210_FUSE_MAPPINGS, &mut discrepancies), 0);
+
+        dump.cache[0xCC / 4] = 0xDEAD_BEEF;
+        assert_eq!(dump.verify(&TEGRA210_FUSE_MAPPINGS, &mut discrepancies), 1);
+        assert_eq!(discrepancies[0].mapping, TEGRA210_FUSE_MAPPINGS[9]);
+        assert_eq!(discrepancies[0].array_value, 0);
+        assert_eq!(discrepancies[0].cache_value, 0xDEAD_BEEF);
+    }
+
+    /// Tests that every mapping lies within the array and the cache.
+    #[test]
+    fn mappings_are_in_bounds() {
+        let dump = sample_dump();
+
+        for mapping in TEGRA210_FUSE_MAPPINGS.iter() {
+            assert!(dump.array_value(mapping).is_some());
+            assert!(dump.cache_value(mapping).is_some());
+        }
+    }
+}
@@ -4,6 +4,9 @@ use mirage_mmio::{Mmio, VolatileStorage};
 
 use crate::{clock::Car, timer::usleep};
 
+pub mod dump;
+pub mod program;
+
 /// Representation of the Fuse registers.
 #[repr(C)]
 pub struct Fuse {
@@ -242,6 +245,11 @@ pub fn hardware_read(address: u32) -> u32 {
 }
 
 /// Writes a fuse to the hardware array.
+///
+/// This doesn't take care of the programming voltage. See the
+/// [`program`] module for a guarded fuse programming API.
+///
+/// [`program`]: program/index.html
 pub fn hardware_write(address: u32, value: u32) {
     let fuse = unsafe { Fuse::get() };
     wait_idle();
@@ -0,0 +1,340 @@
+//! Guarded fuse programming.
+//!
+//! # Description
+//!
+//! Burning fuses is an irreversible operation. A fuse bit can only ever
+//! transition from 0 to 1, so every change to the fuse array is final.
+//!
+//! # Implementation
+//!
+//! - [`plan`] takes a [`FuseRequest`] and computes the exact bits that
+//! would be burnt for its word of the fuse array, i.e. the requested bits
+//! which are not burnt yet. It doesn't touch the hardware beyond reading
+//! the current array contents. This is the dry-run mode; the resulting
+//! [`PlannedWrite`]s can be reported freely.
+//!
+//! - [`program`] burns a previously computed plan. It requires a
+//! [`BurnToken`] as an explicit confirmation and a [`ProgrammingSupply`]
+//! which controls the fuse programming voltage. The supply is sequenced
+//! around the actual burn and the array is re-sensed and verified
+//! afterwards. The power state of the array is restored on every exit.
+//!
+//! - Programming is impossible once [`disable_programming`] has been called,
+//! which is part of [`init`]. Thus, fuses have to be programmed before the
+//! fuse driver is initialized.
+//!
+//! [`plan`]: fn.plan.html
+//! [`program`]: fn.program.html
+//! [`FuseRequest`]: struct.FuseRequest.html
+//! [`PlannedWrite`]: struct.PlannedWrite.html
+//! [`BurnToken`]: struct.BurnToken.html
+//! [`ProgrammingSupply`]: trait.ProgrammingSupply.html
+//! [`disable_programming`]: ../fn.disable_programming.html
+//! [`init`]: ../fn.init.html
+
+use mirage_mmio::VolatileStorage;
+
+use super::{
+    dump::FUSE_ARRAY_WORDS, hardware_read, hardware_sense, hardware_write, make_registers_visible,
+    Fuse,
+};
+use crate::{i2c, timer::usleep};
+
+/// The `PD_CTRL` bit of the `FUSE_CTRL` register, powering down the array.
+const FUSE_CTRL_PD: u32 = 1 << 26;
+
+/// The width of a programming pulse in clk_m cycles (5us at 19.2MHz).
+pub const FUSE_PROGRAM_PULSE_CYCLES: u32 = 0x60;
+
+/// The time to wait for the programming voltage to settle, in microseconds.
+pub const FUSE_SUPPLY_SETTLE_US: u32 = 1000;
+
+/// Enumeration of possible errors when programming fuses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A request targets an address outside of the fuse array.
+    InvalidAddress(u32),
+    /// Fuse programming has already been disabled until the next reset.
+    ProgrammingDisabled,
+    /// The fuse array changed between planning and programming.
+    ArrayChanged(u32),
+    /// The programming voltage could not be controlled.
+    Supply(SupplyError),
+    /// A word doesn't contain all the burnt bits after re-sensing.
+    VerificationFailed {
+        /// The address of the word that failed verification.
+        address: u32,
+        /// The bits that were expected to be set.
+        expected: u32,
+        /// The value that was actually read back.
+        actual: u32,
+    },
+}
+
+/// Enumeration of possible errors when controlling the programming voltage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SupplyError {
+    /// The supply is not available on this board.
+    Unavailable,
+    /// The communication with the regulator failed.
+    I2c(i2c::Error),
+}
+
+/// A request to set bits in a word of the fuse array.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FuseRequest {
+    /// The address of the word in the fuse array.
+    pub address: u32,
+    /// The bits that should be set in the word.
+    ///
+    /// Bits which are already burnt are left as they are, since fuses can't
+    /// be cleared.
+    pub value: u32,
+}
+
+/// The result of planning a [`FuseRequest`].
+///
+/// Planned writes can only be obtained from [`plan`], so that [`program`]
+/// only burns bits which were computed from the contents of the array.
+///
+/// [`FuseRequest`]: struct.FuseRequest.html
+/// [`plan`]: fn.plan.html
+/// [`program`]: fn.program.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PlannedWrite {
+    address: u32,
+    current: u32,
+    requested: u32,
+    burn: u32,
+}
+
+impl PlannedWrite {
+    /// Computes the bits of a request that aren't burnt in `current` yet.
+    fn new(request: &FuseRequest, current: u32) -> Result<Self, Error> {
+        if request.address as usize >= FUSE_ARRAY_WORDS {
+            return Err(Error::InvalidAddress(request.address));
+        }
+
+        Ok(PlannedWrite {
+            address: request.address,
+            current,
+            requested: request.value,
+            burn: request.value & !current,
+        })
+    }
+
+    /// Gets the address of the word in the fuse array.
+    pub fn address(&self) -> u32 {
+        self.address
+    }
+
+    /// Gets the value of the word at the time of planning.
+    pub fn current(&self) -> u32 {
+        self.current
+    }
+
+    /// Gets the bits that were requested to be set.
+    pub fn requested(&self) -> u32 {
+        self.requested
+    }
+
+    /// Gets the bits that will actually be burnt.
+    pub fn burn(&self) -> u32 {
+        self.burn
+    }
+
+    /// Gets the value the word is expected to hold after programming.
+    pub fn expected(&self) -> u32 {
+        self.current | self.burn
+    }
+
+    /// Gets the number of fuse bits that will be burnt.
+    pub fn burn_count(&self) -> u32 {
+        self.burn.count_ones()
+    }
+}
+
+/// Explicit confirmation that fuses should be burnt for real.
+///
+/// Without a token, only [`plan`] can be used.
+///
+/// [`plan`]: fn.plan.html
+pub struct BurnToken {
+    _private: (),
+}
+
+impl BurnToken {
+    /// Creates a new token that permits burning fuses.
+    ///
+    /// # Safety
+    ///
+    /// Fuse changes made with this token are permanent and may render
+    /// the device unbootable. The caller must ensure that the writes
+    /// passed to [`program`] along with the token are intended.
+    ///
+    /// [`program`]: fn.program.html
+    pub unsafe fn new() -> Self {
+        BurnToken { _private: () }
+    }
+}
+
+/// A controller for the fuse programming voltage (VPP).
+pub trait ProgrammingSupply {
+    /// Enables the programming voltage.
+    fn enable(&mut self) -> Result<(), SupplyError>;
+
+    /// Disables the programming voltage.
+    fn disable(&mut self) -> Result<(), SupplyError>;
+}
+
+/// Computes the bits that have to be burnt in order to fulfill a request.
+///
+/// Requests whose bits are already burnt result in a write with an empty
+/// `burn` mask. This function never programs any fuses.
+pub fn plan(request: &FuseRequest) -> Result<PlannedWrite, Error> {
+    if request.address as usize >= FUSE_ARRAY_WORDS {
+        return Err(Error::InvalidAddress(request.address));
+    }
+
+    make_registers_visible(true);
+
+    PlannedWrite::new(request, hardware_read(request.address))
+}
+
+/// Burns a list of planned writes into the fuse array.
+///
+/// Fails if any word changed since the writes were planned. After programming,
+/// the array is sensed again and every word is verified to contain the bits that
+/// were burnt.
+pub fn program<S: ProgrammingSupply>(
+    writes: &[PlannedWrite],
+    supply: &mut S,
+    _token: BurnToken,
+) -> Result<(), Error> {
+    let fuse = unsafe { Fuse::get() };
+
+    make_registers_visible(true);
+
+    if fuse.dis_pgm.read() != 0 {
+        return Err(Error::ProgrammingDisabled);
+    }
+
+    for write in writes {
+        if write.address as usize >= FUSE_ARRAY_WORDS {
+            return Err(Error::InvalidAddress(write.address));
+        }
+
+        if hardware_read(write.address) != write.current {
+            return Err(Error::ArrayChanged(write.address));
+        }
+    }
+
+    // Save the power state of the array, so that it can be restored on every exit.
+    let power_down = fuse.ctrl.read() & FUSE_CTRL_PD;
+
+    // Enable software writes and power up the array.
+    fuse.write_access.write(0);
+    fuse.ctrl.write(fuse.ctrl.read() & !FUSE_CTRL_PD);
+
+    let res = burn_and_verify(writes, supply);
+
+    // Power the array down again if it was powered down before.
+    fuse.ctrl
+        .write((fuse.ctrl.read() & !FUSE_CTRL_PD) | power_down);
+
+    res
+}
+
+/// Burns the planned writes with the array powered up and verifies them.
+fn burn_and_verify<S: ProgrammingSupply>(
+    writes: &[PlannedWrite],
+    supply: &mut S,
+) -> Result<(), Error> {
+    let fuse = unsafe { Fuse::get() };
+
+    // Configure the programming pulse width.
+    fuse.time_pgm2.write(FUSE_PROGRAM_PULSE_CYCLES);
+
+    // Raise the programming voltage and signal power good.
+    let mut res = supply.enable().map_err(Error::Supply);
+    if res.is_ok() {
+        usleep(FUSE_SUPPLY_SETTLE_US);
+        fuse.pwr_good_sw.write(1);
+        usleep(1);
+
+        for write in writes.iter().filter(|write| write.burn != 0) {
+            hardware_write(write.address, write.burn);
+        }
+
+        fuse.pwr_good_sw.write(0);
+        usleep(1);
+    }
+
+    // Lower the programming voltage again, even if enabling it failed.
+    if let Err(err) = supply.disable() {
+        if res.is_ok() {
+            res = Err(Error::Supply(err));
+        }
+    }
+
+    // Disable software writes.
+    fuse.write_access.write(1);
+
+    res?;
+
+    // Re-sense the array into the shadow cache and verify the result.
+    hardware_sense();
+
+    for write in writes {
+        let actual = hardware_read(write.address);
+
+        if actual & write.expected() != write.expected() {
+            return Err(Error::VerificationFailed {
+                address: write.address,
+                expected: write.expected(),
+                actual,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests the burn mask and the expected value of planned writes.
+    #[test]
+    fn planned_write_bits() {
+        let request = FuseRequest {
+            address: 0x2E,
+            value: 0b1110,
+        };
+
+        let write = PlannedWrite::new(&request, 0b1_0010).unwrap();
+        assert_eq!(write.address(), 0x2E);
+        assert_eq!(write.requested(), 0b1110);
+        assert_eq!(write.burn(), 0b1100);
+        assert_eq!(write.burn_count(), 2);
+        assert_eq!(write.expected(), 0b1_1110);
+
+        let write = PlannedWrite::new(&request, 0b1110).unwrap();
+        assert_eq!(write.burn(), 0);
+        assert_eq!(write.burn_count(), 0);
+        assert_eq!(write.expected(), 0b1110);
+    }
+
+    /// Tests that invalid requests are rejected.
+    #[test]
+    fn planned_write_errors() {
+        let request = FuseRequest {
+            address: FUSE_ARRAY_WORDS as u32,
+            value: 1,
+        };
+        assert_eq!(
+            PlannedWrite::new(&request, 0),
+            Err(Error::InvalidAddress(FUSE_ARRAY_WORDS as u32))
+        );
+    }
+}
@@ -109,7 +109,7 @@ pub enum Device {
 }
 
 /// Enumeration of possible errors when communicating over the I²C protocol.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Error {
     /// Generic I²C error. Not closer specified.
     Generic,
@@ -0,0 +1,651 @@
+//! Parser and writer for keyset files.
+//!
+//! # Description
+//!
+//! Keys are commonly exchanged in text files such as `prod.keys`, which
+//! hold one `name = value` pair per line with the value encoded as hex.
+//! Keys that exist once per key generation or keyblob revision carry a
+//! two-digit hexadecimal index, e.g. `master_key_0a`.
+//!
+//! # Implementation
+//!
+//! - The [`Keyset`] struct holds all keys known to this crate. Unknown keys
+//! are skipped while parsing, so that complete keyfiles can be used.
+//!
+//! - Names are matched case-insensitively. Empty lines and lines starting
+//! with `#` or `;` are ignored.
+//!
+//! - The size of every value is validated against the size of the key,
+//! as is the index of indexed keys.
+//!
+//! - Keysets are written in the same format, with lowercase hex values,
+//! through any `core::fmt::Write` implementation.
+//!
+//! - Keys can be loaded into keyslots of the [`KeyslotManager`], and the
+//! sources required for the key derivation can be extracted as [`KeySources`].
+//!
+//! [`Keyset`]: struct.Keyset.html
+//! [`KeyslotManager`]: ../../se/keyslot/struct.KeyslotManager.html
+//! [`KeySources`]: ../struct.KeySources.html
+
+use core::fmt::{self, Write};
+
+use super::{
+    DerivedKeys, KeySources, BIS_KEY_COUNT, BIS_KEY_SIZE, KEYBLOB_REVISIONS, KEYBLOB_SIZE, KEY_SIZE,
+};
+use crate::se::{
+    self,
+    keyslot::{AesKeyslot, KeyslotManager},
+    SecurityEngine,
+};
+
+/// The maximum number of key generations.
+pub const MAX_KEY_REVISIONS: usize = 0x20;
+
+/// Enumeration of possible keyset errors.
+///
+/// Errors during parsing carry the number of the offending line, starting at 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A line is not of the form `name = value`.
+    InvalidLine(usize),
+    /// A value is not a valid hexadecimal string.
+    InvalidHex(usize),
+    /// A value doesn't match the size of the key.
+    InvalidLength(usize),
+    /// The index of an indexed key is out of range.
+    InvalidIndex(usize),
+    /// A key that is required for the operation is missing.
+    MissingKey,
+    /// The key generation is out of range.
+    InvalidRevision(usize),
+    /// A key couldn't be loaded into a keyslot.
+    Engine(se::Error),
+}
+
+impl From<se::Error> for Error {
+    fn from(error: se::Error) -> Self {
+        Error::Engine(error)
+    }
+}
+
+/// Decodes a hexadecimal digit.
+fn hex_digit(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Decodes a hexadecimal string that must exactly fill `output`.
+fn parse_hex(line: usize, value: &str, output: &mut [u8]) -> Result<(), Error> {
+    let value = value.as_bytes();
+
+    if value.len() != 2 * output.len() {
+        return Err(Error::InvalidLength(line));
+    }
+
+    for (byte, digits) in output.iter_mut().zip(value.chunks(2)) {
+        let high = hex_digit(digits[0]).ok_or(Error::InvalidHex(line))?;
+        let low = hex_digit(digits[1]).ok_or(Error::InvalidHex(line))?;
+
+        *byte = (high << 4) | low;
+    }
+
+    Ok(())
+}
+
+/// Splits the index off an indexed key name like `master_key_0a`.
+fn split_index(name: &str) -> Option<(&str, usize)> {
+    let position = name.rfind('_')?;
+    let (name, index) = (&name[..position], &name[position + 1..]);
+
+    match index.as_bytes() {
+        [high, low] => Some((
+            name,
+            usize::from((hex_digit(*high)? << 4) | hex_digit(*low)?),
+        )),
+        _ => None,
+    }
+}
+
+/// Writes a single key in the `name = value` format.
+fn write_key<W: Write>(
+    writer: &mut W,
+    name: &str,
+    index: Option<usize>,
+    key: &[u8],
+) -> fmt::Result {
+    writer.write_str(name)?;
+    if let Some(index) = index {
+        write!(writer, "_{:02x}", index)?;
+    }
+
+    writer.write_str(" = ")?;
+    for byte in key {
+        write!(writer, "{:02x}", byte)?;
+    }
+
+    writer.write_char('\n')
+}
+
+/// Gets a required key or fails.
+fn required<T: Copy>(key: Option<T>) -> Result<T, Error> {
+    key.ok_or(Error::MissingKey)
+}
+
+macro_rules! keyset {
+    (
+        single {
+            $($(#[$single_meta:meta])* $single:ident: $single_size:expr,)*
+        }
+        indexed {
+            $($(#[$indexed_meta:meta])* $indexed:ident: $indexed_size:expr; $count:expr,)*
+        }
+    ) => {
+        /// A set of keys, as stored in keyset files.
+        ///
+        /// Indexed keys are stored in arrays, using the index of the key name.
+        #[derive(Clone)]
+        pub struct Keyset {
+            $($(#[$single_meta])* pub $single: Option<[u8; $single_size]>,)*
+            $($(#[$indexed_meta])* pub $indexed: [Option<[u8; $indexed_size]>; $count],)*
+        }
+
+        impl Keyset {
+            /// Creates an empty keyset.
+            pub fn new() -> Self {
+                Keyset {
+                    $($single: None,)*
+                    $($indexed: [None; $count],)*
+                }
+            }
+
+            /// Sets a key from its hex value and returns whether the key is known.
+            fn set(
+                &mut self,
+                line: usize,
+                name: &str,
+                value: &str,
+            ) -> Result<bool, Error> {
+                $(
+                    if name.eq_ignore_ascii_case(stringify!($single)) {
+                        let mut key = [0; $single_size];
+                        parse_hex(line, value, &mut key)?;
+                        self.$single = Some(key);
+
+                        return Ok(true);
+                    }
+                )*
+
+                if let Some((name, index)) = split_index(name) {
+                    $(
+                        if name.eq_ignore_ascii_case(stringify!($indexed)) {
+                            let slot = self
+                                .$indexed
+                                .get_mut(index)
+                                .ok_or(Error::InvalidIndex(line))?;
+
+                            let mut key = [0; $indexed_size];
+                            parse_hex(line, value, &mut key)?;
+                            *slot = Some(key);
+
+                            return Ok(true);
+                        }
+                    )*
+                }
+
+                Ok(false)
+            }
+
+            /// Gets a key by its name, e.g. `tsec_key` or `master_key_0a`.
+            pub fn get(&self, name: &str) -> Option<&[u8]> {
+                $(
+                    if name.eq_ignore_ascii_case(stringify!($single)) {
+                        return self.$single.as_ref().map(|key| &key[..]);
+                    }
+                )*
+
+                let (name, index) = split_index(name)?;
+                $(
+                    if name.eq_ignore_ascii_case(stringify!($indexed)) {
+                        return self
+                            .$indexed
+                            .get(index)?
+                            .as_ref()
+                            .map(|key| &key[..]);
+                    }
+                )*
+
+                None
+            }
+
+            /// Writes all keys that are present in the keyset format.
+            pub fn write<W: Write>(&self, writer: &mut W) -> fmt::Result {
+                $(
+                    if let Some(key) = &self.$single {
+                        write_key(writer, stringify!($single), None, key)?;
+                    }
+                )*
+
+                $(
+                    for (index, key) in self.$indexed.iter().enumerate() {
+                        if let Some(key) = key {
+                            write_key(writer, stringify!($indexed), Some(index), key)?;
+                        }
+                    }
+                )*
+
+                Ok(())
+            }
+        }
+    };
+}
+
+keyset! {
+    single {
+        /// The Secure Boot Key.
+        secure_boot_key: KEY_SIZE,
+        /// The TSEC key.
+        tsec_key: KEY_SIZE,
+        /// The device key.
+        device_key: KEY_SIZE,
+        /// The source of the keyblob MAC keys.
+        keyblob_mac_key_source: KEY_SIZE,
+        /// The source of the master keys.
+        master_key_source: KEY_SIZE,
+        /// The source of the device key.
+        per_console_key_source: KEY_SIZE,
+        /// The source of the KEK of the first BIS key.
+        retail_specific_aes_key_source: KEY_SIZE,
+        /// The source of KEKs generated from the device key.
+        aes_kek_generation_source: KEY_SIZE,
+        /// The source of keys generated from a generated KEK.
+        aes_key_generation_source: KEY_SIZE,
+        /// The source of the KEK of the remaining BIS keys.
+        bis_kek_source: KEY_SIZE,
+    }
+    indexed {
+        /// The TSEC root keys.
+        tsec_root_key: KEY_SIZE; MAX_KEY_REVISIONS,
+        /// The sources of the keyblob keys.
+        keyblob_key_source: KEY_SIZE; KEYBLOB_REVISIONS,
+        /// The keyblob keys.
+        keyblob_key: KEY_SIZE; KEYBLOB_REVISIONS,
+        /// The keyblob MAC keys.
+        keyblob_mac_key: KEY_SIZE; KEYBLOB_REVISIONS,
+        /// The encrypted keyblobs.
+        encrypted_keyblob: KEYBLOB_SIZE; KEYBLOB_REVISIONS,
+        /// The sources of the master KEKs derived from the TSEC root key.
+        master_kek_source: KEY_SIZE; MAX_KEY_REVISIONS,
+        /// The master KEKs.
+        master_kek: KEY_SIZE; MAX_KEY_REVISIONS,
+        /// The master keys.
+        master_key: KEY_SIZE; MAX_KEY_REVISIONS,
+        /// The package1 keys.
+        package1_key: KEY_SIZE; MAX_KEY_REVISIONS,
+        /// The sources of the BIS keys.
+        bis_key_source: BIS_KEY_SIZE; BIS_KEY_COUNT - 1,
+        /// The BIS keys.
+        bis_key: BIS_KEY_SIZE; BIS_KEY_COUNT,
+    }
+}
+
+impl Keyset {
+    /// Parses a keyset file.
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let mut keyset = Self::new();
+        keyset.update(text)?;
+
+        Ok(keyset)
+    }
+
+    /// Parses a keyset file and adds its keys, replacing existing ones.
+    pub fn update(&mut self, text: &str) -> Result<(), Error> {
+        for (number, line) in text.lines().enumerate() {
+            let number = number + 1;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+
+            let separator = line.find('=').ok_or(Error::InvalidLine(number))?;
+            let name = line[..separator].trim();
+            let value = line[separator + 1..].trim();
+
+            if name.is_empty() {
+                return Err(Error::InvalidLine(number));
+            }
+
+            self.set(number, name, value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Extracts the key sources that are required for the key derivation.
+    pub fn key_sources(&self) -> Result<KeySources, Error> {
+        let mut sources = KeySources {
+            keyblob_mac_key_source: required(self.keyblob_mac_key_source)?,
+            master_key_source: required(self.master_key_source)?,
+            per_console_key_source: required(self.per_console_key_source)?,
+            retail_specific_aes_key_source: required(self.retail_specific_aes_key_source)?,
+            aes_kek_generation_source: required(self.aes_kek_generation_source)?,
+            aes_key_generation_source: required(self.aes_key_generation_source)?,
+            bis_kek_source: required(self.bis_kek_source)?,
+            ..KeySources::default()
+        };
+
+        for (source, key) in sources
+            .keyblob_key_sources
+            .iter_mut()
+            .zip(self.keyblob_key_source.iter())
+        {
+            *source = required(*key)?;
+        }
+        for (source, key) in sources
+            .bis_key_sources
+            .iter_mut()
+            .zip(self.bis_key_source.iter())
+        {
+            *source = required(*key)?;
+        }
+
+        Ok(sources)
+    }
+
+    /// Adds the keys returned by the key derivation for a key generation.
+    pub fn add_derived_keys(&mut self, revision: usize, keys: &DerivedKeys) -> Result<(), Error> {
+        if revision >= MAX_KEY_REVISIONS {
+            return Err(Error::InvalidRevision(revision));
+        }
+
+        self.master_key[revision] = Some(keys.master_key);
+        self.device_key = Some(keys.device_key);
+
+        if let Some(package1_key) = keys.package1_key {
+            self.package1_key[revision] = Some(package1_key);
+        }
+
+        for (key, derived) in self.bis_key.iter_mut().zip(keys.bis_keys.iter()) {
+            *key = Some(*derived);
+        }
+
+        Ok(())
+    }
+
+    /// Loads a key into a keyslot of the keyslot manager.
+    ///
+    /// The TSEC key is loaded into the reserved TSEC keyslot, all other keys
+    /// into a newly allocated keyslot which is released again on failure.
+    /// If releasing the keyslot fails as well, the original error is returned.
+    pub fn load_aes_key(
+        &self,
+        name: &str,
+        manager: &mut KeyslotManager,
+        engine: &SecurityEngine,
+    ) -> Result<AesKeyslot, Error> {
+        let key = self.get(name).ok_or(Error::MissingKey)?;
+
+        if name.eq_ignore_ascii_case("tsec_key") {
+            let keyslot = manager.tsec();
+            manager.set_aes_key(engine, &keyslot, key)?;
+
+            return Ok(keyslot);
+        }
+
+        let keyslot = manager.allocate_aes()?;
+        if let Err(error) = manager.set_aes_key(engine, &keyslot, key) {
+            // Report the original error, even if the keyslot can't be released.
+            let _ = manager.release_aes(engine, keyslot);
+
+            return Err(error.into());
+        }
+
+        Ok(keyslot)
+    }
+}
+
+impl Default for Keyset {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Keyset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::vectors::*, *};
+
+    /// A keyset file with a single key generation.
+    const KEYFILE: &str = "\
+# Comments and blank lines are ignored.
+
+; So are comments in INI style.
+tsec_key = 0123456789abcdef0123456789ABCDEF
+MASTER_KEY_0a = 00112233445566778899aabbccddeeff
+unknown_key = 00
+bis_key_03 = 000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f
+";
+
+    /// A `core::fmt::Write` implementation on top of a fixed buffer.
+    struct Buffer {
+        data: [u8; 0x400],
+        length: usize,
+    }
+
+    impl Buffer {
+        fn new() -> Self {
+            Buffer {
+                data: [0; 0x400],
+                length: 0,
+            }
+        }
+
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.data[..self.length]).unwrap()
+        }
+    }
+
+    impl Write for Buffer {
+        fn write_str(&mut self, text: &str) -> fmt::Result {
+            let end = self.length + text.len();
+            self.data
+                .get_mut(self.length..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(text.as_bytes());
+            self.length = end;
+
+            Ok(())
+        }
+    }
+
+    /// Tests that single and indexed keys are parsed, case-insensitively.
+    #[test]
+    fn parse_keys() {
+        let keyset = Keyset::parse(KEYFILE).unwrap();
+
+        assert_eq!(
+            keyset.tsec_key,
+            Some([
+                0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0x01, 0x23, 0x45, 0x67, 0x89, 0xAB,
+                0xCD, 0xEF,
+            ])
+        );
+        assert_eq!(
+            keyset.master_key[0xA],
+            Some([
+                0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD,
+                0xEE, 0xFF,
+            ])
+        );
+        assert_eq!(keyset.get("master_key_0A"), keyset.get("master_key_0a"));
+        assert!(keyset.master_key.iter().filter(|key| key.is_some()).count() == 1);
+        assert_eq!(keyset.get("bis_key_03").map(|key| key[0x1F]), Some(0x1F));
+        assert_eq!(keyset.get("master_key_20"), None);
+        assert_eq!(keyset.get("unknown_key"), None);
+    }
+
+    /// Tests that a written keyset parses back into the same keys.
+    #[test]
+    fn write_round_trip() {
+        let keyset = Keyset::parse(KEYFILE).unwrap();
+
+        let mut buffer = Buffer::new();
+        keyset.write(&mut buffer).unwrap();
+        assert_eq!(
+            buffer.as_str(),
+            "tsec_key = 0123456789abcdef0123456789abcdef\n\
+             master_key_0a = 00112233445566778899aabbccddeeff\n\
+             bis_key_03 = 000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f\n"
+        );
+
+        let parsed = Keyset::parse(buffer.as_str()).unwrap();
+        assert_eq!(parsed.tsec_key, keyset.tsec_key);
+        assert_eq!(parsed.master_key, keyset.master_key);
+        assert_eq!(parsed.bis_key, keyset.bis_key);
+    }
+
+    /// Tests that malformed lines, values and indices are rejected.
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Keyset::parse("\ntsec_key 00").err(),
+            Some(Error::InvalidLine(2))
+        );
+        assert_eq!(Keyset::parse(" = 00").err(), Some(Error::InvalidLine(1)));
+        assert_eq!(
+            Keyset::parse("tsec_key = 0011").err(),
+            Some(Error::InvalidLength(1))
+        );
+        assert_eq!(
+            Keyset::parse("tsec_key = 00112233445566778899aabbccddeeff00").err(),
+            Some(Error::InvalidLength(1))
+        );
+        assert_eq!(
+            Keyset::parse("tsec_key = 0011223344556677889gaabbccddeeff").err(),
+            Some(Error::InvalidHex(1))
+        );
+        assert_eq!(
+            Keyset::parse("master_key_20 = 00112233445566778899aabbccddeeff").err(),
+            Some(Error::InvalidIndex(1))
+        );
+        assert_eq!(
+            Keyset::parse("keyblob_key_06 = 00112233445566778899aabbccddeeff").err(),
+            Some(Error::InvalidIndex(1))
+        );
+    }
+
+    /// Tests that updating a keyset replaces existing keys.
+    #[test]
+    fn update_overrides_keys() {
+        let mut keyset = Keyset::parse(KEYFILE).unwrap();
+
+        keyset
+            .update("tsec_key = ffffffffffffffffffffffffffffffff")
+            .unwrap();
+
+        assert_eq!(keyset.tsec_key, Some([0xFF; KEY_SIZE]));
+        assert!(keyset.master_key[0xA].is_some());
+    }
+
+    /// Tests that the key sources are extracted and missing ones are reported.
+    #[test]
+    fn extract_key_sources() {
+        let mut buffer = Buffer::new();
+        let sources = key_sources();
+
+        write_key(
+            &mut buffer,
+            "keyblob_mac_key_source",
+            None,
+            &sources.keyblob_mac_key_source,
+        )
+        .unwrap();
+        write_key(
+            &mut buffer,
+            "master_key_source",
+            None,
+            &sources.master_key_source,
+        )
+        .unwrap();
+        write_key(
+            &mut buffer,
+            "per_console_key_source",
+            None,
+            &sources.per_console_key_source,
+        )
+        .unwrap();
+        write_key(
+            &mut buffer,
+            "retail_specific_aes_key_source",
+            None,
+            &sources.retail_specific_aes_key_source,
+        )
+        .unwrap();
+        write_key(
+            &mut buffer,
+            "aes_kek_generation_source",
+            None,
+            &sources.aes_kek_generation_source,
+        )
+        .unwrap();
+        write_key(
+            &mut buffer,
+            "aes_key_generation_source",
+            None,
+            &sources.aes_key_generation_source,
+        )
+        .unwrap();
+        write_key(&mut buffer, "bis_kek_source", None, &sources.bis_kek_source).unwrap();
+
+        let mut keyset = Keyset::parse(buffer.as_str()).unwrap();
+        assert_eq!(keyset.key_sources(), Err(Error::MissingKey));
+
+        for (index, source) in sources.keyblob_key_sources.iter().enumerate() {
+            keyset.keyblob_key_source[index] = Some(*source);
+        }
+        assert_eq!(keyset.key_sources(), Err(Error::MissingKey));
+
+        for (index, source) in sources.bis_key_sources.iter().enumerate() {
+            keyset.bis_key_source[index] = Some(*source);
+        }
+        assert_eq!(keyset.key_sources(), Ok(sources));
+
+        keyset.bis_kek_source = None;
+        assert_eq!(keyset.key_sources(), Err(Error::MissingKey));
+    }
+
+    /// Tests that derived keys are added for their key generation.
+    #[test]
+    fn add_derived_keys() {
+        let keys = DerivedKeys {
+            master_key: MASTER_KEY_02,
+            device_key: DEVICE_KEY,
+            package1_key: Some(PACKAGE1_KEY_02),
+            bis_keys: BIS_KEYS,
+        };
+
+        let mut keyset = Keyset::new();
+        keyset.add_derived_keys(2, &keys).unwrap();
+
+        assert_eq!(keyset.master_key[2], Some(MASTER_KEY_02));
+        assert_eq!(keyset.device_key, Some(DEVICE_KEY));
+        assert_eq!(keyset.package1_key[2], Some(PACKAGE1_KEY_02));
+        assert_eq!(keyset.get("bis_key_03"), Some(&BIS_KEYS[3][..]));
+        assert!(keyset.master_key[0].is_none());
+
+        assert_eq!(
+            keyset.add_derived_keys(MAX_KEY_REVISIONS, &keys),
+            Err(Error::InvalidRevision(MAX_KEY_REVISIONS))
+        );
+    }
+}
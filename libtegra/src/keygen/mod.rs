@@ -0,0 +1,536 @@
+//! Switch key derivation.
+//!
+//! # Description
+//!
+//! The keys used by Horizon are derived from the Secure Boot Key (SBK),
+//! the TSEC key and a set of constant key sources. Up to firmware 6.0.0,
+//! the master key encryption key (KEK) and the package1 key are stored in
+//! encrypted keyblobs on the eMMC. Starting with 6.2.0, the master KEK is
+//! derived from the TSEC root key instead.
+//!
+//! # Implementation
+//!
+//! - The derivation is written against the [`Crypto`] trait. On hardware,
+//! keys never leave the keyslots unless they are needed in memory, while
+//! the software backend allows the pipeline to be tested on the host.
+//!
+//! - The key sources are not part of this crate and have to be provided
+//! through [`KeySources`], e.g. from a keyfile.
+//!
+//! - The keyblob key is `SBK(TSEC(keyblob_key_source))`. Keyblobs are
+//! verified through their AES-CMAC before they are decrypted with AES-CTR.
+//!
+//! - The device key is derived from the keyblob key of the first revision
+//! and used to derive the BIS keys.
+//!
+//! - [`derive_keys`] runs the whole pipeline. It leaves the master key,
+//! the device key and the keyblob key in the keyslots described by the
+//! `KEYSLOT_*` constants and returns the keys that are needed in memory,
+//! which includes the master key and the device key for exporting them.
+//! These keyslots are reserved by the [`KeyslotManager`].
+//!
+//! - Known keys can be read from and derived keys written to keyset files
+//! through the [`keyset`] module.
+//!
+//! [`Crypto`]: ../crypto/trait.Crypto.html
+//! [`KeySources`]: struct.KeySources.html
+//! [`derive_keys`]: fn.derive_keys.html
+//! [`keyset`]: keyset/index.html
+//! [`KeyslotManager`]: ../se/keyslot/struct.KeyslotManager.html
+
+use crate::{
+    crypto::{self, Crypto, AES_BLOCK_SIZE},
+    se::keyslot::{KEYSLOT_KEYGEN_TEMP as KEYSLOT_TEMP, KEYSLOT_SBK, KEYSLOT_TSEC},
+};
+
+pub use crate::se::keyslot::{KEYSLOT_DEVICE_KEY, KEYSLOT_KEYBLOB_KEY, KEYSLOT_MASTER_KEY};
+
+pub mod keyset;
+#[cfg(test)]
+mod vectors;
+
+/// The size of a 128-bit key.
+pub const KEY_SIZE: usize = 0x10;
+
+/// The size of a BIS key, consisting of a crypt and a tweak key.
+pub const BIS_KEY_SIZE: usize = 0x20;
+
+/// The number of BIS keys.
+pub const BIS_KEY_COUNT: usize = 4;
+
+/// The number of keyblob revisions, from 1.0.0 up to 6.0.0.
+pub const KEYBLOB_REVISIONS: usize = 6;
+
+/// The size of an encrypted keyblob.
+pub const KEYBLOB_SIZE: usize = 0xB0;
+
+/// The distance between two keyblobs in storage.
+pub const KEYBLOB_STRIDE: usize = 0x200;
+
+/// The offset of the AES-CTR counter in an encrypted keyblob.
+const KEYBLOB_CTR_OFFSET: usize = 0x10;
+/// The offset of the encrypted data in an encrypted keyblob.
+const KEYBLOB_DATA_OFFSET: usize = 0x20;
+/// The offset of the package1 key in the decrypted keyblob data.
+const KEYBLOB_PACKAGE1_KEY_OFFSET: usize = 0x80;
+
+/// Enumeration of possible key derivation errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A cryptographic operation failed.
+    Crypto(crypto::Error),
+    /// The keyblob revision is not supported.
+    InvalidRevision(usize),
+    /// The keyblob has an invalid size.
+    InvalidKeyblob,
+    /// The AES-CMAC of the keyblob doesn't match, e.g. due to a wrong SBK or TSEC key.
+    KeyblobMacMismatch(usize),
+}
+
+impl From<crypto::Error> for Error {
+    fn from(error: crypto::Error) -> Self {
+        Error::Crypto(error)
+    }
+}
+
+/// The constant key sources required for the derivation.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeySources {
+    /// The sources of the keyblob keys, per keyblob revision.
+    pub keyblob_key_sources: [[u8; KEY_SIZE]; KEYBLOB_REVISIONS],
+    /// The source of the keyblob MAC keys.
+    pub keyblob_mac_key_source: [u8; KEY_SIZE],
+    /// The source of the master key.
+    pub master_key_source: [u8; KEY_SIZE],
+    /// The source of the device key.
+    pub per_console_key_source: [u8; KEY_SIZE],
+    /// The source of the KEK of the first BIS key.
+    pub retail_specific_aes_key_source: [u8; KEY_SIZE],
+    /// The source of KEKs generated from the device key.
+    pub aes_kek_generation_source: [u8; KEY_SIZE],
+    /// The source of keys generated from a generated KEK.
+    pub aes_key_generation_source: [u8; KEY_SIZE],
+    /// The source of the KEK of the remaining BIS keys.
+    pub bis_kek_source: [u8; KEY_SIZE],
+    /// The sources of the BIS keys. The last BIS key uses the source of the second-to-last one.
+    pub bis_key_sources: [[u8; BIS_KEY_SIZE]; BIS_KEY_COUNT - 1],
+}
+
+/// The origin of the master KEK.
+#[derive(Clone, Copy, Debug)]
+pub enum MasterKekSource<'a> {
+    /// An encrypted keyblob read from storage, used up to firmware 6.0.0.
+    Keyblob {
+        /// The keyblob revision.
+        revision: usize,
+        /// The encrypted keyblob.
+        keyblob: &'a [u8],
+    },
+    /// The TSEC root key, used starting with firmware 6.2.0.
+    TsecRootKey {
+        /// The TSEC root key.
+        root_key: [u8; KEY_SIZE],
+        /// The master KEK source of the key generation.
+        master_kek_source: [u8; KEY_SIZE],
+    },
+}
+
+/// A decrypted keyblob.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Keyblob {
+    /// The master key encryption key.
+    pub master_kek: [u8; KEY_SIZE],
+    /// The key to decrypt package1 with.
+    pub package1_key: [u8; KEY_SIZE],
+}
+
+/// The keys that are returned by the derivation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DerivedKeys {
+    /// The master key of the key generation.
+    pub master_key: [u8; KEY_SIZE],
+    /// The device key.
+    pub device_key: [u8; KEY_SIZE],
+    /// The package1 key, only available for keyblob revisions.
+    pub package1_key: Option<[u8; KEY_SIZE]>,
+    /// The BIS keys, each consisting of the crypt key followed by the tweak key.
+    pub bis_keys: [[u8; BIS_KEY_SIZE]; BIS_KEY_COUNT],
+}
+
+/// Converts the TSEC key, as returned by `Tsec::get_key`, to bytes.
+pub fn tsec_key_to_bytes(key: [u32; 4]) -> [u8; KEY_SIZE] {
+    let mut bytes = [0; KEY_SIZE];
+
+    for (chunk, word) in bytes.chunks_mut(4).zip(key.iter()) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+
+    bytes
+}
+
+/// Derives the keyblob key of a revision into `KEYSLOT_KEYBLOB_KEY`.
+///
+/// The TSEC key must be in `KEYSLOT_TSEC` and the SBK in `KEYSLOT_SBK`.
+pub fn derive_keyblob_key<C: Crypto + ?Sized>(
+    crypto: &mut C,
+    sources: &KeySources,
+    revision: usize,
+) -> Result<(), Error> {
+    let source = sources
+        .keyblob_key_sources
+        .get(revision)
+        .ok_or(Error::InvalidRevision(revision))?;
+
+    let mut wrapped_key = [0; KEY_SIZE];
+    crypto.aes_ecb_decrypt(KEYSLOT_TSEC, &mut wrapped_key, source)?;
+    crypto.unwrap_aes_key(KEYSLOT_KEYBLOB_KEY, KEYSLOT_SBK, &wrapped_key)?;
+
+    Ok(())
+}
+
+/// Verifies and decrypts a keyblob with the key in `KEYSLOT_KEYBLOB_KEY`.
+pub fn decrypt_keyblob<C: Crypto + ?Sized>(
+    crypto: &mut C,
+    sources: &KeySources,
+    revision: usize,
+    keyblob: &[u8],
+) -> Result<Keyblob, Error> {
+    let keyblob = keyblob.get(..KEYBLOB_SIZE).ok_or(Error::InvalidKeyblob)?;
+
+    // Verify the AES-CMAC over the counter and the encrypted data.
+    crypto.unwrap_aes_key(
+        KEYSLOT_TEMP,
+        KEYSLOT_KEYBLOB_KEY,
+        &sources.keyblob_mac_key_source,
+    )?;
+    let mac = crypto.aes_cmac(KEYSLOT_TEMP, &keyblob[KEYBLOB_CTR_OFFSET..])?;
+    crypto.set_aes_key(KEYSLOT_TEMP, &[0; KEY_SIZE])?;
+
+    if !constant_time_eq(&mac, &keyblob[..KEYBLOB_CTR_OFFSET]) {
+        return Err(Error::KeyblobMacMismatch(revision));
+    }
+
+    let mut ctr = [0; AES_BLOCK_SIZE];
+    ctr.copy_from_slice(&keyblob[KEYBLOB_CTR_OFFSET..KEYBLOB_DATA_OFFSET]);
+
+    let mut data = [0; KEYBLOB_SIZE - KEYBLOB_DATA_OFFSET];
+    crypto.aes_ctr(
+        KEYSLOT_KEYBLOB_KEY,
+        &ctr,
+        &mut data,
+        &keyblob[KEYBLOB_DATA_OFFSET..],
+    )?;
+
+    let mut decrypted = Keyblob {
+        master_kek: [0; KEY_SIZE],
+        package1_key: [0; KEY_SIZE],
+    };
+    decrypted.master_kek.copy_from_slice(&data[..KEY_SIZE]);
+    decrypted.package1_key.copy_from_slice(
+        &data[KEYBLOB_PACKAGE1_KEY_OFFSET..KEYBLOB_PACKAGE1_KEY_OFFSET + KEY_SIZE],
+    );
+
+    Ok(decrypted)
+}
+
+/// Derives the BIS keys from the device key in `KEYSLOT_DEVICE_KEY`.
+pub fn derive_bis_keys<C: Crypto + ?Sized>(
+    crypto: &mut C,
+    sources: &KeySources,
+) -> Result<[[u8; BIS_KEY_SIZE]; BIS_KEY_COUNT], Error> {
+    let mut bis_keys = [[0; BIS_KEY_SIZE]; BIS_KEY_COUNT];
+
+    // The first BIS key uses a retail-specific KEK.
+    crypto.unwrap_aes_key(
+        KEYSLOT_TEMP,
+        KEYSLOT_DEVICE_KEY,
+        &sources.retail_specific_aes_key_source,
+    )?;
+    crypto.aes_ecb_decrypt(KEYSLOT_TEMP, &mut bis_keys[0], &sources.bis_key_sources[0])?;
+
+    // The remaining BIS keys use a generated KEK.
+    crypto.unwrap_aes_key(
+        KEYSLOT_TEMP,
+        KEYSLOT_DEVICE_KEY,
+        &sources.aes_kek_generation_source,
+    )?;
+    crypto.unwrap_aes_key(KEYSLOT_TEMP, KEYSLOT_TEMP, &sources.bis_kek_source)?;
+    crypto.unwrap_aes_key(
+        KEYSLOT_TEMP,
+        KEYSLOT_TEMP,
+        &sources.aes_key_generation_source,
+    )?;
+    for (key, source) in bis_keys[1..]
+        .iter_mut()
+        .zip(sources.bis_key_sources[1..].iter())
+    {
+        crypto.aes_ecb_decrypt(KEYSLOT_TEMP, key, source)?;
+    }
+    bis_keys[BIS_KEY_COUNT - 1] = bis_keys[BIS_KEY_COUNT - 2];
+
+    crypto.set_aes_key(KEYSLOT_TEMP, &[0; KEY_SIZE])?;
+
+    Ok(bis_keys)
+}
+
+/// Runs the full key derivation.
+///
+/// The SBK must be in `KEYSLOT_SBK`. The TSEC key is loaded into
+/// `KEYSLOT_TSEC`. Afterwards, the master key is in `KEYSLOT_MASTER_KEY`,
+/// the device key in `KEYSLOT_DEVICE_KEY` and the keyblob key of the used
+/// revision in `KEYSLOT_KEYBLOB_KEY`.
+pub fn derive_keys<C: Crypto + ?Sized>(
+    crypto: &mut C,
+    sources: &KeySources,
+    tsec_key: &[u8; KEY_SIZE],
+    master_kek_source: MasterKekSource,
+) -> Result<DerivedKeys, Error> {
+    crypto.set_aes_key(KEYSLOT_TSEC, tsec_key)?;
+
+    // The device key is always derived from the first keyblob key.
+    derive_keyblob_key(crypto, sources, 0)?;
+    let mut device_key = [0; KEY_SIZE];
+    crypto.aes_ecb_decrypt(
+        KEYSLOT_KEYBLOB_KEY,
+        &mut device_key,
+        &sources.per_console_key_source,
+    )?;
+    crypto.unwrap_aes_key(
+        KEYSLOT_DEVICE_KEY,
+        KEYSLOT_KEYBLOB_KEY,
+        &sources.per_console_key_source,
+    )?;
+
+    let package1_key = match master_kek_source {
+        MasterKekSource::Keyblob { revision, keyblob } => {
+            derive_keyblob_key(crypto, sources, revision)?;
+            let keyblob = decrypt_keyblob(crypto, sources, revision, keyblob)?;
+
+            crypto.set_aes_key(KEYSLOT_TEMP, &keyblob.master_kek)?;
+
+            Some(keyblob.package1_key)
+        }
+        MasterKekSource::TsecRootKey {
+            root_key,
+            master_kek_source,
+        } => {
+            crypto.set_aes_key(KEYSLOT_TEMP, &root_key)?;
+            crypto.unwrap_aes_key(KEYSLOT_TEMP, KEYSLOT_TEMP, &master_kek_source)?;
+
+            None
+        }
+    };
+
+    let mut master_key = [0; KEY_SIZE];
+    crypto.aes_ecb_decrypt(KEYSLOT_TEMP, &mut master_key, &sources.master_key_source)?;
+    crypto.unwrap_aes_key(KEYSLOT_MASTER_KEY, KEYSLOT_TEMP, &sources.master_key_source)?;
+    crypto.set_aes_key(KEYSLOT_TEMP, &[0; KEY_SIZE])?;
+
+    let bis_keys = derive_bis_keys(crypto, sources)?;
+
+    Ok(DerivedKeys {
+        master_key,
+        device_key,
+        package1_key,
+        bis_keys,
+    })
+}
+
+/// Compares two buffers without exiting early on the first mismatch.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b.iter()).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{vectors::*, *};
+    use crate::crypto::soft::SoftwareCrypto;
+
+    /// Creates a software backend with the SBK loaded.
+    fn crypto() -> SoftwareCrypto {
+        let mut crypto = SoftwareCrypto::new();
+        crypto.set_aes_key(KEYSLOT_SBK, &SBK).unwrap();
+
+        crypto
+    }
+
+    /// Asserts that a keyslot holds the expected key.
+    fn assert_keyslot(crypto: &mut SoftwareCrypto, keyslot: usize, expected: &[u8; KEY_SIZE]) {
+        let mut actual = [0; AES_BLOCK_SIZE];
+        crypto
+            .aes_ecb_encrypt(keyslot, &mut actual, &[0; AES_BLOCK_SIZE])
+            .unwrap();
+
+        let mut reference = SoftwareCrypto::new();
+        let mut expected_block = [0; AES_BLOCK_SIZE];
+        reference.set_aes_key(0, expected).unwrap();
+        reference
+            .aes_ecb_encrypt(0, &mut expected_block, &[0; AES_BLOCK_SIZE])
+            .unwrap();
+
+        assert_eq!(actual, expected_block);
+    }
+
+    /// Tests the derivation from the keyblob of the first revision.
+    #[test]
+    fn keyblob_first_revision() {
+        let mut crypto = crypto();
+
+        let keys = derive_keys(
+            &mut crypto,
+            &key_sources(),
+            &TSEC_KEY,
+            MasterKekSource::Keyblob {
+                revision: 0,
+                keyblob: &KEYBLOB_00,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(keys.package1_key, Some(PACKAGE1_KEY_00));
+        assert_eq!(keys.bis_keys, BIS_KEYS);
+        assert_eq!(keys.master_key, MASTER_KEY_00);
+        assert_eq!(keys.device_key, DEVICE_KEY);
+        assert_keyslot(&mut crypto, KEYSLOT_MASTER_KEY, &MASTER_KEY_00);
+        assert_keyslot(&mut crypto, KEYSLOT_DEVICE_KEY, &DEVICE_KEY);
+        assert_keyslot(&mut crypto, KEYSLOT_TEMP, &[0; KEY_SIZE]);
+    }
+
+    /// Tests the derivation from a keyblob of a later revision.
+    #[test]
+    fn keyblob_later_revision() {
+        let mut crypto = crypto();
+
+        let keys = derive_keys(
+            &mut crypto,
+            &key_sources(),
+            &TSEC_KEY,
+            MasterKekSource::Keyblob {
+                revision: 2,
+                keyblob: &KEYBLOB_02,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(keys.package1_key, Some(PACKAGE1_KEY_02));
+        assert_eq!(keys.bis_keys, BIS_KEYS);
+        assert_eq!(keys.master_key, MASTER_KEY_02);
+        assert_eq!(keys.device_key, DEVICE_KEY);
+        assert_keyslot(&mut crypto, KEYSLOT_MASTER_KEY, &MASTER_KEY_02);
+        assert_keyslot(&mut crypto, KEYSLOT_DEVICE_KEY, &DEVICE_KEY);
+    }
+
+    /// Tests the derivation from the TSEC root key.
+    #[test]
+    fn tsec_root_key() {
+        let mut crypto = crypto();
+
+        let keys = derive_keys(
+            &mut crypto,
+            &key_sources(),
+            &TSEC_KEY,
+            MasterKekSource::TsecRootKey {
+                root_key: TSEC_ROOT_KEY,
+                master_kek_source: MASTER_KEK_SOURCE,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(keys.package1_key, None);
+        assert_eq!(keys.bis_keys, BIS_KEYS);
+        assert_eq!(keys.master_key, MASTER_KEY_06);
+        assert_eq!(keys.device_key, DEVICE_KEY);
+        assert_keyslot(&mut crypto, KEYSLOT_MASTER_KEY, &MASTER_KEY_06);
+        assert_keyslot(&mut crypto, KEYSLOT_DEVICE_KEY, &DEVICE_KEY);
+    }
+
+    /// Tests that a single keyblob can be decrypted.
+    #[test]
+    fn decrypt_single_keyblob() {
+        let mut crypto = crypto();
+        let sources = key_sources();
+
+        crypto.set_aes_key(KEYSLOT_TSEC, &TSEC_KEY).unwrap();
+        derive_keyblob_key(&mut crypto, &sources, 0).unwrap();
+
+        let keyblob = decrypt_keyblob(&mut crypto, &sources, 0, &KEYBLOB_00).unwrap();
+        assert_eq!(keyblob.master_kek, MASTER_KEK_00);
+        assert_eq!(keyblob.package1_key, PACKAGE1_KEY_00);
+    }
+
+    /// Tests that tampered keyblobs and wrong keys are detected.
+    #[test]
+    fn keyblob_mac_mismatch() {
+        let mut keyblob = KEYBLOB_02;
+        keyblob[0x40] ^= 1;
+
+        assert_eq!(
+            derive_keys(
+                &mut crypto(),
+                &key_sources(),
+                &TSEC_KEY,
+                MasterKekSource::Keyblob {
+                    revision: 2,
+                    keyblob: &keyblob,
+                },
+            ),
+            Err(Error::KeyblobMacMismatch(2))
+        );
+
+        assert_eq!(
+            derive_keys(
+                &mut crypto(),
+                &key_sources(),
+                &TSEC_ROOT_KEY,
+                MasterKekSource::Keyblob {
+                    revision: 2,
+                    keyblob: &KEYBLOB_02,
+                },
+            ),
+            Err(Error::KeyblobMacMismatch(2))
+        );
+    }
+
+    /// Tests that invalid revisions and keyblobs are rejected.
+    #[test]
+    fn invalid_keyblob() {
+        assert_eq!(
+            derive_keys(
+                &mut crypto(),
+                &key_sources(),
+                &TSEC_KEY,
+                MasterKekSource::Keyblob {
+                    revision: KEYBLOB_REVISIONS,
+                    keyblob: &KEYBLOB_00,
+                },
+            ),
+            Err(Error::InvalidRevision(KEYBLOB_REVISIONS))
+        );
+
+        assert_eq!(
+            derive_keys(
+                &mut crypto(),
+                &key_sources(),
+                &TSEC_KEY,
+                MasterKekSource::Keyblob {
+                    revision: 0,
+                    keyblob: &KEYBLOB_00[..KEYBLOB_SIZE - 1],
+                },
+            ),
+            Err(Error::InvalidKeyblob)
+        );
+    }
+
+    /// Tests the conversion of the TSEC key words.
+    #[test]
+    fn tsec_key_bytes() {
+        assert_eq!(
+            tsec_key_to_bytes([0x0302_0100, 0x0706_0504, 0x0B0A_0908, 0x0F0E_0D0C]),
+            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+        );
+    }
+}
@@ -0,0 +1,212 @@
+//! Test vectors for the key derivation.
+//!
+//! The key sources and keys are made up, the expected results have been
+//! computed with an independent implementation of the derivation.
+
+use super::KeySources;
+
+/// The Secure Boot Key.
+pub const SBK: [u8; 0x10] = [
+    0x70, 0x92, 0x94, 0xD8, 0x69, 0x66, 0x9D, 0x4A, 0x1A, 0x6F, 0x55, 0xA9, 0x0D, 0xF4, 0x99, 0x60,
+];
+
+/// The TSEC key.
+pub const TSEC_KEY: [u8; 0x10] = [
+    0xA4, 0x69, 0xDE, 0x71, 0x5D, 0x72, 0x29, 0x5C, 0x9F, 0xC6, 0x2A, 0x7A, 0x9D, 0x62, 0x71, 0xCB,
+];
+
+/// The TSEC root key.
+pub const TSEC_ROOT_KEY: [u8; 0x10] = [
+    0x93, 0xF9, 0xE1, 0xA0, 0x5B, 0xD8, 0xD9, 0x9D, 0xE0, 0x18, 0xB7, 0x14, 0x1F, 0x69, 0xE4, 0x6F,
+];
+
+/// The master KEK source used with the TSEC root key.
+pub const MASTER_KEK_SOURCE: [u8; 0x10] = [
+    0x5C, 0x70, 0x15, 0x67, 0xDC, 0x54, 0x8F, 0xEC, 0xFC, 0xEF, 0x51, 0x64, 0x5D, 0x49, 0x2D, 0x35,
+];
+
+/// The keyblob key sources.
+pub const KEYBLOB_KEY_SOURCES: [[u8; 0x10]; 6] = [
+    [
+        0xB7, 0xC3, 0x4B, 0xEC, 0x91, 0x2C, 0x36, 0xA8, 0x69, 0xC1, 0x68, 0x47, 0x57, 0x2D, 0x42,
+        0x7E,
+    ],
+    [
+        0xA8, 0x04, 0x7E, 0x92, 0xDD, 0x93, 0x90, 0x95, 0x47, 0x4F, 0x13, 0xF8, 0x14, 0x0F, 0xF4,
+        0x1A,
+    ],
+    [
+        0x3D, 0x0E, 0xD1, 0x98, 0xCD, 0x84, 0xA0, 0x27, 0x6D, 0xEB, 0x3E, 0x9D, 0xF3, 0x89, 0xE2,
+        0xAF,
+    ],
+    [
+        0xED, 0x78, 0x2C, 0xE1, 0xF4, 0x32, 0x48, 0x3F, 0x32, 0x00, 0x0D, 0x82, 0x5B, 0xEB, 0xDC,
+        0x26,
+    ],
+    [
+        0x6E, 0xC3, 0xDF, 0xF4, 0x7F, 0x2A, 0x3E, 0x97, 0x18, 0x12, 0x8B, 0x9D, 0x6F, 0x1F, 0xFA,
+        0x2F,
+    ],
+    [
+        0xE8, 0xE0, 0xCD, 0x8F, 0x34, 0x18, 0xDF, 0xCA, 0x6B, 0xFB, 0xD9, 0x02, 0xCD, 0x00, 0x0E,
+        0x3E,
+    ],
+];
+
+/// The BIS key sources.
+pub const BIS_KEY_SOURCES: [[u8; 0x20]; 3] = [
+    [
+        0xAF, 0x4C, 0x0F, 0xC9, 0x28, 0xED, 0xFB, 0x66, 0x5B, 0x2C, 0x93, 0x00, 0x51, 0x54, 0xF7,
+        0x2F, 0xCB, 0x26, 0xFA, 0x0C, 0x5C, 0xD7, 0x43, 0x99, 0x91, 0x42, 0x3D, 0x28, 0xCF, 0x61,
+        0x81, 0x9C,
+    ],
+    [
+        0x11, 0x94, 0xBD, 0xDF, 0xDC, 0x7D, 0x77, 0xD9, 0x84, 0xD5, 0xE4, 0xE0, 0x3D, 0x1D, 0xD2,
+        0xD9, 0xB7, 0x5A, 0xC2, 0xDE, 0xA7, 0x5B, 0xCF, 0xC6, 0xDB, 0xDD, 0x05, 0x7C, 0x9E, 0x88,
+        0xE3, 0x3E,
+    ],
+    [
+        0x90, 0x52, 0x57, 0x3E, 0x31, 0x81, 0x3E, 0x64, 0x22, 0x54, 0x77, 0xCD, 0x46, 0xCD, 0x0E,
+        0x55, 0x8F, 0xFE, 0xF9, 0xAF, 0xC5, 0xB9, 0x52, 0xC9, 0xDF, 0x1F, 0x26, 0x24, 0x42, 0xFB,
+        0x26, 0x36,
+    ],
+];
+
+/// The keyblob MAC key source.
+pub const KEYBLOB_MAC_KEY_SOURCE: [u8; 0x10] = [
+    0xE4, 0x34, 0x4B, 0xEF, 0xB0, 0x85, 0x98, 0xA7, 0x3B, 0x62, 0x26, 0x35, 0xE6, 0xE6, 0x8A, 0x37,
+];
+
+/// The master key source.
+pub const MASTER_KEY_SOURCE: [u8; 0x10] = [
+    0x46, 0x11, 0x8D, 0x00, 0x01, 0x13, 0xAC, 0x3F, 0xA6, 0xC6, 0x5E, 0x5E, 0xA2, 0xFC, 0xCB, 0x17,
+];
+
+/// The device key source.
+pub const PER_CONSOLE_KEY_SOURCE: [u8; 0x10] = [
+    0x55, 0xA5, 0xA9, 0x89, 0x4C, 0xCC, 0xEA, 0x2A, 0xC5, 0x0F, 0x51, 0x24, 0xC7, 0x4E, 0x74, 0xB9,
+];
+
+/// The KEK source of the first BIS key.
+pub const RETAIL_SPECIFIC_AES_KEY_SOURCE: [u8; 0x10] = [
+    0x81, 0x95, 0x8A, 0x96, 0xE7, 0x23, 0x69, 0x32, 0xBD, 0xFA, 0x88, 0xFC, 0xE0, 0x80, 0xA5, 0xB7,
+];
+
+/// The KEK generation source.
+pub const AES_KEK_GENERATION_SOURCE: [u8; 0x10] = [
+    0x85, 0x9A, 0x3B, 0x8A, 0x2D, 0x6E, 0x3F, 0x85, 0x7A, 0x16, 0x3B, 0xAF, 0x94, 0x72, 0xB2, 0x73,
+];
+
+/// The key generation source.
+pub const AES_KEY_GENERATION_SOURCE: [u8; 0x10] = [
+    0x4B, 0x26, 0xE5, 0xB4, 0xFF, 0xED, 0x2A, 0xB9, 0x5F, 0x99, 0xA7, 0x6A, 0xC3, 0xA9, 0xED, 0xAF,
+];
+
+/// The BIS KEK source.
+pub const BIS_KEK_SOURCE: [u8; 0x10] = [
+    0x6A, 0x7C, 0x8A, 0xD5, 0x7E, 0xC5, 0xC8, 0xC2, 0x05, 0xC2, 0x6B, 0xB9, 0xD7, 0xA8, 0x12, 0xA2,
+];
+
+/// Gets the key sources of the test vectors.
+pub fn key_sources() -> KeySources {
+    KeySources {
+        keyblob_key_sources: KEYBLOB_KEY_SOURCES,
+        keyblob_mac_key_source: KEYBLOB_MAC_KEY_SOURCE,
+        master_key_source: MASTER_KEY_SOURCE,
+        per_console_key_source: PER_CONSOLE_KEY_SOURCE,
+        retail_specific_aes_key_source: RETAIL_SPECIFIC_AES_KEY_SOURCE,
+        aes_kek_generation_source: AES_KEK_GENERATION_SOURCE,
+        aes_key_generation_source: AES_KEY_GENERATION_SOURCE,
+        bis_kek_source: BIS_KEK_SOURCE,
+        bis_key_sources: BIS_KEY_SOURCES,
+    }
+}
+
+/// The encrypted keyblob of revision 0.
+pub const KEYBLOB_00: [u8; 0xB0] = [
+    0xE6, 0x84, 0x32, 0xD2, 0x44, 0xF8, 0x85, 0x0F, 0xC7, 0x00, 0x72, 0xCD, 0x36, 0x5C, 0x32, 0xB0,
+    0x90, 0x49, 0x89, 0x13, 0x2A, 0x8C, 0x9C, 0xF6, 0x64, 0x52, 0x5F, 0xB4, 0xC9, 0xAE, 0x59, 0x5B,
+    0xB4, 0xCB, 0xCD, 0xB4, 0x88, 0xA7, 0xE2, 0x9F, 0x9A, 0x76, 0x0C, 0xAB, 0xCB, 0x83, 0x8C, 0xFA,
+    0x0B, 0x92, 0x82, 0x30, 0xC6, 0xD7, 0xA3, 0x68, 0x8E, 0x9C, 0x05, 0x74, 0x28, 0x92, 0x6A, 0xAF,
+    0x86, 0x54, 0x98, 0xFF, 0x98, 0x58, 0xB2, 0x13, 0x25, 0x37, 0x24, 0x7A, 0xBE, 0x08, 0xD5, 0x4D,
+    0x47, 0x99, 0x3B, 0x8E, 0xF7, 0x2D, 0x90, 0x4B, 0x0B, 0xFA, 0x96, 0x45, 0x85, 0xFF, 0x83, 0x13,
+    0x57, 0xB2, 0xC6, 0x3A, 0x07, 0x2D, 0x87, 0xC3, 0xD9, 0x35, 0x54, 0x71, 0x3F, 0xD8, 0x72, 0xFB,
+    0xF7, 0x89, 0xBE, 0xF7, 0xFB, 0x22, 0x6E, 0x22, 0x19, 0x96, 0xC2, 0xFF, 0x1F, 0x61, 0x58, 0x3E,
+    0xB3, 0x60, 0x23, 0x16, 0x51, 0x52, 0x31, 0xBA, 0x8B, 0xD4, 0xC7, 0x34, 0xAA, 0xD8, 0xB9, 0x1B,
+    0x8A, 0x5D, 0x9F, 0x33, 0x93, 0x13, 0x50, 0x10, 0x18, 0x50, 0x44, 0xB8, 0x74, 0x86, 0x32, 0x7F,
+    0x3A, 0x45, 0xE2, 0x42, 0x66, 0x51, 0x87, 0x67, 0xFC, 0x36, 0xC3, 0xDD, 0xD1, 0xDE, 0x22, 0x5F,
+];
+
+/// The encrypted keyblob of revision 2.
+pub const KEYBLOB_02: [u8; 0xB0] = [
+    0x3C, 0x26, 0xDF, 0x93, 0x88, 0x83, 0xC4, 0xFC, 0x19, 0x99, 0x47, 0xB6, 0xDC, 0x32, 0xF6, 0x07,
+    0x3B, 0x71, 0x9A, 0x37, 0x67, 0xA6, 0x92, 0xBC, 0x13, 0x9A, 0x27, 0x68, 0x2A, 0xD5, 0x9A, 0x6D,
+    0x3C, 0x10, 0xA3, 0x1E, 0x54, 0x07, 0x83, 0xA9, 0x60, 0xFE, 0xCD, 0x5A, 0x40, 0xAE, 0xFC, 0x48,
+    0x5A, 0xD3, 0x15, 0xEB, 0x7F, 0xF4, 0x72, 0x37, 0xF8, 0xB5, 0xD6, 0x35, 0x91, 0x4D, 0xF3, 0xE3,
+    0xED, 0xE8, 0x87, 0x1D, 0xFC, 0xC5, 0xE9, 0xE4, 0x5F, 0xA9, 0xB8, 0xDE, 0x4D, 0x09, 0x3F, 0xBF,
+    0xC7, 0x6A, 0xA0, 0xE8, 0xA0, 0x58, 0xA7, 0x8A, 0x33, 0x2D, 0x4E, 0xCD, 0x2B, 0xAF, 0x4E, 0xA8,
+    0x89, 0x41, 0x9E, 0xFB, 0x25, 0x94, 0xF5, 0xF9, 0xB7, 0x87, 0xFA, 0xEE, 0x5C, 0x80, 0x40, 0xD3,
+    0x3F, 0xBB, 0xA8, 0x20, 0x37, 0x91, 0x4C, 0xDE, 0x05, 0x52, 0xA6, 0xD3, 0xC5, 0x1E, 0xBA, 0xC3,
+    0xA6, 0x36, 0xDE, 0xE7, 0x50, 0x67, 0xF6, 0xD0, 0xF0, 0x8D, 0x30, 0x62, 0xD7, 0x43, 0x19, 0x2E,
+    0xEC, 0x9D, 0x94, 0x98, 0xEC, 0x10, 0x82, 0x26, 0xD1, 0xFA, 0x77, 0x32, 0x46, 0x50, 0x88, 0xBD,
+    0xC4, 0xEA, 0x65, 0x60, 0x1A, 0x25, 0xBF, 0x44, 0xC3, 0x59, 0x48, 0x6A, 0xDE, 0x07, 0x6B, 0x5C,
+];
+
+/// The master KEK in the keyblob of revision 0.
+pub const MASTER_KEK_00: [u8; 0x10] = [
+    0xA9, 0xE3, 0x55, 0xCC, 0x70, 0xDB, 0x45, 0x27, 0xCD, 0x69, 0xC6, 0xB1, 0x7C, 0x1C, 0x53, 0x5B,
+];
+
+/// The package1 key in the keyblob of revision 0.
+pub const PACKAGE1_KEY_00: [u8; 0x10] = [
+    0x96, 0x1A, 0x15, 0xFA, 0x85, 0xF7, 0x9E, 0x4D, 0x5C, 0x20, 0x78, 0xE6, 0xA0, 0xA2, 0x83, 0x2B,
+];
+
+/// The package1 key in the keyblob of revision 2.
+pub const PACKAGE1_KEY_02: [u8; 0x10] = [
+    0x9E, 0x65, 0x08, 0xE8, 0x00, 0xD6, 0xC7, 0x1A, 0x86, 0x0A, 0xE6, 0x6C, 0x4D, 0x3B, 0xCB, 0xCB,
+];
+
+/// The master key derived from the keyblob of revision 0.
+pub const MASTER_KEY_00: [u8; 0x10] = [
+    0xBE, 0x1A, 0x43, 0xC0, 0x4B, 0x1D, 0x8A, 0x71, 0x61, 0xE8, 0x8E, 0x83, 0x4F, 0x12, 0xCA, 0x33,
+];
+
+/// The master key derived from the keyblob of revision 2.
+pub const MASTER_KEY_02: [u8; 0x10] = [
+    0x05, 0x5C, 0x6E, 0x1D, 0xB3, 0x02, 0x1C, 0x96, 0x55, 0x44, 0xA5, 0x2E, 0x4B, 0x7C, 0xD2, 0x18,
+];
+
+/// The master key derived from the TSEC root key.
+pub const MASTER_KEY_06: [u8; 0x10] = [
+    0xA1, 0xD7, 0x5C, 0x4E, 0xE8, 0x38, 0x21, 0x48, 0x97, 0x17, 0x4D, 0xD7, 0x92, 0x27, 0x27, 0x8B,
+];
+
+/// The device key.
+pub const DEVICE_KEY: [u8; 0x10] = [
+    0xE2, 0x67, 0x36, 0x61, 0x03, 0x4E, 0xB6, 0x6E, 0x28, 0x57, 0xAB, 0x46, 0xAE, 0x11, 0xF4, 0x64,
+];
+
+/// The BIS keys.
+pub const BIS_KEYS: [[u8; 0x20]; 4] = [
+    [
+        0xBD, 0xDA, 0x47, 0x77, 0xFB, 0x30, 0x53, 0xB0, 0xD4, 0x92, 0x76, 0x37, 0x7F, 0x31, 0x2A,
+        0xA3, 0xE3, 0x4B, 0xD0, 0x42, 0xB8, 0xF4, 0xBB, 0xE7, 0x2B, 0xD8, 0xDC, 0x0A, 0x13, 0x27,
+        0xEF, 0x77,
+    ],
+    [
+        0x05, 0x2C, 0x3E, 0xF1, 0x76, 0xC6, 0x3C, 0x15, 0x17, 0x91, 0x91, 0x31, 0xE2, 0x66, 0x74,
+        0x68, 0x09, 0x65, 0x62, 0x6D, 0x61, 0x41, 0x22, 0x4E, 0xD5, 0xF1, 0x44, 0xAD, 0xD6, 0xDD,
+        0x0E, 0x63,
+    ],
+    [
+        0x39, 0xBF, 0x12, 0xBC, 0xD1, 0xE0, 0x37, 0x0D, 0xA8, 0x29, 0x58, 0x37, 0x77, 0x20, 0xC9,
+        0xA2, 0xF9, 0x3C, 0x7B, 0xD1, 0x35, 0xBC, 0x8D, 0x38, 0xC7, 0x73, 0x3D, 0x55, 0xBB, 0xD4,
+        0xCB, 0xBE,
+    ],
+    [
+        0x39, 0xBF, 0x12, 0xBC, 0xD1, 0xE0, 0x37, 0x0D, 0xA8, 0x29, 0x58, 0x37, 0x77, 0x20, 0xC9,
+        0xA2, 0xF9, 0x3C, 0x7B, 0xD1, 0x35, 0xBC, 0x8D, 0x38, 0xC7, 0x73, 0x3D, 0x55, 0xBB, 0xD4,
+        0xCB, 0xBE,
+    ],
+];
@@ -1,4 +1,26 @@
 //! Tegra210 KFUSE implementation.
+//!
+//! # Implementation
+//!
+//! - [`read`] reads the raw KFUSE words, relying on the hardware to
+//! correct single-bit errors and to verify the CRC of the array.
+//!
+//! - [`read_error_count`] reports the errors the KFUSE hardware encountered
+//! while loading the array.
+//!
+//! - [`parse_dump`] reads the words from a dump of the array, so that dumps
+//! captured from other units can be compared on a host.
+//!
+//! - The KFUSE words are consumed by the HDMI block, which receives them
+//! as opaque 128-bit rows (see `load_kfuse` in the `nvhdcp` driver of
+//! NVIDIA's Linux kernel). The layout of the HDCP keys within these rows
+//! and the ECC and CRC of the array are not publicly documented. Decoding
+//! the HDCP keys and verifying the ECC and CRC in software is therefore
+//! **not implemented**, only the hardware CRC check is available.
+//!
+//! [`read`]: fn.read.html
+//! [`read_error_count`]: fn.read_error_count.html
+//! [`parse_dump`]: fn.parse_dump.html
 
 use mirage_mmio::{Mmio, VolatileStorage};
 
@@ -13,6 +35,48 @@ pub(crate) const KFUSE_KEYADDR_AUTOINC: u32 = (1 << 16);
 
 pub const KFUSE_NUM_WORDS: u32 = 144;
 
+/// The size of a KFUSE dump, in bytes.
+pub const KFUSE_DUMP_SIZE: usize = KFUSE_NUM_WORDS as usize * 4;
+
+/// Enumeration of possible errors when reading KFUSE.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The hardware CRC check failed.
+    CrcFailed,
+    /// A dump has an invalid size.
+    InvalidSize(usize),
+}
+
+/// Decoded contents of the `KFUSE_ERRCOUNT_0` register.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ErrorCount {
+    /// The number of single-bit errors that were corrected.
+    pub err_1: u8,
+    /// The number of double-bit errors that were detected.
+    pub err_2: u8,
+    /// The number of triple-bit errors that were detected.
+    pub err_3: u8,
+    /// The number of fatal errors.
+    pub err_fatal: u8,
+}
+
+impl ErrorCount {
+    /// Decodes the raw value of the `KFUSE_ERRCOUNT_0` register.
+    pub fn from_raw(value: u32) -> Self {
+        ErrorCount {
+            err_1: (value & 0x7F) as u8,
+            err_2: ((value >> 8) & 0x7F) as u8,
+            err_3: ((value >> 16) & 0x7F) as u8,
+            err_fatal: ((value >> 24) & 0x7F) as u8,
+        }
+    }
+
+    /// Whether the hardware encountered errors it couldn't correct.
+    pub fn is_fatal(&self) -> bool {
+        self.err_2 != 0 || self.err_3 != 0 || self.err_fatal != 0
+    }
+}
+
 /// Representation of the HDCP KFUSE registers.
 #[allow(non_snake_case)]
 #[repr(C)]
@@ -36,9 +100,26 @@ impl VolatileStorage for KfuseRegisters {
     }
 }
 
+/// Reads the error counters of the KFUSE hardware.
+pub fn read_error_count() -> ErrorCount {
+    let registers = unsafe { KfuseRegisters::get() };
+
+    Clock::KFUSE.enable();
+
+    while (registers.STATE.read() & KFUSE_STATE_DONE) == 0 {
+        // Wait.
+    }
+
+    let count = ErrorCount::from_raw(registers.ERRCOUNT.read());
+
+    Clock::KFUSE.disable();
+
+    count
+}
+
 /// Reads the KFuse contents into a buffer.
 #[optimize(size)]
-pub fn read(buffer: &mut [u32]) -> Result<(), ()> {
+pub fn read(buffer: &mut [u32; KFUSE_NUM_WORDS as usize]) -> Result<(), Error> {
     let registers = unsafe { KfuseRegisters::get() };
 
     Clock::KFUSE.enable();
@@ -49,16 +130,72 @@ pub fn read(buffer: &mut [u32]) -> Result<(), ()> {
 
     if (registers.STATE.read() & KFUSE_STATE_CRCPASS) == 0 {
         Clock::KFUSE.disable();
-        return Err(());
+        return Err(Error::CrcFailed);
     }
 
     registers.KEYADDR.write(KFUSE_KEYADDR_AUTOINC);
 
-    for i in 0..KFUSE_NUM_WORDS {
-        buffer[i as usize] = registers.KEYS.read();
+    for word in buffer.iter_mut() {
+        *word = registers.KEYS.read();
     }
 
     Clock::KFUSE.disable();
 
     Ok(())
 }
+
+/// Parses a dump of the KFUSE words, stored in little-endian byte order.
+pub fn parse_dump(dump: &[u8], buffer: &mut [u32; KFUSE_NUM_WORDS as usize]) -> Result<(), Error> {
+    if dump.len() != KFUSE_DUMP_SIZE {
+        return Err(Error::InvalidSize(dump.len()));
+    }
+
+    for (word, chunk) in buffer.iter_mut().zip(dump.chunks_exact(4)) {
+        *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests the decoding of the error counters.
+    #[test]
+    fn error_count_from_raw() {
+        let count = ErrorCount::from_raw(0x8000_0003);
+        assert_eq!(count.err_1, 3);
+        assert!(!count.is_fatal());
+
+        let count = ErrorCount::from_raw(0x0102_0304);
+        assert_eq!(
+            count,
+            ErrorCount {
+                err_1: 4,
+                err_2: 3,
+                err_3: 2,
+                err_fatal: 1,
+            }
+        );
+        assert!(count.is_fatal());
+    }
+
+    /// Tests the parsing of KFUSE dumps.
+    #[test]
+    fn parse_dumps() {
+        let mut dump = [0; KFUSE_DUMP_SIZE];
+        dump[..4].copy_from_slice(&[0x78, 0x56, 0x34, 0x12]);
+        dump[KFUSE_DUMP_SIZE - 1] = 0xA5;
+
+        let mut words = [0; KFUSE_NUM_WORDS as usize];
+        assert_eq!(parse_dump(&dump, &mut words), Ok(()));
+        assert_eq!(words[0], 0x1234_5678);
+        assert_eq!(words[KFUSE_NUM_WORDS as usize - 1], 0xA500_0000);
+
+        assert_eq!(
+            parse_dump(&dump[..KFUSE_DUMP_SIZE - 4], &mut words),
+            Err(Error::InvalidSize(KFUSE_DUMP_SIZE - 4))
+        );
+    }
+}
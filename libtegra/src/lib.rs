@@ -22,10 +22,14 @@ pub mod apb_misc;
 pub mod button;
 pub mod clock;
 pub mod cluster;
+pub mod crc;
+pub mod crypto;
 pub mod display;
+pub mod falcon;
 pub mod fuse;
 pub mod gpio;
 pub mod i2c;
+pub mod keygen;
 pub mod kfuse;
 pub mod mc;
 pub mod pinmux;
@@ -0,0 +1,320 @@
+//! Memory Controller security carveouts.
+//!
+//! # Description
+//!
+//! Carveouts are physical memory regions that the MC protects from
+//! unauthorized memory clients. The Tegra210 has five general security
+//! carveouts, which grant access to individual clients, and a few fixed
+//! carveouts for specific purposes, like the TSEC carveout and the Video
+//! Protection Region (VPR).
+//!
+//! Once a carveout is locked, its configuration cannot be changed until
+//! the next reset.
+//!
+//! # Implementation
+//!
+//! - [`Carveout`] describes the configuration of a general security
+//! carveout. It is programmed with [`Carveout::program`] and can be
+//! read back through [`Carveout::read`].
+//!
+//! - [`CarveoutConfig`] represents the `CFG0` register of a general
+//! security carveout, setting [`CarveoutConfig::LOCKED`] locks it.
+//!
+//! - [`FixedCarveout`] represents the carveouts that only consist of
+//! a base address and a size in MiB.
+//!
+//! [`Carveout`]: struct.Carveout.html
+//! [`Carveout::program`]: struct.Carveout.html#method.program
+//! [`Carveout::read`]: struct.Carveout.html#method.read
+//! [`CarveoutConfig`]: struct.CarveoutConfig.html
+//! [`CarveoutConfig::LOCKED`]: struct.CarveoutConfig.html#associatedconstant.LOCKED
+//! [`FixedCarveout`]: enum.FixedCarveout.html
+
+use mirage_mmio::Mmio;
+
+use super::{Error, MC_BASE};
+
+/// The number of general security carveouts.
+pub const CARVEOUT_COUNT: usize = 5;
+
+/// The number of client access registers per carveout.
+pub const CLIENT_ACCESS_REGISTERS: usize = 5;
+
+/// The granularity of general security carveouts.
+pub const CARVEOUT_GRANULARITY: u64 = 128 * 1024;
+
+/// The granularity of fixed carveouts.
+pub const FIXED_CARVEOUT_GRANULARITY: u64 = 1024 * 1024;
+
+/// The offset of the first general security carveout.
+const CARVEOUT_OFFSET: u32 = 0xC08;
+
+/// The distance between two general security carveouts.
+const CARVEOUT_STRIDE: u32 = 0x50;
+
+bitflags! {
+    /// Representation of the `MC_SECURITY_CARVEOUTx_CFG0` register.
+    pub struct CarveoutConfig: u32 {
+        /// The carveout is protected by TrustZone instead of the lock bit.
+        const TZ_SECURE = 1 << 0;
+        /// The carveout configuration is locked.
+        const LOCKED = 1 << 1;
+        /// Only untranslated accesses hit the carveout.
+        const UNTRANSLATED_ONLY = 1 << 2;
+        /// Clients with access level 0 may read.
+        const READ_ACCESS_LEVEL0 = 1 << 3;
+        /// Clients with access level 1 may read.
+        const READ_ACCESS_LEVEL1 = 1 << 4;
+        /// Clients with access level 2 may read.
+        const READ_ACCESS_LEVEL2 = 1 << 5;
+        /// Clients with access level 3 may read.
+        const READ_ACCESS_LEVEL3 = 1 << 6;
+        /// Clients with access level 0 may write.
+        const WRITE_ACCESS_LEVEL0 = 1 << 7;
+        /// Clients with access level 1 may write.
+        const WRITE_ACCESS_LEVEL1 = 1 << 8;
+        /// Clients with access level 2 may write.
+        const WRITE_ACCESS_LEVEL2 = 1 << 9;
+        /// Clients with access level 3 may write.
+        const WRITE_ACCESS_LEVEL3 = 1 << 10;
+        /// The mask of the aperture ID.
+        const APERTURE_ID = 0b111 << 11;
+        /// Reads with access level 0 are not checked.
+        const DISABLE_READ_CHECK_ACCESS_LEVEL0 = 1 << 14;
+        /// Reads with access level 1 are not checked.
+        const DISABLE_READ_CHECK_ACCESS_LEVEL1 = 1 << 15;
+        /// Reads with access level 2 are not checked.
+        const DISABLE_READ_CHECK_ACCESS_LEVEL2 = 1 << 16;
+        /// Reads with access level 3 are not checked.
+        const DISABLE_READ_CHECK_ACCESS_LEVEL3 = 1 << 17;
+        /// Writes with access level 0 are not checked.
+        const DISABLE_WRITE_CHECK_ACCESS_LEVEL0 = 1 << 18;
+        /// Writes with access level 1 are not checked.
+        const DISABLE_WRITE_CHECK_ACCESS_LEVEL1 = 1 << 19;
+        /// Writes with access level 2 are not checked.
+        const DISABLE_WRITE_CHECK_ACCESS_LEVEL2 = 1 << 20;
+        /// Writes with access level 3 are not checked.
+        const DISABLE_WRITE_CHECK_ACCESS_LEVEL3 = 1 << 21;
+        /// The configuration is forwarded to the GPU.
+        const SEND_CFG_TO_GPU = 1 << 22;
+        /// TrustZone may write to the carveout.
+        const TZ_GLOBAL_WR_EN = 1 << 23;
+        /// TrustZone may read from the carveout.
+        const TZ_GLOBAL_RD_EN = 1 << 24;
+        /// Accesses with a mismatching aperture ID are allowed.
+        const ALLOW_APERTURE_ID_MISMATCH = 1 << 25;
+        /// Accesses must match the aperture ID.
+        const FORCE_APERTURE_ID_MATCH = 1 << 26;
+        /// The carveout is a Write-Protected Region.
+        const IS_WPR = 1 << 27;
+
+        /// Clients of all access levels may read.
+        const READ_ACCESS_ALL = Self::READ_ACCESS_LEVEL0.bits
+            | Self::READ_ACCESS_LEVEL1.bits
+            | Self::READ_ACCESS_LEVEL2.bits
+            | Self::READ_ACCESS_LEVEL3.bits;
+    }
+}
+
+impl CarveoutConfig {
+    /// Sets the aperture ID of the carveout.
+    pub fn with_aperture_id(self, id: u32) -> Self {
+        (self - CarveoutConfig::APERTURE_ID)
+            | CarveoutConfig::from_bits_truncate((id << 11) & CarveoutConfig::APERTURE_ID.bits)
+    }
+
+    /// Gets the aperture ID of the carveout.
+    pub fn aperture_id(self) -> u32 {
+        (self & CarveoutConfig::APERTURE_ID).bits >> 11
+    }
+}
+
+/// Representation of the registers of a general security carveout.
+#[repr(C)]
+struct CarveoutRegisters {
+    cfg0: Mmio<u32>,
+    bom: Mmio<u32>,
+    bom_hi: Mmio<u32>,
+    size_128kb: Mmio<u32>,
+    client_access: [Mmio<u32>; CLIENT_ACCESS_REGISTERS],
+    client_force_internal_access: [Mmio<u32>; CLIENT_ACCESS_REGISTERS],
+}
+
+impl CarveoutRegisters {
+    /// Gets the registers of the general security carveout `index`, starting at 1.
+    fn get(index: usize) -> Result<&'static Self, Error> {
+        if index == 0 || index > CARVEOUT_COUNT {
+            return Err(Error::InvalidCarveout(index));
+        }
+
+        let address = MC_BASE + CARVEOUT_OFFSET + (index as u32 - 1) * CARVEOUT_STRIDE;
+
+        Ok(unsafe { &*(address as *const Self) })
+    }
+
+    /// Whether the carveout is locked.
+    fn is_locked(&self) -> bool {
+        CarveoutConfig::from_bits_truncate(self.cfg0.read()).contains(CarveoutConfig::LOCKED)
+    }
+}
+
+/// The configuration of a general security carveout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Carveout {
+    /// The physical base address of the carveout.
+    pub base: u64,
+    /// The size of the carveout in bytes.
+    pub size: u64,
+    /// The masks of clients that may access the carveout.
+    pub client_access: [u32; CLIENT_ACCESS_REGISTERS],
+    /// The masks of clients whose accesses are forced to be internal.
+    pub force_internal_access: [u32; CLIENT_ACCESS_REGISTERS],
+    /// The configuration flags of the carveout.
+    pub config: CarveoutConfig,
+}
+
+impl Carveout {
+    /// Creates a carveout that cannot be accessed by any client.
+    pub const fn new(base: u64, size: u64, config: CarveoutConfig) -> Self {
+        Carveout {
+            base,
+            size,
+            client_access: [0; CLIENT_ACCESS_REGISTERS],
+            force_internal_access: [0; CLIENT_ACCESS_REGISTERS],
+            config,
+        }
+    }
+
+    /// Reads the configuration of the general security carveout `index`, starting at 1.
+    pub fn read(index: usize) -> Result<Self, Error> {
+        let registers = CarveoutRegisters::get(index)?;
+
+        let mut carveout = Carveout::new(
+            u64::from(registers.bom.read()) | u64::from(registers.bom_hi.read()) << 32,
+            u64::from(registers.size_128kb.read()) * CARVEOUT_GRANULARITY,
+            CarveoutConfig::from_bits_truncate(registers.cfg0.read()),
+        );
+
+        for i in 0..CLIENT_ACCESS_REGISTERS {
+            carveout.client_access[i] = registers.client_access[i].read();
+            carveout.force_internal_access[i] = registers.client_force_internal_access[i].read();
+        }
+
+        Ok(carveout)
+    }
+
+    /// Programs the general security carveout `index`, starting at 1.
+    ///
+    /// The configuration is written last, so the carveout is only locked
+    /// after it has been fully programmed.
+    pub fn program(&self, index: usize) -> Result<(), Error> {
+        let registers = CarveoutRegisters::get(index)?;
+
+        if self.base % CARVEOUT_GRANULARITY != 0 || self.size % CARVEOUT_GRANULARITY != 0 {
+            return Err(Error::UnalignedCarveout);
+        }
+
+        if registers.is_locked() {
+            return Err(Error::CarveoutLocked(index));
+        }
+
+        registers.bom.write(self.base as u32);
+        registers.bom_hi.write((self.base >> 32) as u32);
+        registers
+            .size_128kb
+            .write((self.size / CARVEOUT_GRANULARITY) as u32);
+
+        for i in 0..CLIENT_ACCESS_REGISTERS {
+            registers.client_access[i].write(self.client_access[i]);
+            registers.client_force_internal_access[i].write(self.force_internal_access[i]);
+        }
+
+        registers.cfg0.write(self.config.bits());
+
+        Ok(())
+    }
+}
+
+/// Whether the general security carveout `index`, starting at 1, is locked.
+pub fn is_carveout_locked(index: usize) -> Result<bool, Error> {
+    Ok(CarveoutRegisters::get(index)?.is_locked())
+}
+
+/// Locks the general security carveout `index`, starting at 1.
+pub fn lock_carveout(index: usize) -> Result<(), Error> {
+    let registers = CarveoutRegisters::get(index)?;
+
+    registers
+        .cfg0
+        .write(registers.cfg0.read() | CarveoutConfig::LOCKED.bits());
+
+    Ok(())
+}
+
+/// The carveouts that consist of a base address and a size in MiB.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FixedCarveout {
+    /// The carveout of the TSEC.
+    Tsec,
+    /// The Video Protection Region.
+    Vpr,
+    /// The carveout of the MTS.
+    Mts,
+}
+
+impl FixedCarveout {
+    /// Gets the offsets of the `BOM`, `SIZE_MB`, `ADR_HI` and `REG_CTRL` registers.
+    fn offsets(self) -> (u32, u32, Option<u32>, u32) {
+        match self {
+            FixedCarveout::Tsec => (0x670, 0x674, None, 0x678),
+            FixedCarveout::Vpr => (0x648, 0x64C, Some(0x978), 0x650),
+            FixedCarveout::Mts => (0x9A0, 0x9A4, Some(0x9A8), 0x9AC),
+        }
+    }
+
+    /// Gets a register of the carveout.
+    fn register(offset: u32) -> &'static Mmio<u32> {
+        unsafe { &*((MC_BASE + offset) as *const Mmio<u32>) }
+    }
+
+    /// Programs the carveout and optionally locks it.
+    pub fn configure(self, base: u64, size_mb: u32, lock: bool) -> Result<(), Error> {
+        let (bom, size, adr_hi, _) = self.offsets();
+
+        if base % FIXED_CARVEOUT_GRANULARITY != 0 || (adr_hi.is_none() && base >> 32 != 0) {
+            return Err(Error::UnalignedCarveout);
+        }
+
+        if self.is_locked() {
+            return Err(Error::FixedCarveoutLocked(self));
+        }
+
+        Self::register(bom).write(base as u32);
+        if let Some(adr_hi) = adr_hi {
+            Self::register(adr_hi).write((base >> 32) as u32);
+        }
+        Self::register(size).write(size_mb);
+
+        if lock {
+            self.lock();
+        }
+
+        Ok(())
+    }
+
+    /// Whether the carveout is locked.
+    pub fn is_locked(self) -> bool {
+        Self::register(self.offsets().3).read() & 1 != 0
+    }
+
+    /// Locks the carveout.
+    pub fn lock(self) {
+        Self::register(self.offsets().3).write(1);
+    }
+}
+
+/// Configures whether the GPU may override the Video Protection Region.
+pub fn set_vpr_gpu_override(override0: u32, override1: u32) {
+    FixedCarveout::register(0x984).write(override0);
+    FixedCarveout::register(0x988).write(override1);
+}
@@ -0,0 +1,325 @@
+//! Memory Controller clients.
+//!
+//! # Description
+//!
+//! Every hardware unit that accesses memory through the MC does so as
+//! one or more memory clients, usually one for reads and one for writes.
+//! Each client has a fixed ID which also determines its position in the
+//! `CLIENT_ACCESS` registers of the security carveouts: client `id` is
+//! represented by bit `id % 32` of register `id / 32`.
+//!
+//! # Implementation
+//!
+//! - [`Client`] enumerates the Tegra210 memory clients by their IDs.
+//!
+//! - [`ClientMask`] builds the `CLIENT_ACCESS` words of a carveout from
+//! a list of clients.
+//!
+//! # Example
+//!
+//! ```
+//! use mirage_libtegra::mc::client::{Client, ClientMask};
+//!
+//! let access = ClientMask::new()
+//!     .allow(Client::GpuSrd)
+//!     .allow(Client::GpuSwr)
+//!     .build();
+//!
+//! assert_eq!(access, [0, 0, 0x300_0000, 0, 0]);
+//! ```
+//!
+//! [`Client`]: enum.Client.html
+//! [`ClientMask`]: struct.ClientMask.html
+
+use super::carveout::CLIENT_ACCESS_REGISTERS;
+
+enum_from_primitive! {
+    /// Enumeration of the Tegra210 memory clients.
+    ///
+    /// The discriminant of each variant is the ID of the client.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[repr(u32)]
+    pub enum Client {
+        /// The page table cache.
+        Ptcr = 0x00,
+        /// Display window A reads.
+        Display0A = 0x01,
+        /// Display window A reads of display controller B.
+        Display0AB = 0x02,
+        /// Display window B reads.
+        Display0B = 0x03,
+        /// Display window B reads of display controller B.
+        Display0BB = 0x04,
+        /// Display window C reads.
+        Display0C = 0x05,
+        /// Display window C reads of display controller B.
+        Display0CB = 0x06,
+        /// PCIe controller reads.
+        Afir = 0x0E,
+        /// BPMP (AVP) reads.
+        AvpCArm7R = 0x0F,
+        /// Display cursor reads.
+        DisplayHC = 0x10,
+        /// Display cursor reads of display controller B.
+        DisplayHCB = 0x11,
+        /// HDA reads.
+        Hdar = 0x15,
+        /// Host1x DMA reads.
+        Host1xDmar = 0x16,
+        /// Host1x reads.
+        Host1xr = 0x17,
+        /// NVENC reads.
+        NvEncSrd = 0x1C,
+        /// AHB DMA reads.
+        PpcsAhbDmar = 0x1D,
+        /// AHB slave reads.
+        PpcsAhbSlvr = 0x1E,
+        /// SATA reads.
+        Satar = 0x1F,
+        /// CPU reads.
+        MpCorer = 0x27,
+        /// NVENC writes.
+        NvEncSwr = 0x2B,
+        /// PCIe controller writes.
+        Afiw = 0x31,
+        /// BPMP (AVP) writes.
+        AvpCArm7W = 0x32,
+        /// HDA writes.
+        Hdaw = 0x35,
+        /// Host1x writes.
+        Host1xw = 0x36,
+        /// CPU writes.
+        MpCorew = 0x39,
+        /// AHB DMA writes.
+        PpcsAhbDmaw = 0x3B,
+        /// AHB slave writes.
+        PpcsAhbSlvw = 0x3C,
+        /// SATA writes.
+        Sataw = 0x3D,
+        /// ISP2 reads.
+        IspRa = 0x44,
+        /// ISP2 writes.
+        IspWa = 0x46,
+        /// ISP2 writes.
+        IspWb = 0x47,
+        /// XUSB host reads.
+        XusbHostr = 0x4A,
+        /// XUSB host writes.
+        XusbHostw = 0x4B,
+        /// XUSB device reads.
+        XusbDevr = 0x4C,
+        /// XUSB device writes.
+        XusbDevw = 0x4D,
+        /// ISP2B reads.
+        IspRab = 0x4E,
+        /// ISP2B writes.
+        IspWab = 0x50,
+        /// ISP2B writes.
+        IspWbb = 0x51,
+        /// TSEC reads.
+        TsecSrd = 0x54,
+        /// TSEC writes.
+        TsecSwr = 0x55,
+        /// BPMP (AVP) cache reads.
+        A9AvpScr = 0x56,
+        /// BPMP (AVP) cache writes.
+        A9AvpScw = 0x57,
+        /// GPU reads.
+        GpuSrd = 0x58,
+        /// GPU writes.
+        GpuSwr = 0x59,
+        /// Display window T reads.
+        DisplayT = 0x5A,
+        /// SDMMC1 reads.
+        SdmmcRa = 0x60,
+        /// SDMMC2 reads.
+        SdmmcRaa = 0x61,
+        /// SDMMC3 reads.
+        SdmmcR = 0x62,
+        /// SDMMC4 reads.
+        SdmmcRab = 0x63,
+        /// SDMMC1 writes.
+        SdmmcWa = 0x64,
+        /// SDMMC2 writes.
+        SdmmcWaa = 0x65,
+        /// SDMMC3 writes.
+        SdmmcW = 0x66,
+        /// SDMMC4 writes.
+        SdmmcWab = 0x67,
+        /// VIC reads.
+        VicSrd = 0x6C,
+        /// VIC writes.
+        VicSwr = 0x6D,
+        /// VI writes.
+        Viw = 0x72,
+        /// Display window D reads.
+        DisplayD = 0x73,
+        /// NVDEC reads.
+        NvDecSrd = 0x78,
+        /// NVDEC writes.
+        NvDecSwr = 0x79,
+        /// APE reads.
+        Aper = 0x7A,
+        /// APE writes.
+        Apew = 0x7B,
+        /// NVJPG reads.
+        NvJpgSrd = 0x7E,
+        /// NVJPG writes.
+        NvJpgSwr = 0x7F,
+        /// Security Engine reads.
+        SeSrd = 0x80,
+        /// Security Engine writes.
+        SeSwr = 0x81,
+        /// AXI AP reads.
+        AxiApr = 0x82,
+        /// AXI AP writes.
+        AxiApw = 0x83,
+        /// ETR reads.
+        Etrr = 0x84,
+        /// ETR writes.
+        Etrw = 0x85,
+        /// TSECB reads.
+        TsecSrdB = 0x86,
+        /// TSECB writes.
+        TsecSwrB = 0x87,
+        /// GPU reads through the second port.
+        GpuSrd2 = 0x88,
+        /// GPU writes through the second port.
+        GpuSwr2 = 0x89,
+    }
+}
+
+impl Client {
+    /// Gets the ID of the client.
+    pub const fn id(self) -> u32 {
+        self as u32
+    }
+
+    /// Gets the index of the `CLIENT_ACCESS` register that holds the client.
+    pub const fn register(self) -> usize {
+        (self.id() / 32) as usize
+    }
+
+    /// Gets the bit of the client in its `CLIENT_ACCESS` register.
+    pub const fn bit(self) -> u32 {
+        1 << (self.id() % 32)
+    }
+}
+
+/// Builder for the `CLIENT_ACCESS` words of a security carveout.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClientMask {
+    masks: [u32; CLIENT_ACCESS_REGISTERS],
+}
+
+impl ClientMask {
+    /// Creates a mask without any clients.
+    pub const fn new() -> Self {
+        ClientMask {
+            masks: [0; CLIENT_ACCESS_REGISTERS],
+        }
+    }
+
+    /// Creates a mask of all clients in `clients`.
+    pub fn from_clients(clients: &[Client]) -> Self {
+        clients
+            .iter()
+            .fold(Self::new(), |mask, &client| mask.allow(client))
+    }
+
+    /// Adds `client` to the mask.
+    pub fn allow(mut self, client: Client) -> Self {
+        self.masks[client.register()] |= client.bit();
+        self
+    }
+
+    /// Removes `client` from the mask.
+    pub fn deny(mut self, client: Client) -> Self {
+        self.masks[client.register()] &= !client.bit();
+        self
+    }
+
+    /// Whether `client` is part of the mask.
+    pub fn contains(&self, client: Client) -> bool {
+        self.masks[client.register()] & client.bit() != 0
+    }
+
+    /// Gets the `CLIENT_ACCESS` words of the mask.
+    pub fn build(self) -> [u32; CLIENT_ACCESS_REGISTERS] {
+        self.masks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests the register and bit of the clients at the word boundaries.
+    #[test]
+    fn client_positions() {
+        assert_eq!((Client::Ptcr.register(), Client::Ptcr.bit()), (0, 1 << 0));
+        assert_eq!(
+            (Client::Satar.register(), Client::Satar.bit()),
+            (0, 1 << 31)
+        );
+        assert_eq!(
+            (Client::MpCorer.register(), Client::MpCorer.bit()),
+            (1, 1 << 7)
+        );
+        assert_eq!((Client::SeSrd.register(), Client::SeSrd.bit()), (4, 1 << 0));
+        assert_eq!(
+            (Client::GpuSwr2.register(), Client::GpuSwr2.bit()),
+            (4, 1 << 9)
+        );
+    }
+
+    /// Tests building a mask from clients at the word boundaries.
+    #[test]
+    fn from_clients() {
+        let mask = ClientMask::from_clients(&[
+            Client::Satar,
+            Client::MpCorer,
+            Client::Sataw,
+            Client::IspRa,
+            Client::GpuSwr2,
+        ]);
+
+        assert_eq!(
+            mask.build(),
+            [1 << 31, (1 << 7) | (1 << 29), 1 << 4, 0, 1 << 9]
+        );
+
+        assert!(mask.contains(Client::Satar));
+        assert!(mask.contains(Client::MpCorer));
+        assert!(mask.contains(Client::GpuSwr2));
+        assert!(!mask.contains(Client::PpcsAhbSlvr));
+        assert!(!mask.contains(Client::GpuSrd2));
+        assert!(!mask.contains(Client::SeSrd));
+
+        assert_eq!(ClientMask::from_clients(&[]), ClientMask::new());
+    }
+
+    /// Tests that denying a client only clears its own bit.
+    #[test]
+    fn deny_clients() {
+        let mask = ClientMask::from_clients(&[
+            Client::PpcsAhbSlvr,
+            Client::Satar,
+            Client::MpCorer,
+            Client::GpuSrd2,
+            Client::GpuSwr2,
+        ])
+        .deny(Client::Satar)
+        .deny(Client::GpuSwr2);
+
+        assert!(!mask.contains(Client::Satar));
+        assert!(!mask.contains(Client::GpuSwr2));
+        assert!(mask.contains(Client::PpcsAhbSlvr));
+        assert!(mask.contains(Client::MpCorer));
+        assert!(mask.contains(Client::GpuSrd2));
+        assert_eq!(mask.build(), [1 << 30, 1 << 7, 0, 0, 1 << 8]);
+
+        // Denying a client that isn't part of the mask has no effect.
+        assert_eq!(mask.deny(Client::Ptcr), mask);
+    }
+}
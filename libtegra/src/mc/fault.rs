@@ -0,0 +1,326 @@
+//! Memory Controller fault reporting.
+//!
+//! # Description
+//!
+//! When a memory client performs an access that the MC rejects, e.g.
+//! because it hits a security carveout or an unmapped SMMU page, the
+//! MC raises an interrupt in `MC_INTSTATUS` and records the details of
+//! the access in `MC_ERR_STATUS` and `MC_ERR_ADR`. The offending access
+//! itself is silently dropped.
+//!
+//! Violations of the VPR, the TSEC and MTS carveouts and the generalized
+//! carveouts are recorded in their own status and address registers
+//! instead.
+//!
+//! # Implementation
+//!
+//! - [`take_fault`] reads and decodes the next pending fault into a
+//! [`Fault`] and clears its interrupts, so that the next fault can be
+//! recorded. The registers are chosen by the pending interrupt, the
+//! carveout registers take precedence over the generic ones. Faults that
+//! are still pending are reported by subsequent calls.
+//!
+//! - [`Fault`] implements `Display`, producing a single line which is
+//! suitable for logging.
+//!
+//! [`take_fault`]: fn.take_fault.html
+//! [`Fault`]: struct.Fault.html
+
+use core::fmt;
+
+use enum_primitive::FromPrimitive;
+
+use super::{client::Client, register};
+
+/// The offset of the `MC_INTSTATUS` register.
+const MC_INTSTATUS: u32 = 0x000;
+
+/// The offset of the `MC_ERR_STATUS` register.
+const MC_ERR_STATUS: u32 = 0x008;
+
+/// The offset of the `MC_ERR_ADR` register.
+const MC_ERR_ADR: u32 = 0x00C;
+
+/// The offset of the `MC_ERR_VPR_STATUS` register.
+const MC_ERR_VPR_STATUS: u32 = 0x654;
+
+/// The offset of the `MC_ERR_VPR_ADR` register.
+const MC_ERR_VPR_ADR: u32 = 0x658;
+
+/// The offset of the `MC_ERR_SEC_STATUS` register.
+const MC_ERR_SEC_STATUS: u32 = 0x67C;
+
+/// The offset of the `MC_ERR_SEC_ADR` register.
+const MC_ERR_SEC_ADR: u32 = 0x680;
+
+/// The offset of the `MC_ERR_MTS_STATUS` register.
+const MC_ERR_MTS_STATUS: u32 = 0x9B0;
+
+/// The offset of the `MC_ERR_MTS_ADR` register.
+const MC_ERR_MTS_ADR: u32 = 0x9B4;
+
+/// The offset of the `MC_ERR_GENERALIZED_CARVEOUT_STATUS` register.
+const MC_ERR_GENERALIZED_CARVEOUT_STATUS: u32 = 0xC00;
+
+/// The offset of the `MC_ERR_GENERALIZED_CARVEOUT_ADR` register.
+const MC_ERR_GENERALIZED_CARVEOUT_ADR: u32 = 0xC04;
+
+/// The mask of the client ID in `MC_ERR_STATUS`.
+const ERR_STATUS_CLIENT_MASK: u32 = 0xFF;
+
+/// Set in `MC_ERR_STATUS` if the faulting access was a write.
+const ERR_STATUS_WRITE: u32 = 1 << 16;
+
+/// Set in `MC_ERR_STATUS` if the faulting access was secure.
+const ERR_STATUS_SECURE: u32 = 1 << 17;
+
+/// The shift of the high address bits in `MC_ERR_STATUS`.
+const ERR_STATUS_ADR_HI_SHIFT: u32 = 20;
+
+/// The mask of the high address bits in `MC_ERR_STATUS`.
+const ERR_STATUS_ADR_HI_MASK: u32 = 0b11;
+
+/// The shift of the error type in `MC_ERR_STATUS`.
+const ERR_STATUS_TYPE_SHIFT: u32 = 28;
+
+/// The mask of the error type in `MC_ERR_STATUS`.
+const ERR_STATUS_TYPE_MASK: u32 = 0b111;
+
+bitflags! {
+    /// Representation of the `MC_INTSTATUS` register.
+    pub struct Interrupts: u32 {
+        /// An access did not decode to a valid memory address.
+        const DECERR_EMEM = 1 << 6;
+        /// An access violated the TrustZone security settings.
+        const SECURITY_VIOLATION = 1 << 8;
+        /// The arbitration of the external memory failed.
+        const ARBITRATION_EMEM = 1 << 9;
+        /// An access was translated through an invalid SMMU page.
+        const INVALID_SMMU_PAGE = 1 << 10;
+        /// An ASID was updated through the APB while in use.
+        const INVALID_APB_ASID_UPDATE = 1 << 11;
+        /// An access violated the Video Protection Region.
+        const DECERR_VPR = 1 << 12;
+        /// An access violated the TSEC carveout.
+        const SECERR_SEC = 1 << 13;
+        /// An access violated the MTS carveout.
+        const DECERR_MTS = 1 << 16;
+        /// An access violated a general security carveout.
+        const DECERR_GENERALIZED_CARVEOUT = 1 << 17;
+    }
+}
+
+impl Interrupts {
+    /// Selects the interrupts of the next fault to report, along with the
+    /// offsets of the status and address registers that record it.
+    ///
+    /// The carveout interrupts take precedence, since they have their own
+    /// registers. All other interrupts share `MC_ERR_STATUS` and `MC_ERR_ADR`
+    /// and are therefore reported together.
+    fn next_fault(self) -> (Interrupts, u32, u32) {
+        if self.contains(Interrupts::DECERR_GENERALIZED_CARVEOUT) {
+            (
+                Interrupts::DECERR_GENERALIZED_CARVEOUT,
+                MC_ERR_GENERALIZED_CARVEOUT_STATUS,
+                MC_ERR_GENERALIZED_CARVEOUT_ADR,
+            )
+        } else if self.contains(Interrupts::DECERR_VPR) {
+            (Interrupts::DECERR_VPR, MC_ERR_VPR_STATUS, MC_ERR_VPR_ADR)
+        } else if self.contains(Interrupts::SECERR_SEC) {
+            (Interrupts::SECERR_SEC, MC_ERR_SEC_STATUS, MC_ERR_SEC_ADR)
+        } else if self.contains(Interrupts::DECERR_MTS) {
+            (Interrupts::DECERR_MTS, MC_ERR_MTS_STATUS, MC_ERR_MTS_ADR)
+        } else {
+            (self, MC_ERR_STATUS, MC_ERR_ADR)
+        }
+    }
+}
+
+/// The type of a faulting access.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Access {
+    /// A read access.
+    Read,
+    /// A write access.
+    Write,
+}
+
+/// The reason why the MC rejected an access.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reason {
+    /// The address did not decode to valid memory.
+    DecodeError,
+    /// The access violated the TrustZone security settings.
+    SecurityViolation,
+    /// The access violated a security carveout.
+    CarveoutViolation,
+    /// The SMMU failed to translate the address.
+    SmmuPageFault,
+    /// The error type is not known.
+    Unknown(u32),
+}
+
+impl Reason {
+    /// Decodes the error type field of `MC_ERR_STATUS`.
+    fn from_type(value: u32) -> Self {
+        match value {
+            2 => Reason::DecodeError,
+            3 => Reason::SecurityViolation,
+            4 => Reason::CarveoutViolation,
+            6 => Reason::SmmuPageFault,
+            value => Reason::Unknown(value),
+        }
+    }
+}
+
+/// A memory access that was rejected by the MC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fault {
+    /// The pending interrupts.
+    pub interrupts: Interrupts,
+    /// The ID of the client that performed the access.
+    pub client_id: u32,
+    /// The type of the access.
+    pub access: Access,
+    /// Whether the access was secure.
+    pub secure: bool,
+    /// The reason why the access was rejected.
+    pub reason: Reason,
+    /// The physical address of the access.
+    pub address: u64,
+}
+
+impl Fault {
+    /// Decodes the raw values of `MC_INTSTATUS`, `MC_ERR_STATUS` and `MC_ERR_ADR`.
+    pub fn decode(intstatus: u32, err_status: u32, err_adr: u32) -> Self {
+        let address_hi = (err_status >> ERR_STATUS_ADR_HI_SHIFT) & ERR_STATUS_ADR_HI_MASK;
+
+        Fault {
+            interrupts: Interrupts::from_bits_truncate(intstatus),
+            client_id: err_status & ERR_STATUS_CLIENT_MASK,
+            access: if err_status & ERR_STATUS_WRITE != 0 {
+                Access::Write
+            } else {
+                Access::Read
+            },
+            secure: err_status & ERR_STATUS_SECURE != 0,
+            reason: Reason::from_type((err_status >> ERR_STATUS_TYPE_SHIFT) & ERR_STATUS_TYPE_MASK),
+            address: u64::from(address_hi) << 32 | u64::from(err_adr),
+        }
+    }
+
+    /// Gets the client that performed the access, if it is known.
+    pub fn client(&self) -> Option<Client> {
+        Client::from_u32(self.client_id)
+    }
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.client() {
+            Some(client) => write!(f, "{:?}", client)?,
+            None => write!(f, "client {:#x}", self.client_id)?,
+        }
+
+        write!(
+            f,
+            ": {}{:?} of {:#x} rejected, {:?} ({:?})",
+            if self.secure { "secure " } else { "" },
+            self.access,
+            self.address,
+            self.reason,
+            self.interrupts
+        )
+    }
+}
+
+/// Reads the next pending MC fault, if any, and clears its interrupts.
+///
+/// Other pending faults are left untouched and reported by the next call.
+pub fn take_fault() -> Option<Fault> {
+    let pending = Interrupts::from_bits_truncate(register(MC_INTSTATUS).read());
+    if pending.is_empty() {
+        return None;
+    }
+
+    let (interrupts, status, address) = pending.next_fault();
+    let fault = Fault::decode(
+        interrupts.bits(),
+        register(status).read(),
+        register(address).read(),
+    );
+
+    // The interrupt status bits are cleared by writing 1 to them.
+    register(MC_INTSTATUS).write(interrupts.bits());
+
+    Some(fault)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests the decoding of the error status fields.
+    #[test]
+    fn decode_fault() {
+        let fault = Fault::decode(1 << 6, 2 << 28 | 1 << 20 | 1 << 16 | 0x1A, 0x8000_1000);
+
+        assert_eq!(fault.interrupts, Interrupts::DECERR_EMEM);
+        assert_eq!(fault.client_id, 0x1A);
+        assert_eq!(fault.access, Access::Write);
+        assert!(!fault.secure);
+        assert_eq!(fault.reason, Reason::DecodeError);
+        assert_eq!(fault.address, 0x1_8000_1000);
+
+        let fault = Fault::decode(1 << 8 | 1 << 0, 3 << 28 | 1 << 17 | 0x10, 0x4000);
+
+        assert_eq!(fault.interrupts, Interrupts::SECURITY_VIOLATION);
+        assert_eq!(fault.client_id, 0x10);
+        assert_eq!(fault.access, Access::Read);
+        assert!(fault.secure);
+        assert_eq!(fault.reason, Reason::SecurityViolation);
+        assert_eq!(fault.address, 0x4000);
+
+        assert_eq!(
+            Fault::decode(0, 4 << 28, 0).reason,
+            Reason::CarveoutViolation
+        );
+        assert_eq!(Fault::decode(0, 6 << 28, 0).reason, Reason::SmmuPageFault);
+        assert_eq!(Fault::decode(0, 7 << 28, 0).reason, Reason::Unknown(7));
+    }
+
+    /// Tests that carveout violations are read from their own registers and
+    /// reported one at a time.
+    #[test]
+    fn next_fault() {
+        let generic = Interrupts::DECERR_EMEM | Interrupts::INVALID_SMMU_PAGE;
+        assert_eq!(generic.next_fault(), (generic, MC_ERR_STATUS, MC_ERR_ADR));
+
+        let carveouts = [
+            (Interrupts::DECERR_VPR, MC_ERR_VPR_STATUS, MC_ERR_VPR_ADR),
+            (Interrupts::SECERR_SEC, MC_ERR_SEC_STATUS, MC_ERR_SEC_ADR),
+            (Interrupts::DECERR_MTS, MC_ERR_MTS_STATUS, MC_ERR_MTS_ADR),
+            (
+                Interrupts::DECERR_GENERALIZED_CARVEOUT,
+                MC_ERR_GENERALIZED_CARVEOUT_STATUS,
+                MC_ERR_GENERALIZED_CARVEOUT_ADR,
+            ),
+        ];
+
+        for fault in carveouts.iter() {
+            assert_eq!(fault.0.next_fault(), *fault);
+            assert_eq!((fault.0 | generic).next_fault(), *fault);
+        }
+
+        // Draining the pending interrupts visits every fault.
+        let mut pending = Interrupts::all();
+        let mut faults = 0;
+        while !pending.is_empty() {
+            let (interrupts, _, _) = pending.next_fault();
+            assert!(pending.contains(interrupts));
+            pending.remove(interrupts);
+            faults += 1;
+        }
+        assert_eq!(faults, 5);
+    }
+}
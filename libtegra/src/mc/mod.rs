@@ -10,357 +10,151 @@
 //! bandwidth for DDR3L, LPDDR3, and LPDDR4 SDRAMs. The external
 //! memory controller (EMC) communicates with external DDR3L,
 //! LPDDR3, and LPDDR4 devices.
+//!
+//! # Implementation
+//!
+//! - The [`carveout`] module provides the configuration of security
+//! carveouts, which restrict the memory clients that may access
+//! physical memory regions.
+//!
+//! - The [`client`] module enumerates the memory clients and builds the
+//! client access masks of the carveouts.
+//!
+//! - The [`fault`] module decodes the memory accesses that were
+//! rejected by the MC.
+//!
+//! - The [`smmu`] module builds SMMU page tables and assigns memory
+//! clients to the SMMU address spaces.
+//!
+//! [`carveout`]: carveout/index.html
+//! [`client`]: client/index.html
+//! [`fault`]: fault/index.html
+//! [`smmu`]: smmu/index.html
 
 use mirage_mmio::{Mmio, VolatileStorage};
 
 use crate::{clock::Car, timer::usleep};
 
-/// Base address for the MC registers.
-pub(crate) const MC_BASE: u32 = 0x7001_9000;
-
-pub fn config_tsec_carveout(bom: u32, size_mb: u32, lock: bool) {
-    let sec_carveout_bom = unsafe { &*((MC_BASE + 0x670) as *const Mmio<u32>) };
+use self::{
+    carveout::{
+        set_vpr_gpu_override, Carveout, CarveoutConfig, FixedCarveout, CARVEOUT_GRANULARITY,
+    },
+    client::{Client, ClientMask},
+};
 
-    let sec_carveout_size_mb = unsafe { &*((MC_BASE + 0x674) as *const Mmio<u32>) };
+pub mod carveout;
+pub mod client;
+pub mod fault;
+pub mod smmu;
 
-    let sec_carveout_reg_ctrl = unsafe { &*((MC_BASE + 0x678) as *const Mmio<u32>) };
-
-    sec_carveout_bom.write(bom);
-    sec_carveout_size_mb.write(size_mb);
+/// Base address for the MC registers.
+pub(crate) const MC_BASE: u32 = 0x7001_9000;
 
-    if lock {
-        sec_carveout_reg_ctrl.write(1);
-    }
+/// Gets the MC register at `offset`.
+fn register(offset: u32) -> &'static Mmio<u32> {
+    unsafe { &*((MC_BASE + offset) as *const Mmio<u32>) }
 }
 
-pub fn config_carveout() {
-    let video_protect_gpu_override_0 =
-        unsafe { &*((MC_BASE + 0x984) as *const Mmio<u32>) };
-
-    let video_protect_gpu_override_1 =
-        unsafe { &*((MC_BASE + 0x988) as *const Mmio<u32>) };
-
-    let video_protect_bom =
-        unsafe { &*((MC_BASE + 0x648) as *const Mmio<u32>) };
-
-    let video_protect_size_mb =
-        unsafe { &*((MC_BASE + 0x64C) as *const Mmio<u32>) };
-
-    let video_protect_reg_ctrl =
-        unsafe { &*((MC_BASE + 0x650) as *const Mmio<u32>) };
-
-    let mts_carveout_bom =
-        unsafe { &*((MC_BASE + 0x9A0) as *const Mmio<u32>) };
-
-    let mts_carveout_size_mb =
-        unsafe { &*((MC_BASE + 0x9A4) as *const Mmio<u32>) };
-
-    let mts_carveout_adr_hi =
-        unsafe { &*((MC_BASE + 0x9A8) as *const Mmio<u32>) };
-
-    let mts_carveout_reg_ctrl =
-        unsafe { &*((MC_BASE + 0x9ac) as *const Mmio<u32>) };
-
-    let security_carveout1_bom =
-        unsafe { &*((MC_BASE + 0xC0C) as *const Mmio<u32>) };
-
-    let security_carveout1_bom_hi =
-        unsafe { &*((MC_BASE + 0xC10) as *const Mmio<u32>) };
-
-    let security_carveout1_size_128kb =
-        unsafe { &*((MC_BASE + 0xC14) as *const Mmio<u32>) };
-
-    let security_carveout1_client_access0 =
-        unsafe { &*((MC_BASE + 0xC18) as *const Mmio<u32>) };
-
-    let security_carveout1_client_access1 =
-        unsafe { &*((MC_BASE + 0xC1C) as *const Mmio<u32>) };
-
-    let security_carveout1_client_access2 =
-        unsafe { &*((MC_BASE + 0xC20) as *const Mmio<u32>) };
-
-    let security_carveout1_client_access3 =
-        unsafe { &*((MC_BASE + 0xC24) as *const Mmio<u32>) };
-
-    let security_carveout1_client_access4 =
-        unsafe { &*((MC_BASE + 0xC28) as *const Mmio<u32>) };
-
-    let security_carveout1_client_force_internal_access0 =
-        unsafe { &*((MC_BASE + 0xC2C) as *const Mmio<u32>) };
-
-    let security_carveout1_client_force_internal_access1 =
-        unsafe { &*((MC_BASE + 0xC30) as *const Mmio<u32>) };
-
-    let security_carveout1_client_force_internal_access2 =
-        unsafe { &*((MC_BASE + 0xC34) as *const Mmio<u32>) };
-
-    let security_carveout1_client_force_internal_access3 =
-        unsafe { &*((MC_BASE + 0xC38) as *const Mmio<u32>) };
-
-    let security_carveout1_client_force_internal_access4 =
-        unsafe { &*((MC_BASE + 0xC3C) as *const Mmio<u32>) };
-
-    let security_carveout1_cfg0 =
-        unsafe { &*((MC_BASE + 0xC08) as *const Mmio<u32>) };
-
-    let security_carveout3_bom =
-        unsafe { &*((MC_BASE + 0xCAC) as *const Mmio<u32>) };
-
-    let security_carveout3_bom_hi =
-        unsafe { &*((MC_BASE + 0xCB0) as *const Mmio<u32>) };
-
-    let security_carveout3_size_128kb =
-        unsafe { &*((MC_BASE + 0xCB4) as *const Mmio<u32>) };
-
-    let security_carveout3_client_access0 =
-        unsafe { &*((MC_BASE + 0xCB8) as *const Mmio<u32>) };
-
-    let security_carveout3_client_access1 =
-        unsafe { &*((MC_BASE + 0xCBC) as *const Mmio<u32>) };
-
-    let security_carveout3_client_access2 =
-        unsafe { &*((MC_BASE + 0xCC0) as *const Mmio<u32>) };
-
-    let security_carveout3_client_access3 =
-        unsafe { &*((MC_BASE + 0xCC4) as *const Mmio<u32>) };
-
-    let security_carveout3_client_access4 =
-        unsafe { &*((MC_BASE + 0xCC8) as *const Mmio<u32>) };
-
-    let security_carveout3_client_force_internal_access0 =
-        unsafe { &*((MC_BASE + 0xCCC) as *const Mmio<u32>) };
-
-    let security_carveout3_client_force_internal_access1 =
-        unsafe { &*((MC_BASE + 0xCD0) as *const Mmio<u32>) };
-
-    let security_carveout3_client_force_internal_access2 =
-        unsafe { &*((MC_BASE + 0xCD4) as *const Mmio<u32>) };
-
-    let security_carveout3_client_force_internal_access3 =
-        unsafe { &*((MC_BASE + 0xCD8) as *const Mmio<u32>) };
-
-    let security_carveout3_client_force_internal_access4 =
-        unsafe { &*((MC_BASE + 0xCDC) as *const Mmio<u32>) };
-
-    let security_carveout3_cfg0 =
-        unsafe { &*((MC_BASE + 0xCA8) as *const Mmio<u32>) };
-
-    let security_carveout4_bom = unsafe { &*((MC_BASE + 0xCFC) as *const Mmio<u32>) };
-
-    let security_carveout4_bom_hi =
-        unsafe { &*((MC_BASE + 0xD00) as *const Mmio<u32>) };
-
-    let security_carveout4_size_128kb =
-        unsafe { &*((MC_BASE + 0xD04) as *const Mmio<u32>) };
-
-    let security_carveout4_client_access0 =
-        unsafe { &*((MC_BASE + 0xD08) as *const Mmio<u32>) };
-
-    let security_carveout4_client_access1 =
-        unsafe { &*((MC_BASE + 0xD0C) as *const Mmio<u32>) };
-
-    let security_carveout4_client_access2 =
-        unsafe { &*((MC_BASE + 0xD10) as *const Mmio<u32>) };
-
-    let security_carveout4_client_access3 =
-        unsafe { &*((MC_BASE + 0xD14) as *const Mmio<u32>) };
-
-    let security_carveout4_client_access4 =
-        unsafe { &*((MC_BASE + 0xD18) as *const Mmio<u32>) };
-
-    let security_carveout4_client_force_internal_access0 =
-        unsafe { &*((MC_BASE + 0xD1C) as *const Mmio<u32>) };
-
-    let security_carveout4_client_force_internal_access1 =
-        unsafe { &*((MC_BASE + 0xD20) as *const Mmio<u32>) };
-
-    let security_carveout4_client_force_internal_access2 =
-        unsafe { &*((MC_BASE + 0xD24) as *const Mmio<u32>) };
-
-    let security_carveout4_client_force_internal_access3 =
-        unsafe { &*((MC_BASE + 0xD28) as *const Mmio<u32>) };
-
-    let security_carveout4_client_force_internal_access4 =
-        unsafe { &*((MC_BASE + 0xD2C) as *const Mmio<u32>) };
-
-    let security_carveout4_cfg0 =
-        unsafe { &*((MC_BASE + 0xCF8) as *const Mmio<u32>) };
-
-    let security_carveout5_bom = unsafe { &*((MC_BASE + 0xD4C) as *const Mmio<u32>) };
-
-    let security_carveout5_bom_hi =
-        unsafe { &*((MC_BASE + 0xD50) as *const Mmio<u32>) };
-
-    let security_carveout5_size_128kb =
-        unsafe { &*((MC_BASE + 0xD54) as *const Mmio<u32>) };
-
-    let security_carveout5_client_access0 =
-        unsafe { &*((MC_BASE + 0xD58) as *const Mmio<u32>) };
-
-    let security_carveout5_client_access1 =
-        unsafe { &*((MC_BASE + 0xD5C) as *const Mmio<u32>) };
-
-    let security_carveout5_client_access2 =
-        unsafe { &*((MC_BASE + 0xD60) as *const Mmio<u32>) };
-
-    let security_carveout5_client_access3 =
-        unsafe { &*((MC_BASE + 0xD64) as *const Mmio<u32>) };
-
-    let security_carveout5_client_access4 =
-        unsafe { &*((MC_BASE + 0xD68) as *const Mmio<u32>) };
-
-    let security_carveout5_client_force_internal_access0 =
-        unsafe { &*((MC_BASE + 0xD6C) as *const Mmio<u32>) };
-
-    let security_carveout5_client_force_internal_access1 =
-        unsafe { &*((MC_BASE + 0xD70) as *const Mmio<u32>) };
-
-    let security_carveout5_client_force_internal_access2 =
-        unsafe { &*((MC_BASE + 0xD74) as *const Mmio<u32>) };
-
-    let security_carveout5_client_force_internal_access3 =
-        unsafe { &*((MC_BASE + 0xD78) as *const Mmio<u32>) };
-
-    let security_carveout5_client_force_internal_access4 =
-        unsafe { &*((MC_BASE + 0xD7C) as *const Mmio<u32>) };
+/// The configuration of the carveouts that are accessible by the GPU.
+const GPU_CARVEOUT_CONFIG: CarveoutConfig = CarveoutConfig::from_bits_truncate(
+    CarveoutConfig::LOCKED.bits()
+        | CarveoutConfig::UNTRANSLATED_ONLY.bits()
+        | CarveoutConfig::READ_ACCESS_ALL.bits()
+        | CarveoutConfig::WRITE_ACCESS_LEVEL2.bits()
+        | CarveoutConfig::WRITE_ACCESS_LEVEL3.bits()
+        | CarveoutConfig::SEND_CFG_TO_GPU.bits()
+        | CarveoutConfig::FORCE_APERTURE_ID_MATCH.bits(),
+);
+
+/// Gets the client access masks of the carveouts that are accessible by the GPU.
+fn gpu_clients() -> [u32; carveout::CLIENT_ACCESS_REGISTERS] {
+    ClientMask::from_clients(&[
+        Client::GpuSrd,
+        Client::GpuSwr,
+        Client::GpuSrd2,
+        Client::GpuSwr2,
+    ])
+    .build()
+}
 
-    let security_carveout5_cfg0 =
-        unsafe { &*((MC_BASE + 0xD48) as *const Mmio<u32>) };
+/// Enumeration of possible MC errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The general security carveout does not exist.
+    InvalidCarveout(usize),
+    /// The carveout base or size is not aligned to its granularity.
+    UnalignedCarveout,
+    /// The general security carveout is locked.
+    CarveoutLocked(usize),
+    /// The fixed carveout is locked.
+    FixedCarveoutLocked(FixedCarveout),
+    /// The SMMU address space does not exist.
+    InvalidAsid(u8),
+    /// The SMMU mapping is not page-aligned or out of range.
+    UnalignedMapping,
+    /// The SMMU page is already mapped.
+    AlreadyMapped(u32),
+    /// There are no page tables left to map an SMMU page.
+    OutOfPageTables,
+}
 
+/// Configures the default carveouts during early boot.
+///
+/// All carveouts are cleared and locked, except for carveout 2, which is
+/// configured by [`config_carveout_finalize`].
+///
+/// [`config_carveout_finalize`]: fn.config_carveout_finalize.html
+pub fn config_carveout() -> Result<(), Error> {
     unsafe {
         (*(0x8005_FFFC as *const Mmio<u32>)).write(0xC0ED_BBCC);
     }
 
-    video_protect_gpu_override_0.write(1);
-    video_protect_gpu_override_1.write(0);
-    video_protect_bom.write(0);
-    video_protect_size_mb.write(0);
-    video_protect_reg_ctrl.write(1);
-
-    config_tsec_carveout(0, 0, true);
-
-    mts_carveout_bom.write(0);
-    mts_carveout_size_mb.write(0);
-    mts_carveout_adr_hi.write(0);
-    mts_carveout_reg_ctrl.write(1);
-
-    security_carveout1_bom.write(0);
-    security_carveout1_bom_hi.write(0);
-    security_carveout1_size_128kb.write(0);
-    security_carveout1_client_access0.write(0);
-    security_carveout1_client_access1.write(0);
-    security_carveout1_client_access2.write(0);
-    security_carveout1_client_access3.write(0);
-    security_carveout1_client_access4.write(0);
-    security_carveout1_client_force_internal_access0.write(0);
-    security_carveout1_client_force_internal_access1.write(0);
-    security_carveout1_client_force_internal_access2.write(0);
-    security_carveout1_client_force_internal_access3.write(0);
-    security_carveout1_client_force_internal_access4.write(0);
-    security_carveout1_cfg0.write(0x4000006);
-
-    security_carveout3_bom.write(0);
-    security_carveout3_bom_hi.write(0);
-    security_carveout3_size_128kb.write(0);
-    security_carveout3_client_access0.write(0);
-    security_carveout3_client_access1.write(0);
-    security_carveout3_client_access2.write(0x3000000);
-    security_carveout3_client_access3.write(0);
-    security_carveout3_client_access4.write(0x300);
-    security_carveout3_client_force_internal_access0.write(0);
-    security_carveout3_client_force_internal_access1.write(0);
-    security_carveout3_client_force_internal_access2.write(0);
-    security_carveout3_client_force_internal_access3.write(0);
-    security_carveout3_client_force_internal_access4.write(0);
-    security_carveout3_cfg0.write(0x4401E7E);
-
-    security_carveout4_bom.write(0);
-    security_carveout4_bom_hi.write(0);
-    security_carveout4_size_128kb.write(0);
-    security_carveout4_client_access0.write(0);
-    security_carveout4_client_access1.write(0);
-    security_carveout4_client_access2.write(0);
-    security_carveout4_client_access3.write(0);
-    security_carveout4_client_access4.write(0);
-    security_carveout4_client_force_internal_access0.write(0);
-    security_carveout4_client_force_internal_access1.write(0);
-    security_carveout4_client_force_internal_access2.write(0);
-    security_carveout4_client_force_internal_access3.write(0);
-    security_carveout4_client_force_internal_access4.write(0);
-    security_carveout4_cfg0.write(0x8F);
-
-    security_carveout5_bom.write(0);
-    security_carveout5_bom_hi.write(0);
-    security_carveout5_size_128kb.write(0);
-    security_carveout5_client_access0.write(0);
-    security_carveout5_client_access1.write(0);
-    security_carveout5_client_access2.write(0);
-    security_carveout5_client_access3.write(0);
-    security_carveout5_client_access4.write(0);
-    security_carveout5_client_force_internal_access0.write(0);
-    security_carveout5_client_force_internal_access1.write(0);
-    security_carveout5_client_force_internal_access2.write(0);
-    security_carveout5_client_force_internal_access3.write(0);
-    security_carveout5_client_force_internal_access4.write(0);
-    security_carveout5_cfg0.write(0x8F);
+    set_vpr_gpu_override(1, 0);
+    FixedCarveout::Vpr.configure(0, 0, true)?;
+    FixedCarveout::Tsec.configure(0, 0, true)?;
+    FixedCarveout::Mts.configure(0, 0, true)?;
+
+    // Carveout 1 is unused.
+    Carveout::new(
+        0,
+        0,
+        CarveoutConfig::LOCKED
+            | CarveoutConfig::UNTRANSLATED_ONLY
+            | CarveoutConfig::FORCE_APERTURE_ID_MATCH,
+    )
+    .program(1)?;
+
+    // Carveout 3 is reserved for the GPU.
+    let mut gpu_carveout = Carveout::new(0, 0, GPU_CARVEOUT_CONFIG.with_aperture_id(3));
+    gpu_carveout.client_access = gpu_clients();
+    gpu_carveout.program(3)?;
+
+    // Carveouts 4 and 5 are reserved for TrustZone.
+    let tz_carveout = Carveout::new(
+        0,
+        0,
+        CarveoutConfig::TZ_SECURE
+            | CarveoutConfig::LOCKED
+            | CarveoutConfig::UNTRANSLATED_ONLY
+            | CarveoutConfig::READ_ACCESS_LEVEL0
+            | CarveoutConfig::WRITE_ACCESS_LEVEL0,
+    );
+    tz_carveout.program(4)?;
+    tz_carveout.program(5)?;
+
+    Ok(())
 }
 
-pub fn config_carveout_finalize() {
-    let security_carveout2_bom = unsafe { &*((MC_BASE + 0xC5C) as *const Mmio<u32>) };
-
-    let security_carveout2_bom_hi =
-        unsafe { &*((MC_BASE + 0xC60) as *const Mmio<u32>) };
-
-    let security_carveout2_size_128kb =
-        unsafe { &*((MC_BASE + 0xC64) as *const Mmio<u32>) };
-
-    let security_carveout2_client_access0 =
-        unsafe { &*((MC_BASE + 0xC68) as *const Mmio<u32>) };
-
-    let security_carveout2_client_access1 =
-        unsafe { &*((MC_BASE + 0xC6C) as *const Mmio<u32>) };
-
-    let security_carveout2_client_access2 =
-        unsafe { &*((MC_BASE + 0xC70) as *const Mmio<u32>) };
-
-    let security_carveout2_client_access3 =
-        unsafe { &*((MC_BASE + 0xC74) as *const Mmio<u32>) };
-
-    let security_carveout2_client_access4 =
-        unsafe { &*((MC_BASE + 0xC78) as *const Mmio<u32>) };
-
-    let security_carveout2_client_force_internal_access0 =
-        unsafe { &*((MC_BASE + 0xC7C) as *const Mmio<u32>) };
-
-    let security_carveout2_client_force_internal_access1 =
-        unsafe { &*((MC_BASE + 0xC80) as *const Mmio<u32>) };
-
-    let security_carveout2_client_force_internal_access2 =
-        unsafe { &*((MC_BASE + 0xC84) as *const Mmio<u32>) };
-
-    let security_carveout2_client_force_internal_access3 =
-        unsafe { &*((MC_BASE + 0xC88) as *const Mmio<u32>) };
-
-    let security_carveout2_client_force_internal_access4 =
-        unsafe { &*((MC_BASE + 0xC8C) as *const Mmio<u32>) };
-
-    let security_carveout2_cfg0 =
-        unsafe { &*((MC_BASE + 0xC58) as *const Mmio<u32>) };
+/// Configures carveout 2, which protects the GPU microcode.
+pub fn config_carveout_finalize() -> Result<(), Error> {
+    let mut carveout = Carveout::new(
+        0x8002_0000,
+        2 * CARVEOUT_GRANULARITY,
+        GPU_CARVEOUT_CONFIG.with_aperture_id(2),
+    );
+    carveout.client_access = gpu_clients();
 
-    security_carveout2_bom.write(0x8002_0000);
-    security_carveout2_bom_hi.write(0);
-    security_carveout2_size_128kb.write(2);
-    security_carveout2_client_access0.write(0);
-    security_carveout2_client_access1.write(0);
-    security_carveout2_client_access2.write(0x3000000);
-    security_carveout2_client_access3.write(0);
-    security_carveout2_client_access4.write(0x300);
-    security_carveout2_client_force_internal_access0.write(0);
-    security_carveout2_client_force_internal_access1.write(0);
-    security_carveout2_client_force_internal_access2.write(0);
-    security_carveout2_client_force_internal_access3.write(0);
-    security_carveout2_client_force_internal_access4.write(0);
-    security_carveout2_cfg0.write(0x440167E);
+    carveout.program(2)
 }
 
 pub fn enable_ahb_redirect() {
@@ -0,0 +1,793 @@
+//! Tegra210 System MMU.
+//!
+//! # Description
+//!
+//! The SMMU is part of the MC and translates the 32-bit I/O virtual
+//! addresses (IOVAs) of memory clients into physical addresses. Each
+//! translation context is identified by an address space ID (ASID),
+//! which points to a two-level page table.
+//!
+//! The page directory and each page table consist of 1024 32-bit
+//! entries and occupy one 4KiB page. A directory entry covers 4MiB of
+//! the address space, a table entry maps a single 4KiB page.
+//!
+//! Memory clients are assigned to an ASID per software group (swgroup),
+//! e.g. all SDMMC1 clients share the `SDMMC1A` swgroup. Translation then
+//! has to be enabled for each client of the swgroup individually.
+//!
+//! # Implementation
+//!
+//! - [`PageTables`] builds the page tables of an address space from
+//! memory that is provided by the caller. It does not access any
+//! hardware and can be used on the host.
+//!
+//! - [`SwGroup`] enumerates the swgroups that can be assigned to an
+//! ASID through [`assign`].
+//!
+//! - [`set_address_space`] points an ASID to a page directory and
+//! [`enable`] turns on translation.
+//!
+//! - The TLB and the page table cache (PTC) are not coherent with the
+//! page tables, so [`flush_address_space`] must be called after the
+//! page tables have been modified.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mirage_libtegra::mc::smmu::{self, Attributes, PageTable, PageTables, SwGroup};
+//!
+//! let mut directory = PageTable::new();
+//! let mut tables = [PageTable::new(), PageTable::new()];
+//!
+//! let mut page_tables = PageTables::new(&mut directory, &mut tables);
+//! page_tables
+//!     .map(0x8000_0000, 0x9000_0000, 0x10_0000, Attributes::READ_WRITE)
+//!     .unwrap();
+//!
+//! smmu::set_address_space(1, page_tables.directory_address(), Attributes::READ_WRITE).unwrap();
+//! smmu::assign(SwGroup::Sdmmc1A, 1).unwrap();
+//! smmu::flush_address_space(1).unwrap();
+//! smmu::enable();
+//! ```
+//!
+//! [`PageTables`]: struct.PageTables.html
+//! [`SwGroup`]: enum.SwGroup.html
+//! [`assign`]: fn.assign.html
+//! [`set_address_space`]: fn.set_address_space.html
+//! [`enable`]: fn.enable.html
+//! [`flush_address_space`]: fn.flush_address_space.html
+
+use super::{
+    carveout::CLIENT_ACCESS_REGISTERS,
+    client::{Client, ClientMask},
+    register, Error,
+};
+
+/// The size of an SMMU page.
+pub const SMMU_PAGE_SIZE: u32 = 0x1000;
+
+/// The size of the address space that is covered by a directory entry.
+pub const SMMU_SECTION_SIZE: u32 = 0x40_0000;
+
+/// The number of entries in a page directory or page table.
+pub const ENTRIES_PER_TABLE: usize = 1024;
+
+/// The number of address spaces.
+pub const ASID_COUNT: u8 = 128;
+
+/// The number of bits of a physical address.
+const PHYSICAL_ADDRESS_BITS: u32 = 34;
+
+/// The mask of the page frame number in an entry.
+const PFN_MASK: u32 = 0x3F_FFFF;
+
+/// Marks a directory entry that points to a page table.
+const PDE_NEXT: u32 = 1 << 28;
+
+/// The offset of the `MC_SMMU_CONFIG` register.
+const MC_SMMU_CONFIG: u32 = 0x010;
+
+/// The offset of the `MC_SMMU_TLB_CONFIG` register.
+const MC_SMMU_TLB_CONFIG: u32 = 0x014;
+
+/// The offset of the `MC_SMMU_PTC_CONFIG` register.
+const MC_SMMU_PTC_CONFIG: u32 = 0x018;
+
+/// The offset of the `MC_SMMU_PTB_ASID` register.
+const MC_SMMU_PTB_ASID: u32 = 0x01C;
+
+/// The offset of the `MC_SMMU_PTB_DATA` register.
+const MC_SMMU_PTB_DATA: u32 = 0x020;
+
+/// The offset of the `MC_SMMU_TLB_FLUSH` register.
+const MC_SMMU_TLB_FLUSH: u32 = 0x030;
+
+/// The offset of the `MC_SMMU_PTC_FLUSH` register.
+const MC_SMMU_PTC_FLUSH: u32 = 0x034;
+
+/// The offset of the `MC_SMMU_PTC_FLUSH_1` register.
+const MC_SMMU_PTC_FLUSH_1: u32 = 0x9B8;
+
+/// The offsets of the `MC_SMMU_TRANSLATION_ENABLE_x` registers.
+const MC_SMMU_TRANSLATION_ENABLE: [u32; CLIENT_ACCESS_REGISTERS] =
+    [0x228, 0x22C, 0x230, 0x234, 0xB98];
+
+/// Enables translation in `MC_SMMU_CONFIG`.
+const SMMU_CONFIG_ENABLE: u32 = 1 << 0;
+
+/// The `MC_SMMU_TLB_CONFIG` value: hit under miss, round-robin
+/// arbitration and all 48 TLB lines active.
+const SMMU_TLB_CONFIG: u32 = (1 << 29) | (1 << 28) | 0x30;
+
+/// The `MC_SMMU_PTC_CONFIG` value: enabled, a request limit of 8
+/// and the full index map.
+const SMMU_PTC_CONFIG: u32 = (1 << 29) | (8 << 24) | 0x3F;
+
+/// Flushes only the entries of one ASID in `MC_SMMU_TLB_FLUSH`.
+const SMMU_TLB_FLUSH_ASID_MATCH: u32 = 1 << 31;
+
+/// The shift of the ASID in `MC_SMMU_TLB_FLUSH`.
+const SMMU_TLB_FLUSH_ASID_SHIFT: u32 = 24;
+
+/// Flushes only the entries of one section in `MC_SMMU_TLB_FLUSH`.
+const SMMU_TLB_FLUSH_VA_MATCH_SECTION: u32 = 2;
+
+/// Flushes a single address in `MC_SMMU_PTC_FLUSH`.
+const SMMU_PTC_FLUSH_TYPE_ADR: u32 = 1 << 0;
+
+/// The mask of the address in `MC_SMMU_PTC_FLUSH`.
+const SMMU_PTC_FLUSH_ADR_MASK: u32 = 0xFFFF_FFF0;
+
+/// Enables the ASID of a swgroup.
+const SMMU_ASID_ENABLE: u32 = 1 << 31;
+
+bitflags! {
+    /// The attributes of a page, directory entry or address space.
+    pub struct Attributes: u32 {
+        /// The memory may be read.
+        const READABLE = 1 << 31;
+        /// The memory may be written.
+        const WRITABLE = 1 << 30;
+        /// The memory may be accessed by non-secure clients.
+        const NONSECURE = 1 << 29;
+
+        /// Non-secure read and write access.
+        const READ_WRITE = Self::READABLE.bits | Self::WRITABLE.bits | Self::NONSECURE.bits;
+    }
+}
+
+/// A page directory or page table.
+#[repr(C, align(4096))]
+pub struct PageTable {
+    entries: [u32; ENTRIES_PER_TABLE],
+}
+
+impl PageTable {
+    /// Creates an empty table.
+    pub const fn new() -> Self {
+        PageTable {
+            entries: [0; ENTRIES_PER_TABLE],
+        }
+    }
+
+    /// Gets the raw entries of the table.
+    pub fn entries(&self) -> &[u32] {
+        &self.entries
+    }
+}
+
+/// Gets the index of the directory entry of `iova`.
+fn directory_index(iova: u32) -> usize {
+    (iova / SMMU_SECTION_SIZE) as usize
+}
+
+/// Gets the index of the table entry of `iova`.
+fn table_index(iova: u32) -> usize {
+    (iova / SMMU_PAGE_SIZE) as usize % ENTRIES_PER_TABLE
+}
+
+/// Creates a directory or table entry pointing to `address`.
+fn make_entry(address: u64, flags: u32) -> u32 {
+    (address >> 12) as u32 & PFN_MASK | flags
+}
+
+/// Gets the address an entry points to.
+fn entry_address(entry: u32) -> u64 {
+    u64::from(entry & PFN_MASK) << 12
+}
+
+/// The page tables of an SMMU address space.
+///
+/// The page directory and a pool of page tables are provided by the
+/// caller. Page tables are taken from the pool as needed and are never
+/// released, even if all of their pages are unmapped.
+pub struct PageTables<'a> {
+    /// The page directory.
+    directory: &'a mut PageTable,
+    /// The physical address of the page directory.
+    directory_address: u64,
+    /// The pool of page tables.
+    tables: &'a mut [PageTable],
+    /// The physical address of the first page table in the pool.
+    tables_address: u64,
+    /// The number of page tables that are in use.
+    allocated: usize,
+}
+
+impl<'a> PageTables<'a> {
+    /// Creates empty page tables in memory that is identity-mapped.
+    pub fn new(directory: &'a mut PageTable, tables: &'a mut [PageTable]) -> Self {
+        let directory_address = directory as *mut PageTable as usize as u64;
+        let tables_address = tables.as_mut_ptr() as usize as u64;
+
+        Self::with_addresses(directory, directory_address, tables, tables_address)
+    }
+
+    /// Creates empty page tables, given the physical addresses of the
+    /// page directory and the first page table in the pool.
+    pub fn with_addresses(
+        directory: &'a mut PageTable,
+        directory_address: u64,
+        tables: &'a mut [PageTable],
+        tables_address: u64,
+    ) -> Self {
+        *directory = PageTable::new();
+
+        PageTables {
+            directory,
+            directory_address,
+            tables,
+            tables_address,
+            allocated: 0,
+        }
+    }
+
+    /// Gets the physical address of the page directory.
+    pub fn directory_address(&self) -> u64 {
+        self.directory_address
+    }
+
+    /// Gets the page directory.
+    pub fn directory(&self) -> &PageTable {
+        self.directory
+    }
+
+    /// Gets the number of page tables that are in use.
+    pub fn allocated(&self) -> usize {
+        self.allocated
+    }
+
+    /// Gets the index into the pool of the page table `entry` points to.
+    fn pool_index(&self, entry: u32) -> usize {
+        ((entry_address(entry) - self.tables_address) / u64::from(SMMU_PAGE_SIZE)) as usize
+    }
+
+    /// Gets the page table that covers `iova`, if there is one.
+    fn table(&self, iova: u32) -> Option<&PageTable> {
+        let entry = self.directory.entries[directory_index(iova)];
+
+        if entry & PDE_NEXT == 0 {
+            return None;
+        }
+
+        Some(&self.tables[self.pool_index(entry)])
+    }
+
+    /// Gets the page table that covers `iova`, allocating it if needed.
+    fn table_mut(&mut self, iova: u32) -> Result<&mut PageTable, Error> {
+        let index = directory_index(iova);
+        let entry = self.directory.entries[index];
+
+        if entry & PDE_NEXT != 0 {
+            let pool_index = self.pool_index(entry);
+            return Ok(&mut self.tables[pool_index]);
+        }
+
+        if self.allocated == self.tables.len() {
+            return Err(Error::OutOfPageTables);
+        }
+
+        let pool_index = self.allocated;
+        let address = self.tables_address + pool_index as u64 * u64::from(SMMU_PAGE_SIZE);
+
+        self.allocated += 1;
+        self.tables[pool_index] = PageTable::new();
+        self.directory.entries[index] =
+            make_entry(address, PDE_NEXT | Attributes::READ_WRITE.bits());
+
+        Ok(&mut self.tables[pool_index])
+    }
+
+    /// Maps `size` bytes at `iova` to the physical memory at `address`.
+    ///
+    /// If an error occurs, the pages that were mapped before it are
+    /// left in place.
+    pub fn map(
+        &mut self,
+        iova: u32,
+        address: u64,
+        size: u32,
+        attributes: Attributes,
+    ) -> Result<(), Error> {
+        if iova % SMMU_PAGE_SIZE != 0
+            || address % u64::from(SMMU_PAGE_SIZE) != 0
+            || size % SMMU_PAGE_SIZE != 0
+            || u64::from(iova) + u64::from(size) > 1 << 32
+            || address + u64::from(size) > 1 << PHYSICAL_ADDRESS_BITS
+        {
+            return Err(Error::UnalignedMapping);
+        }
+
+        for offset in (0..size).step_by(SMMU_PAGE_SIZE as usize) {
+            let page = iova + offset;
+            let entry = &mut self.table_mut(page)?.entries[table_index(page)];
+
+            if *entry != 0 {
+                return Err(Error::AlreadyMapped(page));
+            }
+
+            *entry = make_entry(address + u64::from(offset), attributes.bits());
+        }
+
+        Ok(())
+    }
+
+    /// Unmaps `size` bytes at `iova`.
+    pub fn unmap(&mut self, iova: u32, size: u32) -> Result<(), Error> {
+        if iova % SMMU_PAGE_SIZE != 0
+            || size % SMMU_PAGE_SIZE != 0
+            || u64::from(iova) + u64::from(size) > 1 << 32
+        {
+            return Err(Error::UnalignedMapping);
+        }
+
+        for offset in (0..size).step_by(SMMU_PAGE_SIZE as usize) {
+            let page = iova + offset;
+            let entry = self.directory.entries[directory_index(page)];
+
+            if entry & PDE_NEXT != 0 {
+                let pool_index = self.pool_index(entry);
+                self.tables[pool_index].entries[table_index(page)] = 0;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Translates `iova` to a physical address, if it is mapped.
+    pub fn translate(&self, iova: u32) -> Option<u64> {
+        let entry = self.table(iova)?.entries[table_index(iova)];
+
+        if entry == 0 {
+            return None;
+        }
+
+        Some(entry_address(entry) + u64::from(iova % SMMU_PAGE_SIZE))
+    }
+}
+
+/// Enumeration of the SMMU software groups.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwGroup {
+    /// The PCIe controller.
+    Afi,
+    /// The BPMP (AVP).
+    Avpc,
+    /// Display controller A.
+    Dc,
+    /// Display controller B.
+    Dcb,
+    /// Host1x.
+    Hc,
+    /// HDA.
+    Hda,
+    /// ISP2.
+    Isp2,
+    /// ISP2B.
+    Isp2B,
+    /// NVENC.
+    NvEnc,
+    /// The AHB DMA.
+    Ppcs,
+    /// The AHB slaves.
+    Ppcs1,
+    /// SATA.
+    Sata,
+    /// VI.
+    Vi,
+    /// VIC.
+    Vic,
+    /// The XUSB host controller.
+    XusbHost,
+    /// The XUSB device controller.
+    XusbDev,
+    /// The BPMP (AVP) cache.
+    A9Avp,
+    /// The TSEC.
+    Tsec,
+    /// The TSECB.
+    TsecB,
+    /// SDMMC1.
+    Sdmmc1A,
+    /// SDMMC2.
+    Sdmmc2A,
+    /// SDMMC3.
+    Sdmmc3A,
+    /// SDMMC4.
+    Sdmmc4A,
+    /// NVDEC.
+    NvDec,
+    /// APE.
+    Ape,
+    /// NVJPG.
+    NvJpg,
+}
+
+impl SwGroup {
+    /// Gets the offset of the `MC_SMMU_x_ASID` register of the swgroup.
+    fn asid_register(self) -> u32 {
+        match self {
+            SwGroup::Afi => 0x238,
+            SwGroup::Avpc => 0x23C,
+            SwGroup::Dc => 0x240,
+            SwGroup::Dcb => 0x244,
+            SwGroup::Hc => 0x250,
+            SwGroup::Hda => 0x254,
+            SwGroup::Isp2 => 0x258,
+            SwGroup::NvEnc => 0x264,
+            SwGroup::Ppcs => 0x270,
+            SwGroup::Ppcs1 => 0x298,
+            SwGroup::Sata => 0x274,
+            SwGroup::Vi => 0x280,
+            SwGroup::Vic => 0x284,
+            SwGroup::XusbHost => 0x288,
+            SwGroup::XusbDev => 0x28C,
+            SwGroup::A9Avp => 0x290,
+            SwGroup::Tsec => 0x294,
+            SwGroup::Sdmmc1A => 0xA94,
+            SwGroup::Sdmmc2A => 0xA98,
+            SwGroup::Sdmmc3A => 0xA9C,
+            SwGroup::Sdmmc4A => 0xAA0,
+            SwGroup::Isp2B => 0xAA4,
+            SwGroup::NvDec => 0xAB4,
+            SwGroup::Ape => 0xAB8,
+            SwGroup::NvJpg => 0xAC0,
+            SwGroup::TsecB => 0xAD4,
+        }
+    }
+
+    /// Gets the memory clients that belong to the swgroup.
+    pub fn clients(self) -> &'static [Client] {
+        match self {
+            SwGroup::Afi => &[Client::Afir, Client::Afiw],
+            SwGroup::Avpc => &[Client::AvpCArm7R, Client::AvpCArm7W],
+            SwGroup::Dc => &[
+                Client::Display0A,
+                Client::Display0B,
+                Client::Display0C,
+                Client::DisplayHC,
+                Client::DisplayT,
+                Client::DisplayD,
+            ],
+            SwGroup::Dcb => &[
+                Client::Display0AB,
+                Client::Display0BB,
+                Client::Display0CB,
+                Client::DisplayHCB,
+            ],
+            SwGroup::Hc => &[Client::Host1xDmar, Client::Host1xr, Client::Host1xw],
+            SwGroup::Hda => &[Client::Hdar, Client::Hdaw],
+            SwGroup::Isp2 => &[Client::IspRa, Client::IspWa, Client::IspWb],
+            SwGroup::Isp2B => &[Client::IspRab, Client::IspWab, Client::IspWbb],
+            SwGroup::NvEnc => &[Client::NvEncSrd, Client::NvEncSwr],
+            SwGroup::Ppcs => &[Client::PpcsAhbDmar, Client::PpcsAhbDmaw],
+            SwGroup::Ppcs1 => &[Client::PpcsAhbSlvr, Client::PpcsAhbSlvw],
+            SwGroup::Sata => &[Client::Satar, Client::Sataw],
+            SwGroup::Vi => &[Client::Viw],
+            SwGroup::Vic => &[Client::VicSrd, Client::VicSwr],
+            SwGroup::XusbHost => &[Client::XusbHostr, Client::XusbHostw],
+            SwGroup::XusbDev => &[Client::XusbDevr, Client::XusbDevw],
+            SwGroup::A9Avp => &[Client::A9AvpScr, Client::A9AvpScw],
+            SwGroup::Tsec => &[Client::TsecSrd, Client::TsecSwr],
+            SwGroup::TsecB => &[Client::TsecSrdB, Client::TsecSwrB],
+            SwGroup::Sdmmc1A => &[Client::SdmmcRa, Client::SdmmcWa],
+            SwGroup::Sdmmc2A => &[Client::SdmmcRaa, Client::SdmmcWaa],
+            SwGroup::Sdmmc3A => &[Client::SdmmcR, Client::SdmmcW],
+            SwGroup::Sdmmc4A => &[Client::SdmmcRab, Client::SdmmcWab],
+            SwGroup::NvDec => &[Client::NvDecSrd, Client::NvDecSwr],
+            SwGroup::Ape => &[Client::Aper, Client::Apew],
+            SwGroup::NvJpg => &[Client::NvJpgSrd, Client::NvJpgSwr],
+        }
+    }
+}
+
+/// Validates an address space ID.
+fn check_asid(asid: u8) -> Result<(), Error> {
+    if asid >= ASID_COUNT {
+        return Err(Error::InvalidAsid(asid));
+    }
+
+    Ok(())
+}
+
+/// Waits for preceding SMMU register writes to take effect.
+fn barrier() {
+    register(MC_SMMU_CONFIG).read();
+}
+
+/// Enables the TLB, the PTC and address translation.
+pub fn enable() {
+    register(MC_SMMU_TLB_CONFIG).write(SMMU_TLB_CONFIG);
+    register(MC_SMMU_PTC_CONFIG).write(SMMU_PTC_CONFIG);
+
+    flush_ptc_all();
+    flush_tlb_all();
+
+    register(MC_SMMU_CONFIG).write(SMMU_CONFIG_ENABLE);
+    barrier();
+}
+
+/// Disables address translation.
+pub fn disable() {
+    register(MC_SMMU_CONFIG).write(0);
+    barrier();
+}
+
+/// Points the address space `asid` to the page directory at `directory_address`.
+pub fn set_address_space(
+    asid: u8,
+    directory_address: u64,
+    attributes: Attributes,
+) -> Result<(), Error> {
+    check_asid(asid)?;
+
+    if directory_address % u64::from(SMMU_PAGE_SIZE) != 0
+        || directory_address >> PHYSICAL_ADDRESS_BITS != 0
+    {
+        return Err(Error::UnalignedMapping);
+    }
+
+    register(MC_SMMU_PTB_ASID).write(u32::from(asid));
+    register(MC_SMMU_PTB_DATA).write(make_entry(directory_address, attributes.bits()));
+    barrier();
+
+    Ok(())
+}
+
+/// Removes the page directory of the address space `asid`.
+pub fn clear_address_space(asid: u8) -> Result<(), Error> {
+    check_asid(asid)?;
+
+    register(MC_SMMU_PTB_ASID).write(u32::from(asid));
+    register(MC_SMMU_PTB_DATA).write(0);
+    barrier();
+
+    Ok(())
+}
+
+/// Sets or clears the translation enable bits of the clients of `group`.
+fn set_translation(group: SwGroup, enable: bool) {
+    let mask = ClientMask::from_clients(group.clients()).build();
+
+    for (&offset, &bits) in MC_SMMU_TRANSLATION_ENABLE.iter().zip(mask.iter()) {
+        if bits == 0 {
+            continue;
+        }
+
+        let register = register(offset);
+        if enable {
+            register.write(register.read() | bits);
+        } else {
+            register.write(register.read() & !bits);
+        }
+    }
+}
+
+/// Assigns the clients of `group` to the address space `asid` and
+/// enables translation for them.
+pub fn assign(group: SwGroup, asid: u8) -> Result<(), Error> {
+    check_asid(asid)?;
+
+    register(group.asid_register()).write(SMMU_ASID_ENABLE | u32::from(asid));
+    set_translation(group, true);
+    barrier();
+
+    Ok(())
+}
+
+/// Disables translation for the clients of `group`.
+pub fn unassign(group: SwGroup) {
+    set_translation(group, false);
+    register(group.asid_register()).write(0);
+    barrier();
+}
+
+/// Flushes the whole TLB.
+pub fn flush_tlb_all() {
+    register(MC_SMMU_TLB_FLUSH).write(0);
+    barrier();
+}
+
+/// Flushes the TLB entries of the address space `asid`.
+pub fn flush_tlb_asid(asid: u8) -> Result<(), Error> {
+    check_asid(asid)?;
+
+    register(MC_SMMU_TLB_FLUSH)
+        .write(SMMU_TLB_FLUSH_ASID_MATCH | u32::from(asid) << SMMU_TLB_FLUSH_ASID_SHIFT);
+    barrier();
+
+    Ok(())
+}
+
+/// Flushes the TLB entries of the 4MiB section containing `iova` in the
+/// address space `asid`.
+pub fn flush_tlb_section(asid: u8, iova: u32) -> Result<(), Error> {
+    check_asid(asid)?;
+
+    register(MC_SMMU_TLB_FLUSH).write(
+        SMMU_TLB_FLUSH_ASID_MATCH
+            | u32::from(asid) << SMMU_TLB_FLUSH_ASID_SHIFT
+            | (iova & !(SMMU_SECTION_SIZE - 1)) >> 12
+            | SMMU_TLB_FLUSH_VA_MATCH_SECTION,
+    );
+    barrier();
+
+    Ok(())
+}
+
+/// Flushes the whole PTC.
+pub fn flush_ptc_all() {
+    register(MC_SMMU_PTC_FLUSH).write(0);
+    barrier();
+}
+
+/// Flushes the PTC entry of the directory or table entry at `address`.
+pub fn flush_ptc(address: u64) {
+    register(MC_SMMU_PTC_FLUSH_1).write((address >> 32) as u32);
+    register(MC_SMMU_PTC_FLUSH)
+        .write(address as u32 & SMMU_PTC_FLUSH_ADR_MASK | SMMU_PTC_FLUSH_TYPE_ADR);
+    barrier();
+}
+
+/// Flushes the PTC and the TLB entries of the address space `asid`, so
+/// that modifications of its page tables take effect.
+pub fn flush_address_space(asid: u8) -> Result<(), Error> {
+    flush_ptc_all();
+    flush_tlb_asid(asid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The physical address of the page directory in the tests.
+    const DIRECTORY_ADDRESS: u64 = 0x8000_0000;
+
+    /// The physical address of the page table pool in the tests.
+    const TABLES_ADDRESS: u64 = 0x8000_1000;
+
+    /// Tests the encoding of directory and table entries.
+    #[test]
+    fn map_entries() {
+        let mut directory = PageTable::new();
+        let mut tables = [PageTable::new(), PageTable::new()];
+        let mut page_tables = PageTables::with_addresses(
+            &mut directory,
+            DIRECTORY_ADDRESS,
+            &mut tables,
+            TABLES_ADDRESS,
+        );
+
+        page_tables
+            .map(0x8040_1000, 0x1_2345_6000, 0x2000, Attributes::READ_WRITE)
+            .unwrap();
+
+        assert_eq!(page_tables.allocated(), 1);
+        assert_eq!(page_tables.directory_address(), DIRECTORY_ADDRESS);
+        assert_eq!(page_tables.directory().entries()[0x201], 0xF008_0001);
+        assert_eq!(
+            page_tables
+                .directory()
+                .entries()
+                .iter()
+                .filter(|&&e| e != 0)
+                .count(),
+            1
+        );
+
+        assert_eq!(tables[0].entries()[1], 0xE012_3456);
+        assert_eq!(tables[0].entries()[2], 0xE012_3457);
+        assert_eq!(tables[0].entries().iter().filter(|&&e| e != 0).count(), 2);
+    }
+
+    /// Tests translating mapped and unmapped addresses.
+    #[test]
+    fn translate() {
+        let mut directory = PageTable::new();
+        let mut tables = [PageTable::new(), PageTable::new()];
+        let mut page_tables = PageTables::with_addresses(
+            &mut directory,
+            DIRECTORY_ADDRESS,
+            &mut tables,
+            TABLES_ADDRESS,
+        );
+
+        // Crosses a section boundary and needs two page tables.
+        page_tables
+            .map(0x3F_F000, 0x9000_0000, 0x2000, Attributes::READABLE)
+            .unwrap();
+
+        assert_eq!(page_tables.allocated(), 2);
+        assert_eq!(page_tables.translate(0x3F_F123), Some(0x9000_0123));
+        assert_eq!(page_tables.translate(0x40_0FFF), Some(0x9000_1FFF));
+        assert_eq!(page_tables.translate(0x40_1000), None);
+        assert_eq!(page_tables.translate(0x80_0000), None);
+
+        page_tables.unmap(0x3F_F000, 0x1000).unwrap();
+
+        assert_eq!(page_tables.translate(0x3F_F000), None);
+        assert_eq!(page_tables.translate(0x40_0000), Some(0x9000_1000));
+    }
+
+    /// Tests that invalid mappings are rejected.
+    #[test]
+    fn map_errors() {
+        let mut directory = PageTable::new();
+        let mut tables = [PageTable::new()];
+        let mut page_tables = PageTables::with_addresses(
+            &mut directory,
+            DIRECTORY_ADDRESS,
+            &mut tables,
+            TABLES_ADDRESS,
+        );
+
+        let rw = Attributes::READ_WRITE;
+        assert_eq!(
+            page_tables.map(0x800, 0, 0x1000, rw),
+            Err(Error::UnalignedMapping)
+        );
+        assert_eq!(
+            page_tables.map(0, 0x800, 0x1000, rw),
+            Err(Error::UnalignedMapping)
+        );
+        assert_eq!(
+            page_tables.map(0, 0, 0x800, rw),
+            Err(Error::UnalignedMapping)
+        );
+        assert_eq!(
+            page_tables.map(0xFFFF_F000, 0, 0x2000, rw),
+            Err(Error::UnalignedMapping)
+        );
+        assert_eq!(
+            page_tables.map(0, 0x3_FFFF_F000, 0x2000, rw),
+            Err(Error::UnalignedMapping)
+        );
+        assert_eq!(page_tables.allocated(), 0);
+
+        page_tables.map(0, 0, 0x1000, rw).unwrap();
+        assert_eq!(
+            page_tables.map(0, 0, 0x1000, rw),
+            Err(Error::AlreadyMapped(0))
+        );
+        assert_eq!(
+            page_tables.map(0x40_0000, 0, 0x1000, rw),
+            Err(Error::OutOfPageTables)
+        );
+    }
+
+    /// Tests that the AHB DMA and the AHB slaves use separate swgroups.
+    #[test]
+    fn ppcs_swgroups() {
+        assert_eq!(SwGroup::Ppcs.asid_register(), 0x270);
+        assert_eq!(
+            SwGroup::Ppcs.clients(),
+            &[Client::PpcsAhbDmar, Client::PpcsAhbDmaw]
+        );
+
+        assert_eq!(SwGroup::Ppcs1.asid_register(), 0x298);
+        assert_eq!(
+            SwGroup::Ppcs1.clients(),
+            &[Client::PpcsAhbSlvr, Client::PpcsAhbSlvw]
+        );
+    }
+}
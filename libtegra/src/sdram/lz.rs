@@ -0,0 +1,138 @@
+//! LZ77 decompression of the SDRAM configuration tables.
+//!
+//! # Description
+//!
+//! The tables are compressed at build time in the LZ77 format of the
+//! Basic Compression Library. The first byte of a stream is a marker
+//! that is chosen to be the least frequent byte of the input. Every
+//! other byte is a literal, unless it equals the marker:
+//!
+//! - A marker followed by `0` is a literal marker byte.
+//!
+//! - Otherwise, the marker is followed by the length and the offset of
+//! a back-reference into the output that was produced so far.
+//!
+//! Lengths and offsets are stored as big-endian groups of 7 bits, where
+//! the highest bit of each byte indicates that another group follows.
+
+/// Enumeration of possible decompression errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The input ended in the middle of a back-reference.
+    Truncated,
+    /// A back-reference points before the start of the output.
+    InvalidOffset,
+    /// A length or offset does not fit into a `usize`.
+    InvalidSize,
+    /// The output buffer is too small for the decompressed data.
+    OutputTooSmall,
+}
+
+/// Reads a variable-size integer from the start of `input`.
+fn read_size(input: &mut &[u8]) -> Result<usize, Error> {
+    let mut value = 0usize;
+
+    loop {
+        let (&byte, rest) = input.split_first().ok_or(Error::Truncated)?;
+        *input = rest;
+
+        if value > usize::max_value() >> 7 {
+            return Err(Error::InvalidSize);
+        }
+        value = value << 7 | usize::from(byte & 0x7F);
+
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+}
+
+/// Decompresses `input` into `output` and returns the decompressed size.
+pub fn decompress(input: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+    let (&marker, mut input) = match input.split_first() {
+        Some(split) => split,
+        None => return Ok(0),
+    };
+    let mut position = 0;
+
+    while let Some((&symbol, rest)) = input.split_first() {
+        input = rest;
+
+        if symbol != marker || input.first() == Some(&0) {
+            if symbol == marker {
+                input = &input[1..];
+            }
+
+            *output.get_mut(position).ok_or(Error::OutputTooSmall)? = symbol;
+            position += 1;
+            continue;
+        }
+
+        let length = read_size(&mut input)?;
+        let offset = read_size(&mut input)?;
+
+        if offset == 0 || offset > position {
+            return Err(Error::InvalidOffset);
+        }
+        if length > output.len() - position {
+            return Err(Error::OutputTooSmall);
+        }
+
+        // The source may overlap with the destination, so copy bytewise.
+        for i in position..position + length {
+            output[i] = output[i - offset];
+        }
+        position += length;
+    }
+
+    Ok(position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests literals, escaped markers and overlapping back-references.
+    #[test]
+    fn decompress_stream() {
+        let input = [0xFE, 1, 2, 3, 0xFE, 0, 0xFE, 6, 3, 9];
+        let mut output = [0; 12];
+
+        assert_eq!(decompress(&input, &mut output), Ok(11));
+        assert_eq!(output, [1, 2, 3, 0xFE, 2, 3, 0xFE, 2, 3, 0xFE, 9, 0]);
+    }
+
+    /// Tests lengths that span multiple bytes.
+    #[test]
+    fn decompress_long_reference() {
+        let input = [0xFE, 7, 0xFE, 0x81, 0x00, 1];
+        let mut output = [0; 129];
+
+        assert_eq!(decompress(&input, &mut output), Ok(129));
+        assert!(output.iter().all(|&byte| byte == 7));
+    }
+
+    /// Tests that malformed streams are rejected.
+    #[test]
+    fn decompress_errors() {
+        let mut output = [0; 4];
+
+        assert_eq!(decompress(&[], &mut output), Ok(0));
+        assert_eq!(
+            decompress(&[0xFE, 1, 0xFE, 2], &mut output),
+            Err(Error::Truncated)
+        );
+        assert_eq!(
+            decompress(&[0xFE, 1, 0xFE, 2, 2], &mut output),
+            Err(Error::InvalidOffset)
+        );
+        assert_eq!(
+            decompress(&[0xFE, 1, 0xFE, 4, 1], &mut output),
+            Err(Error::OutputTooSmall)
+        );
+        assert_eq!(
+            decompress(&[0xFE, 1, 2, 3, 4, 5], &mut output),
+            Err(Error::OutputTooSmall)
+        );
+    }
+}
@@ -3,20 +3,32 @@
 //! # Implementation
 //!
 //! - [`get_parameters`] is to be used for retrieving SDRAM configuration parameters.
+//! The base configuration table is LZ77-compressed at build time and decompressed
+//! on demand, which keeps it from bloating the payload. The parameters of the
+//! other SDRAM IDs are derived from it by the [`patches`] of each ID.
 //!
 //! - The [`init`] function initializes the SDRAM and calls  [`config_sdram`] which
 //! does the actual dirty job of writing SDRAM parameters to the respective registers
 //! to configure it.
 //!
+//! - [`init_with_parameters`] does the same with parameters from another source,
+//! e.g. a BCT that was parsed with the [`params`] module.
+//!
 //! [`get_parameters`]: fn.get_parameters.html
 //! [`init`]: fn.init.html
+//! [`init_with_parameters`]: fn.init_with_parameters.html
+//! [`params`]: params/index.html
+//! [`patches`]: patches/index.html
 //! [`config_sdram`]: fn.config_sdram.html
 
-use core::{mem::transmute_copy, ptr::write_volatile};
+use core::ptr::write_volatile;
 
 use mirage_mmio::Mmio;
 
-use self::{config::DRAM_CONFIG, params::Parameters};
+use self::{
+    params::{MemoryType, Parameters, PARAMETERS_SIZE},
+    patches::DRAM_PATCHES,
+};
 use crate::{
     clock::Car,
     fuse::read_reserved_odm,
@@ -25,8 +37,24 @@ use crate::{
     timer::{get_microseconds, usleep},
 };
 
-mod config;
-mod params;
+mod lz;
+pub mod params;
+pub mod patches;
+#[cfg(test)]
+mod vectors;
+
+include!(concat!(env!("OUT_DIR"), "/dram_config.rs"));
+
+/// Enumeration of possible SDRAM parameter errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The parameters or the BCT have an invalid size.
+    InvalidSize(usize),
+    /// The parameters contain an unsupported memory type.
+    InvalidMemoryType(u32),
+    /// The BCT does not contain the requested SDRAM parameter set.
+    InvalidSdramSet(usize),
+}
 
 /// Retrieves the SDRAM ID.
 #[inline]
@@ -36,6 +64,8 @@ fn get_sdram_id() -> usize {
 
 /// Configures the SDRAM.
 fn config_sdram(car: &Car, pmc: &Pmc, params: &mut Parameters) {
+    let memory_type = params.get_memory_type();
+
     pmc.io_dpd3_req
         .write((((4 * params.emc_pmc_scratch1 >> 2) + 0x8000_0000) ^ 0xFFFF) & 0xC000_FFFF);
     usleep(params.pmc_io_dpd3_req_wait);
@@ -483,11 +513,11 @@ fn config_sdram(car: &Car, pmc: &Pmc, params: &mut Parameters) {
         (*((0x7001B000 + 820) as *const Mmio<u32>)).write(params.emc_pmacro_brick_ctrl_rfu2);
 
         if params.emc_zcal_warm_cold_boot_enables & 1 != 0 {
-            if params.memory_type == 2 {
+            if memory_type == Some(MemoryType::Ddr3) {
                 (*((0x7001B000 + 740) as *const Mmio<u32>)).write(8 * params.emc_zcal_wait_cnt);
             }
 
-            if params.memory_type == 3 {
+            if memory_type == Some(MemoryType::Lpddr4) {
                 (*((0x7001B000 + 740) as *const Mmio<u32>)).write(params.emc_zcal_wait_cnt);
                 (*((0x7001B000 + 744) as *const Mmio<u32>)).write(params.emc_zcal_mrw_cmd);
             }
@@ -498,7 +528,7 @@ fn config_sdram(car: &Car, pmc: &Pmc, params: &mut Parameters) {
         pmc.ddr_cntrl.write(pmc.ddr_cntrl.read() & 0xFFF8_007F);
         usleep(params.pmc_ddr_ctrl_wait);
 
-        if params.memory_type == 2 {
+        if memory_type == Some(MemoryType::Ddr3) {
             (*((0x7001B000 + 36) as *const Mmio<u32>))
                 .write((params.emc_pin_gpio_enable << 16) | (params.emc_pin_gpio << 12));
             usleep(params.emc_pin_extra_wait + 200);
@@ -507,7 +537,7 @@ fn config_sdram(car: &Car, pmc: &Pmc, params: &mut Parameters) {
             usleep(params.emc_pin_extra_wait + 500);
         }
 
-        if params.memory_type == 3 {
+        if memory_type == Some(MemoryType::Lpddr4) {
             (*((0x7001B000 + 36) as *const Mmio<u32>))
                 .write((params.emc_pin_gpio_enable << 16) | (params.emc_pin_gpio << 12));
             usleep(params.emc_pin_extra_wait + 200);
@@ -520,15 +550,15 @@ fn config_sdram(car: &Car, pmc: &Pmc, params: &mut Parameters) {
             .write(((params.emc_pin_gpio_enable << 16) | (params.emc_pin_gpio << 12)) + 0x101);
         usleep(params.emc_pin_program_wait);
 
-        if params.memory_type != 3 {
+        if memory_type != Some(MemoryType::Lpddr4) {
             (*((0x7001B000 + 220) as *const Mmio<u32>)).write((params.emc_dev_select << 30) + 1);
         }
 
-        if params.memory_type == 1 {
+        if memory_type == Some(MemoryType::Lpddr2) {
             usleep(params.emc_pin_extra_wait + 200);
         }
 
-        if params.memory_type == 3 {
+        if memory_type == Some(MemoryType::Lpddr4) {
             if params.emc_bct_spare10 != 0 {
                 write_volatile(
                     &mut params.emc_bct_spare10 as *mut u32,
@@ -563,7 +593,7 @@ fn config_sdram(car: &Car, pmc: &Pmc, params: &mut Parameters) {
 
         pmc.ddr_cfg.write(params.pmc_ddr_cfg);
 
-        if (params.memory_type - 1) <= 2 {
+        if memory_type.is_some() {
             (*((0x7001B000 + 736) as *const Mmio<u32>)).write(params.emc_zcal_interval);
             (*((0x7001B000 + 740) as *const Mmio<u32>)).write(params.emc_zcal_wait_cnt);
             (*((0x7001B000 + 744) as *const Mmio<u32>)).write(params.emc_zcal_mrw_cmd);
@@ -610,18 +640,32 @@ fn config_sdram(car: &Car, pmc: &Pmc, params: &mut Parameters) {
     }
 }
 
+/// Decompresses the base SDRAM parameters and patches them for the SDRAM `id`.
+fn decompress_parameters(id: usize) -> Parameters {
+    let mut buffer = [0u8; PARAMETERS_SIZE];
+
+    let size = lz::decompress(BASE_DRAM_CONFIG_LZ, &mut buffer)
+        .expect("Corrupted SDRAM configuration table!");
+    assert_eq!(size, PARAMETERS_SIZE);
+
+    patches::apply(&mut buffer, DRAM_PATCHES[id]);
+
+    Parameters::from_bytes(&buffer).expect("Invalid SDRAM configuration table!")
+}
+
 /// Retrieves the SDRAM parameters.
 pub fn get_parameters() -> Parameters {
-    // TODO(Vale): LZ77 compression of the config values.
-    let parameters: Parameters = unsafe { transmute_copy(&DRAM_CONFIG[get_sdram_id()]) };
-
-    parameters
+    decompress_parameters(get_sdram_id())
 }
 
-/// Initializes and configures the SDRAM.
+/// Initializes and configures the SDRAM with the built-in parameters.
 pub fn init(car: &Car, pmc: &Pmc) {
-    let mut params = get_parameters();
+    init_with_parameters(car, pmc, get_parameters());
+}
 
+/// Initializes and configures the SDRAM with `params`, e.g. parameters
+/// that were read from a BCT.
+pub fn init_with_parameters(car: &Car, pmc: &Pmc, mut params: Parameters) {
     I2c::C5.write_byte(Device::Max77620Pwr, 0x22, 5).unwrap();
     I2c::C5.write_byte(Device::Max77620Pwr, 0x17, 40).unwrap();
 
@@ -644,3 +688,95 @@ pub fn init(car: &Car, pmc: &Pmc) {
 
     config_sdram(car, pmc, &mut params);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{params::BCT_MAX_SDRAM_SETS, vectors::DRAM_CONFIG, *};
+
+    /// Tests that the patched base table matches the full table of each SDRAM ID.
+    #[test]
+    fn parameters_are_identical() {
+        assert_eq!(DRAM_PATCHES.len(), DRAM_CONFIG.len());
+
+        for (id, table) in DRAM_CONFIG.iter().enumerate() {
+            let mut bytes = [0; PARAMETERS_SIZE];
+            decompress_parameters(id).to_bytes(&mut bytes).unwrap();
+
+            assert_eq!(&bytes[..], &table[..], "SDRAM ID {}", id);
+        }
+    }
+
+    /// Tests that invalid parameters are rejected.
+    #[test]
+    fn parameters_errors() {
+        let mut bytes = DRAM_CONFIG[0];
+
+        assert_eq!(
+            Parameters::from_bytes(&bytes[1..]).err(),
+            Some(Error::InvalidSize(PARAMETERS_SIZE - 1))
+        );
+
+        bytes[0] = 4;
+        assert_eq!(
+            Parameters::from_bytes(&bytes).err(),
+            Some(Error::InvalidMemoryType(4))
+        );
+    }
+
+    /// Tests reading parameter sets from a BCT.
+    #[test]
+    fn parameters_from_bct() {
+        let mut bct = [0; 0x2800];
+        bct[0x728] = 2;
+        bct[0x72C + PARAMETERS_SIZE..0x72C + 2 * PARAMETERS_SIZE].copy_from_slice(&DRAM_CONFIG[4]);
+
+        let mut bytes = [0; PARAMETERS_SIZE];
+        let parameters = Parameters::from_bct(&bct, 1).unwrap();
+        parameters.to_bytes(&mut bytes).unwrap();
+        assert_eq!(&bytes[..], &DRAM_CONFIG[4][..]);
+
+        assert_eq!(
+            Parameters::from_bct(&bct, 0).err(),
+            Some(Error::InvalidMemoryType(0))
+        );
+        assert_eq!(
+            Parameters::from_bct(&bct, 2).err(),
+            Some(Error::InvalidSdramSet(2))
+        );
+        assert_eq!(
+            Parameters::from_bct(&bct[..0x1000], 1).err(),
+            Some(Error::InvalidSize(0x1000))
+        );
+
+        bct[0x728] = BCT_MAX_SDRAM_SETS as u8 + 1;
+        assert_eq!(
+            Parameters::from_bct(&bct, BCT_MAX_SDRAM_SETS).err(),
+            Some(Error::InvalidSdramSet(BCT_MAX_SDRAM_SETS))
+        );
+    }
+
+    /// Tests that the patches are sorted, aligned and only override fields
+    /// which differ from the base table.
+    #[test]
+    fn patches_are_minimal() {
+        let base = &DRAM_CONFIG[0];
+
+        for patches in DRAM_PATCHES.iter() {
+            for (i, patch) in patches.iter().enumerate() {
+                assert_eq!(patch.offset % 4, 0);
+                assert!(patch.offset < PARAMETERS_SIZE);
+                assert!(i == 0 || patches[i - 1].offset < patch.offset);
+                assert_ne!(
+                    &base[patch.offset..patch.offset + 4],
+                    &patch.value.to_le_bytes()[..]
+                );
+            }
+        }
+    }
+
+    /// Tests that the compressed base table is smaller than the original one.
+    #[test]
+    fn table_is_compressed() {
+        assert!(BASE_DRAM_CONFIG_LZ.len() < PARAMETERS_SIZE);
+    }
+}
@@ -0,0 +1,131 @@
+//! Per-board SDRAM parameter patches.
+//!
+//! # Description
+//!
+//! The SDRAM parameters of the different Switch revisions and DRAM
+//! vendors only differ in a few fields. Instead of a full table for
+//! each SDRAM ID, only the parameters of SDRAM ID 0 are stored and the
+//! other IDs are described as a list of overrides on top of them.
+
+use super::params::PARAMETERS_SIZE;
+
+/// An override of a single 32-bit SDRAM parameter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Patch {
+    /// The byte offset of the parameter in the serialized `Parameters`.
+    pub offset: usize,
+    /// The value of the parameter.
+    pub value: u32,
+}
+
+impl Patch {
+    /// Creates a new patch.
+    pub const fn new(offset: usize, value: u32) -> Self {
+        Patch { offset, value }
+    }
+}
+
+/// The patches of SDRAM ID 1.
+const PATCHES_1: &[Patch] = &[
+    Patch::new(0x10C, 0x0000_000D), // emc_r2w
+    Patch::new(0x16C, 0x0000_0001), // emc_puterm_extra
+    Patch::new(0x170, 0x8000_0000), // emc_puterm_width
+    Patch::new(0x4F4, 0x0000_0210), // emc_pmacro_data_rx_term_mode
+    Patch::new(0x5C0, 0x0000_0005), // mc_emem_arb_timing_r2w
+];
+
+/// The patches of SDRAM ID 3.
+const PATCHES_3: &[Patch] = &[
+    Patch::new(0x1B0, 0x0000_0012), // emc_rw2pden
+    Patch::new(0x1DC, 0x0000_0003), // emc_tclkstable
+    Patch::new(0x49C, 0x0000_0012), // emc_cmd_brlshft2
+    Patch::new(0x4A0, 0x0000_0012), // emc_cmd_brlshft3
+];
+
+/// The patches of SDRAM ID 4.
+const PATCHES_4: &[Patch] = &[
+    Patch::new(0x56C, 0x000C_0302), // mc_emem_adr_cfg_dev0
+    Patch::new(0x570, 0x000C_0302), // mc_emem_adr_cfg_dev1
+    Patch::new(0x584, 0x0000_1800), // mc_emem_cfg
+];
+
+/// The patches of SDRAM ID 5.
+const PATCHES_5: &[Patch] = &[
+    Patch::new(0x10C, 0x0000_000D), // emc_r2w
+    Patch::new(0x16C, 0x0000_0001), // emc_puterm_extra
+    Patch::new(0x170, 0x8000_0000), // emc_puterm_width
+    Patch::new(0x1B0, 0x0000_0012), // emc_rw2pden
+    Patch::new(0x1DC, 0x0000_0003), // emc_tclkstable
+    Patch::new(0x334, 0x0012_0015), // emc_pmacro_ob_ddll_long_dq_rank0_4
+    Patch::new(0x338, 0x0016_0012), // emc_pmacro_ob_ddll_long_dq_rank0_5
+    Patch::new(0x34C, 0x0012_0015), // emc_pmacro_ob_ddll_long_dq_rank1_4
+    Patch::new(0x350, 0x0016_0012), // emc_pmacro_ob_ddll_long_dq_rank1_5
+    Patch::new(0x354, 0x002F_0032), // emc_pmacro_ob_ddll_long_dqs_rank0_0
+    Patch::new(0x358, 0x0031_0032), // emc_pmacro_ob_ddll_long_dqs_rank0_1
+    Patch::new(0x35C, 0x0036_0034), // emc_pmacro_ob_ddll_long_dqs_rank0_2
+    Patch::new(0x360, 0x0033_002F), // emc_pmacro_ob_ddll_long_dqs_rank0_3
+    Patch::new(0x364, 0x0000_0006), // emc_pmacro_ob_ddll_long_dqs_rank0_4
+    Patch::new(0x36C, 0x002F_0032), // emc_pmacro_ob_ddll_long_dqs_rank1_0
+    Patch::new(0x370, 0x0031_0032), // emc_pmacro_ob_ddll_long_dqs_rank1_1
+    Patch::new(0x374, 0x0036_0034), // emc_pmacro_ob_ddll_long_dqs_rank1_2
+    Patch::new(0x378, 0x0033_002F), // emc_pmacro_ob_ddll_long_dqs_rank1_3
+    Patch::new(0x37C, 0x0000_0006), // emc_pmacro_ob_ddll_long_dqs_rank1_4
+    Patch::new(0x3A4, 0x0015_0015), // emc_pmacro_ddll_long_cmd_0
+    Patch::new(0x3AC, 0x0012_0012), // emc_pmacro_ddll_long_cmd_2
+    Patch::new(0x3B0, 0x0016_0016), // emc_pmacro_ddll_long_cmd_3
+    Patch::new(0x3B4, 0x0000_0015), // emc_pmacro_ddll_long_cmd_4
+    Patch::new(0x49C, 0x0000_0012), // emc_cmd_brlshft2
+    Patch::new(0x4A0, 0x0000_0012), // emc_cmd_brlshft3
+    Patch::new(0x4F4, 0x0000_0210), // emc_pmacro_data_rx_term_mode
+    Patch::new(0x5C0, 0x0000_0005), // mc_emem_arb_timing_r2w
+];
+
+/// The patches of SDRAM ID 6.
+const PATCHES_6: &[Patch] = &[
+    Patch::new(0x0EC, 0x0000_003A), // emc_rfc
+    Patch::new(0x0F0, 0x0000_001D), // emc_rfc_pb
+    Patch::new(0x1B0, 0x0000_0012), // emc_rw2pden
+    Patch::new(0x1C0, 0x0000_003B), // emc_txsr
+    Patch::new(0x1C4, 0x0000_003B), // emc_txsr_dll
+    Patch::new(0x1DC, 0x0000_0003), // emc_tclkstable
+    Patch::new(0x334, 0x0012_0015), // emc_pmacro_ob_ddll_long_dq_rank0_4
+    Patch::new(0x338, 0x0016_0012), // emc_pmacro_ob_ddll_long_dq_rank0_5
+    Patch::new(0x34C, 0x0012_0015), // emc_pmacro_ob_ddll_long_dq_rank1_4
+    Patch::new(0x350, 0x0016_0012), // emc_pmacro_ob_ddll_long_dq_rank1_5
+    Patch::new(0x354, 0x002F_0032), // emc_pmacro_ob_ddll_long_dqs_rank0_0
+    Patch::new(0x358, 0x0031_0032), // emc_pmacro_ob_ddll_long_dqs_rank0_1
+    Patch::new(0x35C, 0x0036_0034), // emc_pmacro_ob_ddll_long_dqs_rank0_2
+    Patch::new(0x360, 0x0033_002F), // emc_pmacro_ob_ddll_long_dqs_rank0_3
+    Patch::new(0x364, 0x0000_0006), // emc_pmacro_ob_ddll_long_dqs_rank0_4
+    Patch::new(0x36C, 0x002F_0032), // emc_pmacro_ob_ddll_long_dqs_rank1_0
+    Patch::new(0x370, 0x0031_0032), // emc_pmacro_ob_ddll_long_dqs_rank1_1
+    Patch::new(0x374, 0x0036_0034), // emc_pmacro_ob_ddll_long_dqs_rank1_2
+    Patch::new(0x378, 0x0033_002F), // emc_pmacro_ob_ddll_long_dqs_rank1_3
+    Patch::new(0x37C, 0x0000_0006), // emc_pmacro_ob_ddll_long_dqs_rank1_4
+    Patch::new(0x3A4, 0x0015_0015), // emc_pmacro_ddll_long_cmd_0
+    Patch::new(0x3AC, 0x0012_0012), // emc_pmacro_ddll_long_cmd_2
+    Patch::new(0x3B0, 0x0016_0016), // emc_pmacro_ddll_long_cmd_3
+    Patch::new(0x3B4, 0x0000_0015), // emc_pmacro_ddll_long_cmd_4
+    Patch::new(0x49C, 0x0000_0012), // emc_cmd_brlshft2
+    Patch::new(0x4A0, 0x0000_0012), // emc_cmd_brlshft3
+    Patch::new(0x5C8, 0x0000_0007), // mc_emem_arb_timing_rfcpb
+    Patch::new(0x5D4, 0x72A3_0504), // mc_emem_arb_misc0
+];
+
+/// The patches of each SDRAM ID.
+pub const DRAM_PATCHES: [&[Patch]; 7] = [
+    &[],
+    PATCHES_1,
+    &[],
+    PATCHES_3,
+    PATCHES_4,
+    PATCHES_5,
+    PATCHES_6,
+];
+
+/// Applies `patches` to the serialized parameters in `bytes`.
+pub fn apply(bytes: &mut [u8; PARAMETERS_SIZE], patches: &[Patch]) {
+    for patch in patches {
+        bytes[patch.offset..patch.offset + 4].copy_from_slice(&patch.value.to_le_bytes());
+    }
+}
@@ -0,0 +1,334 @@
+//! AES block cipher modes on top of the Security Engine.
+//!
+//! # Description
+//!
+//! The SE implements AES in hardware and is able to process multiple blocks
+//! per operation. This module provides ECB, CBC and CTR mode operations on
+//! arbitrary-length buffers using keys from the SE keyslots.
+//!
+//! # Implementation
+//!
+//! - ECB and CBC operate on block-aligned buffers only. CTR mode also
+//! supports buffers with a trailing partial block.
+//!
+//! - Buffers larger than what a single SE linked list can describe are
+//! split into multiple operations. The IV or counter is carried over
+//! between these operations in software.
+//!
+//! - The source and destination buffers must have the same length. As they
+//! are borrowed as `&[u8]` and `&mut [u8]`, they can't refer to the same
+//! memory, so in-place operations aren't supported.
+
+use core::convert::TryInto;
+
+use mirage_mmio::VolatileStorage;
+
+use super::*;
+
+/// Enables the hash output of the AES core.
+pub(crate) const CRYPTO_HASH_ENABLE: u32 = 1 << 0;
+/// XOR the IV with the input of the AES core.
+pub(crate) const CRYPTO_XOR_TOP: u32 = 2 << 1;
+/// XOR the IV with the output of the AES core.
+const CRYPTO_XOR_BOTTOM: u32 = 3 << 1;
+/// Use the linked counter as input to the AES core.
+const CRYPTO_INPUT_LINKED: u32 = 3 << 3;
+/// Use the previous AES output as the next IV.
+pub(crate) const CRYPTO_VCTRAM_AESOUT: u32 = 2 << 5;
+/// Use the previous AHB input as the next IV.
+const CRYPTO_VCTRAM_PREVAHB: u32 = 3 << 5;
+/// Use the IV updated by the previous operation instead of the original one.
+pub(crate) const CRYPTO_IV_UPDATED: u32 = 1 << 7;
+/// Configures the AES core for encryption.
+pub(crate) const CRYPTO_CORE_ENCRYPT: u32 = 1 << 8;
+/// Increments the counter by one for every block.
+const CRYPTO_CTR_CNTN_1: u32 = 1 << 11;
+
+/// The maximum size of a block-aligned chunk processed in a single operation.
+const MAX_CHUNK_SIZE: usize = MAX_OPERATION_SIZE & !(AES_BLOCK_SIZE - 1);
+
+/// Sizes of AES keys supported by the SE.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeySize {
+    /// 128-bit AES keys.
+    Aes128,
+    /// 192-bit AES keys.
+    Aes192,
+    /// 256-bit AES keys.
+    Aes256,
+}
+
+impl KeySize {
+    /// Gets the key size in bytes.
+    pub fn bytes(self) -> usize {
+        match self {
+            KeySize::Aes128 => 0x10,
+            KeySize::Aes192 => 0x18,
+            KeySize::Aes256 => 0x20,
+        }
+    }
+
+    /// Gets the mode value of the `CONFIG_REG` for this key size.
+    #[inline]
+    pub(crate) fn mode(self) -> u32 {
+        match self {
+            KeySize::Aes128 => 0,
+            KeySize::Aes192 => 1,
+            KeySize::Aes256 => 2,
+        }
+    }
+}
+
+/// Selects the keyslot to be used for an operation in the `CRYPTO_REG`.
+#[inline]
+pub(crate) const fn crypto_key_index(keyslot: usize) -> u32 {
+    (keyslot << 24) as u32
+}
+
+/// Adds a number of blocks to a big-endian 128-bit counter.
+fn increment_counter(ctr: &mut [u8; AES_BLOCK_SIZE], blocks: usize) {
+    let value = u128::from_be_bytes(*ctr).wrapping_add(blocks as u128);
+
+    *ctr = value.to_be_bytes();
+}
+
+impl SecurityEngine {
+    /// Processes a block-aligned buffer of at most `MAX_OPERATION_SIZE` bytes.
+    ///
+    /// `CONFIG_REG` and `CRYPTO_REG` must be configured by the caller.
+    fn perform_aes_operation(&self, destination: &mut [u8], source: &[u8]) -> Result<(), Error> {
+        let register_base = unsafe { Registers::get() };
+
+        let size = source.len();
+        if size == 0 {
+            return Ok(());
+        }
+
+        if size % AES_BLOCK_SIZE != 0 || size > MAX_OPERATION_SIZE || destination.len() != size {
+            return Err(Error::InvalidLength);
+        }
+
+        register_base
+            .BLOCK_COUNT_REG
+            .write((size / AES_BLOCK_SIZE - 1) as u32);
+        self.trigger_blocking_operation(OP_START, destination, source)
+    }
+
+    /// Validates the arguments of a block-aligned AES operation.
+    fn check_aes_arguments(
+        &self,
+        keyslot: usize,
+        destination: &[u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        if keyslot >= KEYSLOT_AES_MAX {
+            return Err(Error::InvalidKeyslot(keyslot));
+        }
+
+        if destination.len() != source.len() || source.len() % AES_BLOCK_SIZE != 0 {
+            return Err(Error::InvalidLength);
+        }
+
+        Ok(())
+    }
+
+    /// Encrypts a block-aligned buffer with AES-ECB.
+    pub fn aes_ecb_encrypt(
+        &self,
+        keyslot: usize,
+        key_size: KeySize,
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        let register_base = unsafe { Registers::get() };
+
+        self.check_aes_arguments(keyslot, destination, source)?;
+
+        register_base
+            .CONFIG_REG
+            .write(config_encrypt(ALG_AES, key_size.mode(), DST_MEMORY));
+        register_base
+            .CRYPTO_REG
+            .write(crypto_key_index(keyslot) | CRYPTO_CORE_ENCRYPT);
+
+        for (dst, src) in destination
+            .chunks_mut(MAX_CHUNK_SIZE)
+            .zip(source.chunks(MAX_CHUNK_SIZE))
+        {
+            self.perform_aes_operation(dst, src)?;
+        }
+
+        Ok(())
+    }
+
+    /// Decrypts a block-aligned buffer with AES-ECB.
+    pub fn aes_ecb_decrypt(
+        &self,
+        keyslot: usize,
+        key_size: KeySize,
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        let register_base = unsafe { Registers::get() };
+
+        self.check_aes_arguments(keyslot, destination, source)?;
+
+        register_base
+            .CONFIG_REG
+            .write(config_decrypt(ALG_AES, key_size.mode(), DST_MEMORY));
+        register_base.CRYPTO_REG.write(crypto_key_index(keyslot));
+
+        for (dst, src) in destination
+            .chunks_mut(MAX_CHUNK_SIZE)
+            .zip(source.chunks(MAX_CHUNK_SIZE))
+        {
+            self.perform_aes_operation(dst, src)?;
+        }
+
+        Ok(())
+    }
+
+    /// Encrypts a block-aligned buffer with AES-CBC.
+    ///
+    /// NOTE: This overwrites the IV of the given keyslot.
+    pub fn aes_cbc_encrypt(
+        &self,
+        keyslot: usize,
+        key_size: KeySize,
+        iv: &[u8; AES_BLOCK_SIZE],
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        let register_base = unsafe { Registers::get() };
+
+        self.check_aes_arguments(keyslot, destination, source)?;
+
+        register_base
+            .CONFIG_REG
+            .write(config_encrypt(ALG_AES, key_size.mode(), DST_MEMORY));
+        register_base.CRYPTO_REG.write(
+            crypto_key_index(keyslot) | CRYPTO_CORE_ENCRYPT | CRYPTO_VCTRAM_AESOUT | CRYPTO_XOR_TOP,
+        );
+
+        let mut iv = *iv;
+
+        for (dst, src) in destination
+            .chunks_mut(MAX_CHUNK_SIZE)
+            .zip(source.chunks(MAX_CHUNK_SIZE))
+        {
+            self.set_aes_keyslot_iv(keyslot, &iv)?;
+            self.perform_aes_operation(dst, src)?;
+
+            // The last ciphertext block is the IV for the next chunk.
+            iv = dst[dst.len() - AES_BLOCK_SIZE..].try_into().unwrap();
+        }
+
+        Ok(())
+    }
+
+    /// Decrypts a block-aligned buffer with AES-CBC.
+    ///
+    /// NOTE: This overwrites the IV of the given keyslot.
+    pub fn aes_cbc_decrypt(
+        &self,
+        keyslot: usize,
+        key_size: KeySize,
+        iv: &[u8; AES_BLOCK_SIZE],
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        let register_base = unsafe { Registers::get() };
+
+        self.check_aes_arguments(keyslot, destination, source)?;
+
+        register_base
+            .CONFIG_REG
+            .write(config_decrypt(ALG_AES, key_size.mode(), DST_MEMORY));
+        register_base
+            .CRYPTO_REG
+            .write(crypto_key_index(keyslot) | CRYPTO_VCTRAM_PREVAHB | CRYPTO_XOR_BOTTOM);
+
+        let mut iv = *iv;
+
+        for (dst, src) in destination
+            .chunks_mut(MAX_CHUNK_SIZE)
+            .zip(source.chunks(MAX_CHUNK_SIZE))
+        {
+            // The last ciphertext block of this chunk is the IV of the next one.
+            let next_iv = src[src.len() - AES_BLOCK_SIZE..].try_into().unwrap();
+
+            self.set_aes_keyslot_iv(keyslot, &iv)?;
+            self.perform_aes_operation(dst, src)?;
+
+            iv = next_iv;
+        }
+
+        Ok(())
+    }
+
+    /// Encrypts or decrypts a buffer with AES-CTR.
+    ///
+    /// The buffer doesn't need to be block-aligned; a trailing
+    /// partial block is processed using a padded copy.
+    pub fn aes_ctr(
+        &self,
+        keyslot: usize,
+        key_size: KeySize,
+        ctr: &[u8; AES_BLOCK_SIZE],
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        let register_base = unsafe { Registers::get() };
+
+        if keyslot >= KEYSLOT_AES_MAX {
+            return Err(Error::InvalidKeyslot(keyslot));
+        }
+
+        if destination.len() != source.len() {
+            return Err(Error::InvalidLength);
+        }
+
+        register_base.SPARE_0.write(1);
+        register_base
+            .CONFIG_REG
+            .write(config_encrypt(ALG_AES, key_size.mode(), DST_MEMORY));
+        register_base.CRYPTO_REG.write(
+            crypto_key_index(keyslot)
+                | CRYPTO_CORE_ENCRYPT
+                | CRYPTO_INPUT_LINKED
+                | CRYPTO_XOR_BOTTOM
+                | CRYPTO_CTR_CNTN_1,
+        );
+
+        let aligned_size = source.len() & !(AES_BLOCK_SIZE - 1);
+        let (aligned_destination, tail_destination) = destination.split_at_mut(aligned_size);
+        let (aligned_source, tail_source) = source.split_at(aligned_size);
+
+        let mut ctr = *ctr;
+
+        for (dst, src) in aligned_destination
+            .chunks_mut(MAX_CHUNK_SIZE)
+            .zip(aligned_source.chunks(MAX_CHUNK_SIZE))
+        {
+            self.set_ctr(&ctr)?;
+            self.perform_aes_operation(dst, src)?;
+
+            increment_counter(&mut ctr, src.len() / AES_BLOCK_SIZE);
+        }
+
+        // Process the remaining partial block through a padded buffer.
+        if !tail_source.is_empty() {
+            let mut block = [0; AES_BLOCK_SIZE];
+            block[..tail_source.len()].copy_from_slice(tail_source);
+
+            let input = block;
+
+            self.set_ctr(&ctr)?;
+            self.perform_aes_operation(&mut block, &input)?;
+
+            tail_destination.copy_from_slice(&block[..tail_source.len()]);
+        }
+
+        Ok(())
+    }
+}
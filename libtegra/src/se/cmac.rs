@@ -0,0 +1,130 @@
+//! AES-CMAC on top of the Security Engine.
+//!
+//! # Description
+//!
+//! AES-CMAC as specified in RFC 4493 is used to authenticate various
+//! structures, e.g. keyblobs, the BootConfig and save data headers.
+//!
+//! # Implementation
+//!
+//! - The subkeys are derived by encrypting a zero block with the key
+//! from the keyslot and doubling the result in GF(2^128).
+//!
+//! - All complete blocks but the last are processed by the SE in CBC-MAC
+//! mode, with the result being written to the `HASH_RESULT_REG`.
+//!
+//! - The last block is XORed with the corresponding subkey in software
+//! and processed using the updated IV of the previous operation.
+
+use mirage_mmio::VolatileStorage;
+
+use super::{aes::*, *};
+
+/// The constant used for subkey derivation with a block size of 128 bits.
+const CMAC_RB: u128 = 0x87;
+
+/// Multiplies a block by x in GF(2^128).
+fn double_block(block: u128) -> u128 {
+    if block >> 127 != 0 {
+        (block << 1) ^ CMAC_RB
+    } else {
+        block << 1
+    }
+}
+
+impl SecurityEngine {
+    /// Derives the CMAC subkeys K1 and K2 from the key in a keyslot.
+    fn derive_cmac_subkeys(
+        &self,
+        keyslot: usize,
+        key_size: KeySize,
+    ) -> Result<(u128, u128), Error> {
+        let mut l = [0; AES_BLOCK_SIZE];
+
+        self.aes_ecb_encrypt(keyslot, key_size, &mut l, &[0; AES_BLOCK_SIZE])?;
+
+        let k1 = double_block(u128::from_be_bytes(l));
+        let k2 = double_block(k1);
+
+        Ok((k1, k2))
+    }
+
+    /// Runs a CBC-MAC operation over block-aligned data.
+    fn perform_cmac_operation(&self, source: &[u8]) -> Result<(), Error> {
+        let register_base = unsafe { Registers::get() };
+
+        register_base
+            .BLOCK_COUNT_REG
+            .write((source.len() / AES_BLOCK_SIZE - 1) as u32);
+        self.trigger_blocking_operation(OP_START, &mut [0; 0], source)?;
+
+        // Subsequent operations continue from the updated IV.
+        register_base
+            .CRYPTO_REG
+            .write(register_base.CRYPTO_REG.read() | CRYPTO_IV_UPDATED);
+
+        Ok(())
+    }
+
+    /// Calculates the AES-CMAC of a message with the key from a keyslot.
+    ///
+    /// NOTE: This clears the IV of the given keyslot.
+    pub fn aes_cmac(
+        &self,
+        keyslot: usize,
+        key_size: KeySize,
+        data: &[u8],
+    ) -> Result<[u8; AES_BLOCK_SIZE], Error> {
+        let register_base = unsafe { Registers::get() };
+
+        if keyslot >= KEYSLOT_AES_MAX {
+            return Err(Error::InvalidKeyslot(keyslot));
+        }
+
+        let (k1, k2) = self.derive_cmac_subkeys(keyslot, key_size)?;
+
+        register_base
+            .CONFIG_REG
+            .write(config_encrypt(ALG_AES, key_size.mode(), DST_HASH_REG));
+        register_base.CRYPTO_REG.write(
+            crypto_key_index(keyslot)
+                | CRYPTO_XOR_TOP
+                | CRYPTO_VCTRAM_AESOUT
+                | CRYPTO_HASH_ENABLE
+                | CRYPTO_CORE_ENCRYPT,
+        );
+        self.clear_aes_keyslot_iv(keyslot)?;
+
+        // Split off the last block, which may be incomplete or even empty.
+        let last_size = match data.len() % AES_BLOCK_SIZE {
+            0 if !data.is_empty() => AES_BLOCK_SIZE,
+            size => size,
+        };
+        let (blocks, last) = data.split_at(data.len() - last_size);
+
+        for chunk in blocks.chunks(MAX_OPERATION_SIZE) {
+            self.perform_cmac_operation(chunk)?;
+        }
+
+        // Pad the last block if necessary and XOR it with the matching subkey.
+        let mut block = [0; AES_BLOCK_SIZE];
+        block[..last.len()].copy_from_slice(last);
+
+        let subkey = if last.len() == AES_BLOCK_SIZE {
+            k1
+        } else {
+            block[last.len()] = 0x80;
+            k2
+        };
+
+        let block = (u128::from_be_bytes(block) ^ subkey).to_be_bytes();
+        self.perform_cmac_operation(&block)?;
+
+        let mut mac = [0; AES_BLOCK_SIZE];
+        for (chunk, reg) in mac.chunks_mut(4).zip(register_base.HASH_RESULT_REG.iter()) {
+            chunk.copy_from_slice(&reg.read().to_le_bytes());
+        }
+
+        Ok(mac)
+    }
+}
@@ -0,0 +1,446 @@
+//! Security Engine context save and restore.
+//!
+//! # Description
+//!
+//! Before entering LP0 or handing the SE over to another stage, the
+//! contents of the SE keyslots can be saved to memory. The SE encrypts
+//! the context with the Secure Root Key (SRK), which is generated from
+//! the RNG and stored in the PMC secure scratch registers.
+//!
+//! # Implementation
+//!
+//! - [`save_context`] generates a fresh SRK and saves the context block by
+//! block, following the layout of the [`Context`] struct. This mirrors the
+//! context save performed by the secure monitor of Horizon.
+//!
+//! - [`restore_context`] decrypts a saved context with the SRK from the PMC
+//! into a temporary buffer and writes the AES and RSA keyslots back through
+//! the [`KeyslotManager`]. The known pattern at the end of the context is
+//! used to verify that decryption succeeded.
+//!
+//! - The sizes of the RSA keys are not part of the context. They are
+//! recovered from the most significant non-zero word of each key.
+//!
+//! - The sticky bits can only be restored by the Boot ROM and are therefore
+//! left untouched by [`restore_context`].
+//!
+//! [`save_context`]: ../struct.SecurityEngine.html#method.save_context
+//! [`restore_context`]: ../struct.SecurityEngine.html#method.restore_context
+//! [`Context`]: struct.Context.html
+//! [`KeyslotManager`]: ../keyslot/struct.KeyslotManager.html
+
+use core::{mem::size_of, ptr::write_volatile, slice};
+
+use mirage_mmio::VolatileStorage;
+
+use super::{
+    aes::KeySize,
+    keyslot::{AesKeyslot, KeyslotManager, RsaKeyslot},
+    *,
+};
+use crate::pmc::Pmc;
+
+/// The size of a saved SE context.
+pub const CONTEXT_SIZE: usize = 0x840;
+
+/// The fixed pattern which is saved at the end of the context.
+pub const KNOWN_PATTERN: [u8; AES_BLOCK_SIZE] = [
+    0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0xFE, 0xDC, 0xBA, 0x98, 0x76, 0x54, 0x32, 0x10,
+];
+
+const CONTEXT_SAVE_SRC_STICKY_BITS: u32 = 0 << 29;
+const CONTEXT_SAVE_SRC_RSA_KEYTABLE: u32 = 1 << 29;
+const CONTEXT_SAVE_SRC_AES_KEYTABLE: u32 = 2 << 29;
+const CONTEXT_SAVE_SRC_MEM: u32 = 4 << 29;
+
+const AES_WORD_QUAD_KEYS_0_3: u32 = 0;
+const AES_WORD_QUAD_ORIGINAL_IV: u32 = 2;
+const AES_WORD_QUAD_UPDATED_IV: u32 = 3;
+
+/// Selects the quad of the sticky bits to save.
+#[inline]
+const fn context_save_sticky_word_quad(quad: usize) -> u32 {
+    (quad << 24) as u32
+}
+
+/// Selects the RSA key (exponent or modulus of a keyslot) to save.
+#[inline]
+const fn context_save_rsa_key_index(index: usize) -> u32 {
+    (index << 16) as u32
+}
+
+/// Selects the quad of the RSA key to save.
+#[inline]
+const fn context_save_rsa_word_quad(quad: usize) -> u32 {
+    (quad << 12) as u32
+}
+
+/// Selects the AES keyslot to save.
+#[inline]
+const fn context_save_aes_key_index(keyslot: usize) -> u32 {
+    (keyslot << 8) as u32
+}
+
+/// Representation of a saved SE context.
+#[repr(C)]
+pub struct Context {
+    /// A random block to randomize the encryption of the following data.
+    pub random: [u8; AES_BLOCK_SIZE],
+    /// The sticky bits of the SE.
+    pub sticky_bits: [[u8; AES_BLOCK_SIZE]; 2],
+    /// The contents of the AES keyslots.
+    pub aes_keys: [[u8; KEYSIZE_AES_MAX]; KEYSLOT_AES_MAX],
+    /// The original IVs of the AES keyslots.
+    pub aes_original_ivs: [[u8; AES_BLOCK_SIZE]; KEYSLOT_AES_MAX],
+    /// The updated IVs of the AES keyslots.
+    pub aes_updated_ivs: [[u8; AES_BLOCK_SIZE]; KEYSLOT_AES_MAX],
+    /// The exponents and moduli of the RSA keyslots, in keytable order.
+    pub rsa_keys: [[[u8; KEYSIZE_RSA_MAX]; 2]; KEYSLOT_RSA_MAX],
+    /// The known pattern to verify the context with.
+    pub known_pattern: [u8; AES_BLOCK_SIZE],
+}
+
+impl Context {
+    /// Creates a new, zeroed context.
+    pub fn new() -> Self {
+        Context {
+            random: [0; AES_BLOCK_SIZE],
+            sticky_bits: [[0; AES_BLOCK_SIZE]; 2],
+            aes_keys: [[0; KEYSIZE_AES_MAX]; KEYSLOT_AES_MAX],
+            aes_original_ivs: [[0; AES_BLOCK_SIZE]; KEYSLOT_AES_MAX],
+            aes_updated_ivs: [[0; AES_BLOCK_SIZE]; KEYSLOT_AES_MAX],
+            rsa_keys: [[[0; KEYSIZE_RSA_MAX]; 2]; KEYSLOT_RSA_MAX],
+            known_pattern: [0; AES_BLOCK_SIZE],
+        }
+    }
+
+    /// Gets the raw bytes of the context.
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self as *const _ as *const u8, size_of::<Self>()) }
+    }
+
+    /// Gets the raw bytes of the context mutably.
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        unsafe { slice::from_raw_parts_mut(self as *mut _ as *mut u8, size_of::<Self>()) }
+    }
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The keyslots that were restored from a saved context.
+#[derive(Default)]
+pub struct RestoredKeyslots {
+    /// Handles to the restored AES keyslots, `None` if a keyslot was skipped.
+    pub aes: [Option<AesKeyslot>; KEYSLOT_AES_MAX],
+    /// Handles to the restored RSA keyslots, `None` if a keyslot was skipped.
+    pub rsa: [Option<RsaKeyslot>; KEYSLOT_RSA_MAX],
+}
+
+/// The granularity of the RSA modulus sizes supported by the SE.
+const RSA_MODULUS_ALIGNMENT: usize = 0x40;
+
+/// The granularity of the RSA exponent sizes supported by the SE.
+const RSA_EXPONENT_ALIGNMENT: usize = 4;
+
+/// Converts an RSA key component from keytable order into big-endian `key`.
+///
+/// The keytable holds the key as a little-endian number, so its size is
+/// recovered from the most significant non-zero byte and rounded up to
+/// `alignment`. Returns the size of the key, which is 0 for an empty keyslot.
+fn rsa_key_from_keytable(
+    keytable: &[u8; KEYSIZE_RSA_MAX],
+    alignment: usize,
+    key: &mut [u8; KEYSIZE_RSA_MAX],
+) -> usize {
+    let size = keytable
+        .iter()
+        .rposition(|byte| *byte != 0)
+        .map_or(0, |last| (last / alignment + 1) * alignment);
+
+    for (dst, src) in key.iter_mut().zip(keytable[..size].iter().rev()) {
+        *dst = *src;
+    }
+
+    size
+}
+
+/// Clears a buffer that held key material.
+fn clear(buffer: &mut [u8]) {
+    for byte in buffer.iter_mut() {
+        // Volatile writes can't be optimized away as dead stores.
+        unsafe { write_volatile(byte, 0) };
+    }
+}
+
+impl SecurityEngine {
+    /// Saves a single encrypted block of the context.
+    fn save_context_block(
+        &self,
+        config: u32,
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
+        let register_base = unsafe { Registers::get() };
+
+        register_base.CONTEXT_SAVE_CONFIG_REG.write(config);
+        register_base.BLOCK_COUNT_REG.write(0);
+        self.trigger_blocking_operation(OP_CTX_SAVE, destination, source)
+    }
+
+    /// Saves the encrypted SE context to memory.
+    ///
+    /// This generates a new SRK, which is required to restore the context.
+    /// The RNG must have been initialized through `init_rng`.
+    pub fn save_context(&self, context: &mut Context) -> Result<(), Error> {
+        self.generate_srk()?;
+
+        // Save a random block.
+        let mut random = [0; AES_BLOCK_SIZE];
+        self.generate_random(&mut random)?;
+        self.save_context_block(CONTEXT_SAVE_SRC_MEM, &mut context.random, &random)?;
+
+        // Save the sticky bits.
+        for (quad, block) in context.sticky_bits.iter_mut().enumerate() {
+            self.save_context_block(
+                CONTEXT_SAVE_SRC_STICKY_BITS | context_save_sticky_word_quad(quad),
+                block,
+                &[0; 0],
+            )?;
+        }
+
+        // Save the AES keyslots.
+        for (keyslot, key) in context.aes_keys.iter_mut().enumerate() {
+            for (quad, block) in key.chunks_mut(AES_BLOCK_SIZE).enumerate() {
+                self.save_context_block(
+                    CONTEXT_SAVE_SRC_AES_KEYTABLE
+                        | context_save_aes_key_index(keyslot)
+                        | (AES_WORD_QUAD_KEYS_0_3 + quad as u32),
+                    block,
+                    &[0; 0],
+                )?;
+            }
+        }
+
+        // Save the original and updated IVs of the AES keyslots.
+        for (keyslot, iv) in context.aes_original_ivs.iter_mut().enumerate() {
+            self.save_context_block(
+                CONTEXT_SAVE_SRC_AES_KEYTABLE
+                    | context_save_aes_key_index(keyslot)
+                    | AES_WORD_QUAD_ORIGINAL_IV,
+                iv,
+                &[0; 0],
+            )?;
+        }
+        for (keyslot, iv) in context.aes_updated_ivs.iter_mut().enumerate() {
+            self.save_context_block(
+                CONTEXT_SAVE_SRC_AES_KEYTABLE
+                    | context_save_aes_key_index(keyslot)
+                    | AES_WORD_QUAD_UPDATED_IV,
+                iv,
+                &[0; 0],
+            )?;
+        }
+
+        // Save the RSA keyslots.
+        for (keyslot, keys) in context.rsa_keys.iter_mut().enumerate() {
+            for (index, key) in keys.iter_mut().enumerate() {
+                for (quad, block) in key.chunks_mut(AES_BLOCK_SIZE).enumerate() {
+                    self.save_context_block(
+                        CONTEXT_SAVE_SRC_RSA_KEYTABLE
+                            | context_save_rsa_key_index(2 * keyslot + index)
+                            | context_save_rsa_word_quad(quad),
+                        block,
+                        &[0; 0],
+                    )?;
+                }
+            }
+        }
+
+        // Save the known pattern.
+        self.save_context_block(
+            CONTEXT_SAVE_SRC_MEM,
+            &mut context.known_pattern,
+            &KNOWN_PATTERN,
+        )
+    }
+
+    /// Restores an RSA keyslot from its exponent and modulus in keytable order.
+    ///
+    /// Keyslots which were empty when the context was saved are skipped.
+    fn restore_rsa_keyslot(
+        &mut self,
+        manager: &mut KeyslotManager,
+        keyslot: usize,
+        keys: &[[u8; KEYSIZE_RSA_MAX]; 2],
+    ) -> Result<Option<RsaKeyslot>, Error> {
+        let mut modulus = [0; KEYSIZE_RSA_MAX];
+        let mut exponent = [0; KEYSIZE_RSA_MAX];
+
+        let modulus_size = rsa_key_from_keytable(&keys[1], RSA_MODULUS_ALIGNMENT, &mut modulus);
+        let exponent_size = rsa_key_from_keytable(&keys[0], RSA_EXPONENT_ALIGNMENT, &mut exponent);
+
+        let result = if modulus_size == 0 {
+            Ok(None)
+        } else {
+            manager.restore_rsa(
+                self,
+                keyslot,
+                &modulus[..modulus_size],
+                &exponent[..exponent_size],
+            )
+        };
+
+        clear(&mut modulus);
+        clear(&mut exponent);
+
+        result
+    }
+
+    /// Restores the AES and RSA keyslots from a decrypted context.
+    fn restore_keyslots(
+        &mut self,
+        manager: &mut KeyslotManager,
+        context: &Context,
+        srk_keyslot: &AesKeyslot,
+    ) -> Result<RestoredKeyslots, Error> {
+        if context.known_pattern != KNOWN_PATTERN {
+            return Err(Error::InvalidContext);
+        }
+
+        let mut restored = RestoredKeyslots::default();
+
+        for keyslot in 0..KEYSLOT_AES_MAX {
+            let handle = manager.restore_aes(
+                self,
+                keyslot,
+                &context.aes_keys[keyslot],
+                &context.aes_original_ivs[keyslot],
+                &context.aes_updated_ivs[keyslot],
+            )?;
+
+            // The caller already holds the handle of the SRK keyslot.
+            if keyslot != srk_keyslot.index() {
+                restored.aes[keyslot] = handle;
+            }
+        }
+
+        for keyslot in 0..KEYSLOT_RSA_MAX {
+            restored.rsa[keyslot] =
+                self.restore_rsa_keyslot(manager, keyslot, &context.rsa_keys[keyslot])?;
+        }
+
+        Ok(restored)
+    }
+
+    /// Decrypts a saved context and restores the AES and RSA keyslots from it.
+    ///
+    /// `srk_keyslot` is used to hold the SRK during decryption and is restored
+    /// from the context afterwards. All other keyslots are restored through
+    /// `manager`: free keyslots are allocated, while keyslots that are locked
+    /// or owned by the Boot ROM are skipped. RSA keyslots that were empty when
+    /// the context was saved are skipped as well.
+    ///
+    /// The context is decrypted into a temporary buffer which is cleared
+    /// afterwards, `context` itself is left untouched. Fails with
+    /// [`Error::InvalidContext`] if the context could not be verified, in which
+    /// case no keyslots besides `srk_keyslot` are modified. `srk_keyslot` is
+    /// cleared on every error.
+    ///
+    /// [`Error::InvalidContext`]: ../enum.Error.html#variant.InvalidContext
+    pub fn restore_context(
+        &mut self,
+        manager: &mut KeyslotManager,
+        context: &Context,
+        srk_keyslot: &AesKeyslot,
+    ) -> Result<RestoredKeyslots, Error> {
+        let pmc = unsafe { Pmc::get() };
+
+        // Load the SRK from the PMC secure scratch registers.
+        let mut srk = [0; AES_BLOCK_SIZE];
+        for (chunk, reg) in srk.chunks_mut(4).zip(
+            [
+                &pmc.secure_scratch4,
+                &pmc.secure_scratch5,
+                &pmc.secure_scratch6,
+                &pmc.secure_scratch7,
+            ]
+            .iter(),
+        ) {
+            chunk.copy_from_slice(&reg.read().to_le_bytes());
+        }
+        let result = manager.set_aes_key(self, srk_keyslot, &srk);
+        clear(&mut srk);
+        result?;
+
+        // Decrypt the context, which is encrypted as a single CBC chain.
+        let mut decrypted = Context::new();
+        let result = self
+            .aes_cbc_decrypt(
+                srk_keyslot.index(),
+                KeySize::Aes128,
+                &[0; AES_BLOCK_SIZE],
+                decrypted.as_bytes_mut(),
+                context.as_bytes(),
+            )
+            .and_then(|_| self.restore_keyslots(manager, &decrypted, srk_keyslot));
+
+        clear(decrypted.as_bytes_mut());
+
+        // Don't leave the SRK behind if the context can't be restored.
+        if result.is_err() {
+            manager.clear_aes(self, srk_keyslot)?;
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests the recovery of RSA key sizes from the keytable order.
+    #[test]
+    fn rsa_keys_from_keytable() {
+        let mut key = [0; KEYSIZE_RSA_MAX];
+
+        // A 2048-bit modulus uses the whole keyslot.
+        let mut keytable = [0; KEYSIZE_RSA_MAX];
+        keytable[0] = 0x01;
+        keytable[KEYSIZE_RSA_MAX - 1] = 0xC5;
+        assert_eq!(
+            rsa_key_from_keytable(&keytable, RSA_MODULUS_ALIGNMENT, &mut key),
+            KEYSIZE_RSA_MAX
+        );
+        assert_eq!(key[0], 0xC5);
+        assert_eq!(key[KEYSIZE_RSA_MAX - 1], 0x01);
+
+        // A 1024-bit modulus only uses the low half.
+        let mut keytable = [0; KEYSIZE_RSA_MAX];
+        keytable[0x7F] = 0x80;
+        assert_eq!(
+            rsa_key_from_keytable(&keytable, RSA_MODULUS_ALIGNMENT, &mut key),
+            0x80
+        );
+        assert_eq!(key[0], 0x80);
+
+        // The common public exponent 65537.
+        let mut keytable = [0; KEYSIZE_RSA_MAX];
+        keytable[..3].copy_from_slice(&[0x01, 0x00, 0x01]);
+        assert_eq!(
+            rsa_key_from_keytable(&keytable, RSA_EXPONENT_ALIGNMENT, &mut key),
+            4
+        );
+        assert_eq!(key[..4], [0x00, 0x01, 0x00, 0x01]);
+
+        let keytable = [0; KEYSIZE_RSA_MAX];
+        assert_eq!(
+            rsa_key_from_keytable(&keytable, RSA_MODULUS_ALIGNMENT, &mut key),
+            0
+        );
+    }
+}
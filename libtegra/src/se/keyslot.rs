@@ -0,0 +1,744 @@
+//! Ownership and lock tracking for the SE keyslots.
+//!
+//! # Description
+//!
+//! The SE keyslots are addressed by plain indices, which makes it easy to
+//! accidentally overwrite keys that are owned by the Boot ROM or another
+//! stage, such as the SBK or the SSK. The [`KeyslotManager`] hands out typed
+//! handles to the keyslots instead and refuses operations on slots that
+//! are reserved or have been locked.
+//!
+//! # Implementation
+//!
+//! - The SBK, SSK and TSEC keyslots are reserved. Handles to them can only be
+//! obtained through [`sbk`], [`ssk`] and [`tsec`] and are never released.
+//!
+//! - The keyslots which hold the results of the [`keygen`] derivation are
+//! reserved as well, so that they are never handed out to other users.
+//!
+//! - Other keyslots are handed out through [`allocate_aes`] and
+//! [`allocate_rsa`] and are cleared before they are released again.
+//!
+//! - Flags set through the manager are recorded. The access bits of the
+//! hardware can only be cleared until the next reset, so the tracked access
+//! is the intersection of all flags that have been set.
+//!
+//! - The raw keyslot setters of the [`SecurityEngine`] are private to the
+//! crate, so keys, IVs and flags can only be modified through the manager.
+//! This includes restoring a saved [`context`], which skips locked keyslots
+//! and those owned by the Boot ROM. The only exception are the IVs which
+//! the AES-CBC and AES-CMAC operations load into their keyslot.
+//!
+//! - A keyslot whose `KEY_UPDATE` access has been revoked is considered
+//! locked. Keys and IVs of locked keyslots can neither be set nor cleared.
+//! The same applies to the SBK and SSK keyslots, which are owned by the
+//! Boot ROM. The TSEC keyslot may be written by its owner.
+//!
+//! [`KeyslotManager`]: struct.KeyslotManager.html
+//! [`SecurityEngine`]: ../struct.SecurityEngine.html
+//! [`sbk`]: struct.KeyslotManager.html#method.sbk
+//! [`ssk`]: struct.KeyslotManager.html#method.ssk
+//! [`tsec`]: struct.KeyslotManager.html#method.tsec
+//! [`keygen`]: ../../keygen/index.html
+//! [`context`]: ../context/index.html
+//! [`allocate_aes`]: struct.KeyslotManager.html#method.allocate_aes
+//! [`allocate_rsa`]: struct.KeyslotManager.html#method.allocate_rsa
+
+use super::*;
+
+/// The keyslot used by the key derivation for intermediate keys.
+pub const KEYSLOT_KEYGEN_TEMP: usize = 0x9;
+/// The keyslot holding the master key after key derivation.
+pub const KEYSLOT_MASTER_KEY: usize = 0xA;
+/// The keyslot holding the keyblob key after key derivation.
+pub const KEYSLOT_KEYBLOB_KEY: usize = 0xB;
+/// The keyslot holding the TSEC key.
+pub const KEYSLOT_TSEC: usize = 0xC;
+/// The keyslot holding the device key after key derivation.
+pub const KEYSLOT_DEVICE_KEY: usize = 0xD;
+/// The keyslot holding the Secure Boot Key.
+pub const KEYSLOT_SBK: usize = 0xE;
+/// The keyslot holding the Secure Storage Key.
+pub const KEYSLOT_SSK: usize = 0xF;
+
+/// Allows the key of an AES keyslot to be read.
+pub const AES_ACCESS_KEY_READ: u32 = 1 << 0;
+/// Allows the key of an AES keyslot to be updated.
+pub const AES_ACCESS_KEY_UPDATE: u32 = 1 << 1;
+/// Allows the original IV of an AES keyslot to be read.
+pub const AES_ACCESS_ORIGINAL_IV_READ: u32 = 1 << 2;
+/// Allows the original IV of an AES keyslot to be updated.
+pub const AES_ACCESS_ORIGINAL_IV_UPDATE: u32 = 1 << 3;
+/// Allows the updated IV of an AES keyslot to be read.
+pub const AES_ACCESS_UPDATED_IV_READ: u32 = 1 << 4;
+/// Allows the updated IV of an AES keyslot to be updated.
+pub const AES_ACCESS_UPDATED_IV_UPDATE: u32 = 1 << 5;
+/// Allows the key of an AES keyslot to be used.
+pub const AES_ACCESS_KEY_USE: u32 = 1 << 6;
+/// All access bits of an AES keyslot.
+pub const AES_ACCESS_ALL: u32 = 0x7F;
+
+/// Revokes the permission to read the key of an RSA keyslot.
+pub const RSA_DISABLE_KEY_READ: u32 = 1 << 0;
+/// Revokes the permission to update the key of an RSA keyslot.
+pub const RSA_DISABLE_KEY_UPDATE: u32 = 1 << 1;
+/// Revokes the permission to use the key of an RSA keyslot.
+pub const RSA_DISABLE_KEY_USE: u32 = 1 << 6;
+
+/// Disables reads of a keyslot through the `KEY_READ_DISABLE` registers.
+pub const KEYSLOT_DISABLE_READ: u32 = 0x80;
+
+/// The access bits of an RSA keyslot.
+const RSA_ACCESS_KEY_READ: u32 = 1 << 0;
+const RSA_ACCESS_KEY_UPDATE: u32 = 1 << 1;
+const RSA_ACCESS_KEY_USE: u32 = 1 << 2;
+const RSA_ACCESS_ALL: u32 = 0x7;
+
+/// The owner of a keyslot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyslotOwner {
+    /// The keyslot is available for allocation.
+    Free,
+    /// The keyslot has been handed out by the manager.
+    Allocated,
+    /// The keyslot is reserved for the Secure Boot Key.
+    Sbk,
+    /// The keyslot is reserved for the Secure Storage Key.
+    Ssk,
+    /// The keyslot is reserved for the TSEC key.
+    Tsec,
+    /// The keyslot is reserved for the key derivation.
+    Keygen,
+}
+
+/// A handle to an AES keyslot.
+#[derive(Debug, PartialEq, Eq)]
+pub struct AesKeyslot(usize);
+
+impl AesKeyslot {
+    /// Gets the index of the keyslot, to be used with the SE operations.
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// A handle to an RSA keyslot.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RsaKeyslot(usize);
+
+impl RsaKeyslot {
+    /// Gets the index of the keyslot, to be used with the SE operations.
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// The tracked state of a keyslot.
+#[derive(Clone, Copy)]
+struct KeyslotState {
+    /// The owner of the keyslot.
+    owner: KeyslotOwner,
+    /// The access bits that have not been revoked yet.
+    access: u32,
+    /// Whether reads have been disabled through the `KEY_READ_DISABLE` registers.
+    read_disabled: bool,
+}
+
+impl KeyslotState {
+    /// Creates the state of a keyslot after reset.
+    const fn new(owner: KeyslotOwner, access: u32) -> Self {
+        KeyslotState {
+            owner,
+            access,
+            read_disabled: false,
+        }
+    }
+
+    /// Gets the flags of the keyslot, in the format of the access bits.
+    fn flags(&self) -> u32 {
+        if self.read_disabled {
+            self.access | KEYSLOT_DISABLE_READ
+        } else {
+            self.access
+        }
+    }
+}
+
+/// Keeps track of the ownership and the lock state of the SE keyslots.
+pub struct KeyslotManager {
+    /// The state of the AES keyslots.
+    aes: [KeyslotState; KEYSLOT_AES_MAX],
+    /// The state of the RSA keyslots.
+    rsa: [KeyslotState; KEYSLOT_RSA_MAX],
+}
+
+impl KeyslotManager {
+    /// Creates a new keyslot manager, assuming the keyslots are in their reset state.
+    pub fn new() -> Self {
+        let mut aes = [KeyslotState::new(KeyslotOwner::Free, AES_ACCESS_ALL); KEYSLOT_AES_MAX];
+
+        aes[KEYSLOT_TSEC] = KeyslotState::new(KeyslotOwner::Tsec, AES_ACCESS_ALL);
+        for &keyslot in &[
+            KEYSLOT_KEYGEN_TEMP,
+            KEYSLOT_MASTER_KEY,
+            KEYSLOT_KEYBLOB_KEY,
+            KEYSLOT_DEVICE_KEY,
+        ] {
+            aes[keyslot] = KeyslotState::new(KeyslotOwner::Keygen, AES_ACCESS_ALL);
+        }
+        aes[KEYSLOT_SBK] = KeyslotState::new(KeyslotOwner::Sbk, AES_ACCESS_ALL);
+        aes[KEYSLOT_SSK] = KeyslotState::new(KeyslotOwner::Ssk, AES_ACCESS_ALL);
+
+        KeyslotManager {
+            aes,
+            rsa: [KeyslotState::new(KeyslotOwner::Free, RSA_ACCESS_ALL); KEYSLOT_RSA_MAX],
+        }
+    }
+
+    /// Gets a handle to the keyslot of the Secure Boot Key.
+    pub fn sbk(&self) -> AesKeyslot {
+        AesKeyslot(KEYSLOT_SBK)
+    }
+
+    /// Gets a handle to the keyslot of the Secure Storage Key.
+    pub fn ssk(&self) -> AesKeyslot {
+        AesKeyslot(KEYSLOT_SSK)
+    }
+
+    /// Gets a handle to the keyslot of the TSEC key.
+    pub fn tsec(&self) -> AesKeyslot {
+        AesKeyslot(KEYSLOT_TSEC)
+    }
+
+    /// Gets a handle to the keyslot of the derived master key.
+    pub fn master_key(&self) -> AesKeyslot {
+        AesKeyslot(KEYSLOT_MASTER_KEY)
+    }
+
+    /// Gets a handle to the keyslot of the derived keyblob key.
+    pub fn keyblob_key(&self) -> AesKeyslot {
+        AesKeyslot(KEYSLOT_KEYBLOB_KEY)
+    }
+
+    /// Gets a handle to the keyslot of the derived device key.
+    pub fn device_key(&self) -> AesKeyslot {
+        AesKeyslot(KEYSLOT_DEVICE_KEY)
+    }
+
+    /// Gets the owner of an AES keyslot.
+    pub fn aes_owner(&self, keyslot: usize) -> Result<KeyslotOwner, Error> {
+        self.aes
+            .get(keyslot)
+            .map(|state| state.owner)
+            .ok_or(Error::InvalidKeyslot(keyslot))
+    }
+
+    /// Gets the owner of an RSA keyslot.
+    pub fn rsa_owner(&self, keyslot: usize) -> Result<KeyslotOwner, Error> {
+        self.rsa
+            .get(keyslot)
+            .map(|state| state.owner)
+            .ok_or(Error::InvalidKeyslot(keyslot))
+    }
+
+    /// Gets a handle to an AES keyslot that is in use, i.e. reserved or allocated.
+    ///
+    /// This allows backends that address keyslots by index, such as the
+    /// [`SeCrypto`], to route their operations through the manager.
+    ///
+    /// [`SeCrypto`]: ../../crypto/se/struct.SeCrypto.html
+    pub(crate) fn aes_keyslot(&self, keyslot: usize) -> Result<AesKeyslot, Error> {
+        match self.aes_owner(keyslot)? {
+            KeyslotOwner::Free => Err(Error::KeyslotNotAllocated(keyslot)),
+            _ => Ok(AesKeyslot(keyslot)),
+        }
+    }
+
+    /// Gets a handle to an RSA keyslot that has been allocated.
+    pub(crate) fn rsa_keyslot(&self, keyslot: usize) -> Result<RsaKeyslot, Error> {
+        match self.rsa_owner(keyslot)? {
+            KeyslotOwner::Free => Err(Error::KeyslotNotAllocated(keyslot)),
+            _ => Ok(RsaKeyslot(keyslot)),
+        }
+    }
+
+    /// Allocates a free AES keyslot.
+    pub fn allocate_aes(&mut self) -> Result<AesKeyslot, Error> {
+        let keyslot = self
+            .aes
+            .iter()
+            .position(|state| state.owner == KeyslotOwner::Free)
+            .ok_or(Error::NoFreeKeyslot)?;
+
+        self.aes[keyslot].owner = KeyslotOwner::Allocated;
+
+        Ok(AesKeyslot(keyslot))
+    }
+
+    /// Allocates a free RSA keyslot.
+    pub fn allocate_rsa(&mut self) -> Result<RsaKeyslot, Error> {
+        let keyslot = self
+            .rsa
+            .iter()
+            .position(|state| state.owner == KeyslotOwner::Free)
+            .ok_or(Error::NoFreeKeyslot)?;
+
+        self.rsa[keyslot].owner = KeyslotOwner::Allocated;
+
+        Ok(RsaKeyslot(keyslot))
+    }
+
+    /// Clears an allocated AES keyslot and makes it available again.
+    ///
+    /// Reserved keyslots can't be released. Locked keyslots can't be cleared
+    /// and therefore stay allocated until the next reset.
+    pub fn release_aes(
+        &mut self,
+        engine: &SecurityEngine,
+        keyslot: AesKeyslot,
+    ) -> Result<(), Error> {
+        if self.aes[keyslot.0].owner != KeyslotOwner::Allocated {
+            return Err(Error::KeyslotReserved(keyslot.0));
+        }
+
+        self.clear_aes(engine, &keyslot)?;
+        self.aes[keyslot.0].owner = KeyslotOwner::Free;
+
+        Ok(())
+    }
+
+    /// Clears an allocated RSA keyslot and makes it available again.
+    ///
+    /// Locked keyslots can't be cleared and therefore stay allocated until
+    /// the next reset.
+    pub fn release_rsa(
+        &mut self,
+        engine: &mut SecurityEngine,
+        keyslot: RsaKeyslot,
+    ) -> Result<(), Error> {
+        if self.rsa[keyslot.0].owner != KeyslotOwner::Allocated {
+            return Err(Error::KeyslotNotAllocated(keyslot.0));
+        }
+
+        self.clear_rsa(engine, &keyslot)?;
+        self.rsa[keyslot.0].owner = KeyslotOwner::Free;
+
+        Ok(())
+    }
+
+    /// Gets the flags of an AES keyslot.
+    ///
+    /// These are the remaining `AES_ACCESS_*` bits, combined with
+    /// `KEYSLOT_DISABLE_READ` if reads have been disabled.
+    pub fn aes_flags(&self, keyslot: &AesKeyslot) -> u32 {
+        self.aes[keyslot.0].flags()
+    }
+
+    /// Gets the flags of an RSA keyslot.
+    ///
+    /// These are the remaining access bits for key reads, updates and usage,
+    /// combined with `KEYSLOT_DISABLE_READ` if reads have been disabled.
+    pub fn rsa_flags(&self, keyslot: &RsaKeyslot) -> u32 {
+        self.rsa[keyslot.0].flags()
+    }
+
+    /// Checks whether the key of an AES keyslot can no longer be updated.
+    pub fn is_aes_locked(&self, keyslot: &AesKeyslot) -> bool {
+        self.aes[keyslot.0].access & AES_ACCESS_KEY_UPDATE == 0
+    }
+
+    /// Checks whether the key of an RSA keyslot can no longer be updated.
+    pub fn is_rsa_locked(&self, keyslot: &RsaKeyslot) -> bool {
+        self.rsa[keyslot.0].access & RSA_ACCESS_KEY_UPDATE == 0
+    }
+
+    /// Fails if the key of an AES keyslot must not be overwritten.
+    fn check_aes_writable(&self, keyslot: &AesKeyslot, access: u32) -> Result<(), Error> {
+        match self.aes[keyslot.0].owner {
+            KeyslotOwner::Sbk | KeyslotOwner::Ssk => Err(Error::KeyslotReserved(keyslot.0)),
+            _ => self.check_aes_access(keyslot, access),
+        }
+    }
+
+    /// Fails if the given access bits of an AES keyslot have been revoked.
+    fn check_aes_access(&self, keyslot: &AesKeyslot, access: u32) -> Result<(), Error> {
+        if self.aes[keyslot.0].access & access != access {
+            return Err(Error::KeyslotLocked(keyslot.0));
+        }
+
+        Ok(())
+    }
+
+    /// Fails if the given access bits of an RSA keyslot have been revoked.
+    fn check_rsa_access(&self, keyslot: &RsaKeyslot, access: u32) -> Result<(), Error> {
+        if self.rsa[keyslot.0].access & access != access {
+            return Err(Error::KeyslotLocked(keyslot.0));
+        }
+
+        Ok(())
+    }
+
+    /// Sets the key of an AES keyslot, unless the keyslot is locked or owned by the Boot ROM.
+    pub fn set_aes_key(
+        &self,
+        engine: &SecurityEngine,
+        keyslot: &AesKeyslot,
+        key: &[u8],
+    ) -> Result<(), Error> {
+        self.check_aes_writable(keyslot, AES_ACCESS_KEY_UPDATE)?;
+
+        engine.set_aes_keyslot(keyslot.0, key)
+    }
+
+    /// Sets the original IV of an AES keyslot, unless it is locked or owned by the Boot ROM.
+    pub fn set_aes_iv(
+        &self,
+        engine: &SecurityEngine,
+        keyslot: &AesKeyslot,
+        iv: &[u8],
+    ) -> Result<(), Error> {
+        self.check_aes_writable(keyslot, AES_ACCESS_ORIGINAL_IV_UPDATE)?;
+
+        engine.set_aes_keyslot_iv(keyslot.0, iv)
+    }
+
+    /// Clears the key and the IVs of an AES keyslot, unless it is locked or owned by the Boot ROM.
+    pub fn clear_aes(&self, engine: &SecurityEngine, keyslot: &AesKeyslot) -> Result<(), Error> {
+        self.check_aes_writable(
+            keyslot,
+            AES_ACCESS_KEY_UPDATE | AES_ACCESS_ORIGINAL_IV_UPDATE | AES_ACCESS_UPDATED_IV_UPDATE,
+        )?;
+
+        engine.clear_aes_keyslot(keyslot.0)
+    }
+
+    /// Decrypts a wrapped key with the key in `source` into `destination`.
+    ///
+    /// Fails if `destination` is locked or owned by the Boot ROM, or if the key
+    /// in `source` can't be used.
+    pub fn unwrap_aes_key(
+        &self,
+        engine: &SecurityEngine,
+        destination: &AesKeyslot,
+        source: &AesKeyslot,
+        wrapped_key: &[u8],
+    ) -> Result<(), Error> {
+        self.check_aes_writable(destination, AES_ACCESS_KEY_UPDATE)?;
+        self.check_aes_access(source, AES_ACCESS_KEY_USE)?;
+
+        engine.decrypt_data_into_keyslot(destination.0, source.0, wrapped_key)
+    }
+
+    /// Sets the key of an RSA keyslot, unless the keyslot is locked.
+    pub fn set_rsa_key(
+        &self,
+        engine: &mut SecurityEngine,
+        keyslot: &RsaKeyslot,
+        modulus: &[u8],
+        exponent: &[u8],
+    ) -> Result<(), Error> {
+        self.check_rsa_access(keyslot, RSA_ACCESS_KEY_UPDATE)?;
+
+        engine.set_rsa_keyslot(keyslot.0, modulus, exponent)
+    }
+
+    /// Clears an RSA keyslot, unless the keyslot is locked.
+    pub fn clear_rsa(
+        &self,
+        engine: &mut SecurityEngine,
+        keyslot: &RsaKeyslot,
+    ) -> Result<(), Error> {
+        self.check_rsa_access(keyslot, RSA_ACCESS_KEY_UPDATE)?;
+
+        engine.clear_rsa_keyslot(keyslot.0)
+    }
+
+    /// Restores the key and the IVs of an AES keyslot from a saved context.
+    ///
+    /// A free keyslot is allocated for the restored key. Keyslots that are
+    /// locked or owned by the Boot ROM are left untouched, in which case
+    /// `None` is returned.
+    pub(crate) fn restore_aes(
+        &mut self,
+        engine: &SecurityEngine,
+        keyslot: usize,
+        key: &[u8],
+        original_iv: &[u8],
+        updated_iv: &[u8],
+    ) -> Result<Option<AesKeyslot>, Error> {
+        self.aes_owner(keyslot)?;
+
+        let handle = AesKeyslot(keyslot);
+        if self
+            .check_aes_writable(
+                &handle,
+                AES_ACCESS_KEY_UPDATE
+                    | AES_ACCESS_ORIGINAL_IV_UPDATE
+                    | AES_ACCESS_UPDATED_IV_UPDATE,
+            )
+            .is_err()
+        {
+            return Ok(None);
+        }
+
+        engine.set_aes_keyslot(keyslot, key)?;
+        engine.set_aes_keyslot_iv(keyslot, original_iv)?;
+        engine.set_aes_keyslot_updated_iv(keyslot, updated_iv)?;
+
+        if self.aes[keyslot].owner == KeyslotOwner::Free {
+            self.aes[keyslot].owner = KeyslotOwner::Allocated;
+        }
+
+        Ok(Some(handle))
+    }
+
+    /// Restores the key of an RSA keyslot from a saved context.
+    ///
+    /// A free keyslot is allocated for the restored key. Locked keyslots are
+    /// left untouched, in which case `None` is returned.
+    pub(crate) fn restore_rsa(
+        &mut self,
+        engine: &mut SecurityEngine,
+        keyslot: usize,
+        modulus: &[u8],
+        exponent: &[u8],
+    ) -> Result<Option<RsaKeyslot>, Error> {
+        self.rsa_owner(keyslot)?;
+
+        let handle = RsaKeyslot(keyslot);
+        if self
+            .check_rsa_access(&handle, RSA_ACCESS_KEY_UPDATE)
+            .is_err()
+        {
+            return Ok(None);
+        }
+
+        engine.set_rsa_keyslot(keyslot, modulus, exponent)?;
+
+        if self.rsa[keyslot].owner == KeyslotOwner::Free {
+            self.rsa[keyslot].owner = KeyslotOwner::Allocated;
+        }
+
+        Ok(Some(handle))
+    }
+
+    /// Sets the flags of an AES keyslot and records them.
+    ///
+    /// `flags` is a combination of the `AES_ACCESS_*` bits to keep and
+    /// `KEYSLOT_DISABLE_READ`.
+    pub fn set_aes_flags(
+        &mut self,
+        engine: &SecurityEngine,
+        keyslot: &AesKeyslot,
+        flags: u32,
+    ) -> Result<(), Error> {
+        engine.set_aes_keyslot_flags(keyslot.0, flags)?;
+        self.record_aes_flags(keyslot, flags);
+
+        Ok(())
+    }
+
+    /// Records the flags that have been set for an AES keyslot.
+    fn record_aes_flags(&mut self, keyslot: &AesKeyslot, flags: u32) {
+        let state = &mut self.aes[keyslot.0];
+        if flags & !KEYSLOT_DISABLE_READ != 0 {
+            state.access &= flags;
+        }
+        if flags & KEYSLOT_DISABLE_READ != 0 {
+            state.read_disabled = true;
+        }
+    }
+
+    /// Sets the flags of an RSA keyslot and records them.
+    ///
+    /// `flags` is a combination of the `RSA_DISABLE_*` bits and
+    /// `KEYSLOT_DISABLE_READ`.
+    pub fn set_rsa_flags(
+        &mut self,
+        engine: &SecurityEngine,
+        keyslot: &RsaKeyslot,
+        flags: u32,
+    ) -> Result<(), Error> {
+        engine.set_rsa_keyslot_flags(keyslot.0, flags)?;
+        self.record_rsa_flags(keyslot, flags);
+
+        Ok(())
+    }
+
+    /// Records the flags that have been set for an RSA keyslot.
+    fn record_rsa_flags(&mut self, keyslot: &RsaKeyslot, flags: u32) {
+        let state = &mut self.rsa[keyslot.0];
+        if flags & !KEYSLOT_DISABLE_READ != 0 {
+            let mut revoked = 0;
+            if flags & RSA_DISABLE_KEY_READ != 0 {
+                revoked |= RSA_ACCESS_KEY_READ;
+            }
+            if flags & RSA_DISABLE_KEY_UPDATE != 0 {
+                revoked |= RSA_ACCESS_KEY_UPDATE;
+            }
+            if flags & RSA_DISABLE_KEY_USE != 0 {
+                revoked |= RSA_ACCESS_KEY_USE;
+            }
+
+            state.access &= !revoked;
+        }
+        if flags & KEYSLOT_DISABLE_READ != 0 {
+            state.read_disabled = true;
+        }
+    }
+
+    /// Locks the SBK from being read.
+    pub fn lock_sbk(&mut self, engine: &SecurityEngine) -> Result<(), Error> {
+        let sbk = self.sbk();
+
+        self.set_aes_flags(engine, &sbk, AES_ACCESS_ALL & !AES_ACCESS_KEY_READ)
+    }
+
+    /// Locks the SSK from being read.
+    pub fn lock_ssk(&mut self, engine: &SecurityEngine) -> Result<(), Error> {
+        let ssk = self.ssk();
+
+        self.set_aes_flags(engine, &ssk, AES_ACCESS_ALL & !AES_ACCESS_KEY_READ)
+    }
+}
+
+impl Default for KeyslotManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests that reserved keyslots are never handed out.
+    #[test]
+    fn reserved_keyslots() {
+        let mut manager = KeyslotManager::new();
+
+        assert_eq!(manager.aes_owner(KEYSLOT_SBK), Ok(KeyslotOwner::Sbk));
+        assert_eq!(manager.aes_owner(KEYSLOT_SSK), Ok(KeyslotOwner::Ssk));
+        assert_eq!(manager.aes_owner(KEYSLOT_TSEC), Ok(KeyslotOwner::Tsec));
+        assert_eq!(
+            manager.aes_owner(KEYSLOT_MASTER_KEY),
+            Ok(KeyslotOwner::Keygen)
+        );
+        assert_eq!(
+            manager.aes_owner(KEYSLOT_AES_MAX),
+            Err(Error::InvalidKeyslot(KEYSLOT_AES_MAX))
+        );
+
+        let mut allocated = 0;
+        while let Ok(keyslot) = manager.allocate_aes() {
+            assert_eq!(
+                manager.aes_owner(keyslot.index()),
+                Ok(KeyslotOwner::Allocated)
+            );
+            allocated += 1;
+        }
+        assert_eq!(allocated, KEYSLOT_AES_MAX - 7);
+
+        assert_eq!(manager.aes_owner(KEYSLOT_SBK), Ok(KeyslotOwner::Sbk));
+        assert_eq!(manager.aes_owner(KEYSLOT_SSK), Ok(KeyslotOwner::Ssk));
+        assert_eq!(manager.aes_owner(KEYSLOT_TSEC), Ok(KeyslotOwner::Tsec));
+
+        let sbk = manager.sbk();
+        assert_eq!(
+            manager.check_aes_writable(&sbk, AES_ACCESS_KEY_UPDATE),
+            Err(Error::KeyslotReserved(KEYSLOT_SBK))
+        );
+        let tsec = manager.tsec();
+        assert_eq!(
+            manager.check_aes_writable(&tsec, AES_ACCESS_KEY_UPDATE),
+            Ok(())
+        );
+    }
+
+    /// Tests that no keyslot is allocated twice and that released keyslots are reused.
+    #[test]
+    fn allocate_and_release() {
+        let mut manager = KeyslotManager::new();
+        let mut allocated = 0u32;
+
+        while let Ok(keyslot) = manager.allocate_aes() {
+            assert_eq!(allocated & (1 << keyslot.index()), 0);
+            allocated |= 1 << keyslot.index();
+        }
+        assert_eq!(manager.allocate_aes(), Err(Error::NoFreeKeyslot));
+
+        // Mirrors `release_aes`, without clearing the hardware keyslot.
+        manager.aes[3].owner = KeyslotOwner::Free;
+        assert_eq!(manager.allocate_aes().map(|keyslot| keyslot.index()), Ok(3));
+
+        let first = manager.allocate_rsa().unwrap();
+        let second = manager.allocate_rsa().unwrap();
+        assert_ne!(first, second);
+        assert_eq!(manager.allocate_rsa(), Err(Error::NoFreeKeyslot));
+    }
+
+    /// Tests that handles are only created for keyslots which are in use.
+    #[test]
+    fn handles_by_index() {
+        let mut manager = KeyslotManager::new();
+
+        assert_eq!(manager.aes_keyslot(0), Err(Error::KeyslotNotAllocated(0)));
+        assert_eq!(
+            manager.aes_keyslot(KEYSLOT_SBK),
+            Ok(AesKeyslot(KEYSLOT_SBK))
+        );
+
+        let keyslot = manager.allocate_aes().unwrap();
+        assert_eq!(manager.aes_keyslot(keyslot.index()), Ok(keyslot));
+
+        assert_eq!(manager.rsa_keyslot(0), Err(Error::KeyslotNotAllocated(0)));
+        assert_eq!(
+            manager.rsa_keyslot(KEYSLOT_RSA_MAX),
+            Err(Error::InvalidKeyslot(KEYSLOT_RSA_MAX))
+        );
+    }
+
+    /// Tests that revoked access bits lock keyslots against updates.
+    #[test]
+    fn lock_and_read_flags() {
+        let mut manager = KeyslotManager::new();
+        let keyslot = manager.allocate_aes().unwrap();
+
+        assert_eq!(manager.aes_flags(&keyslot), AES_ACCESS_ALL);
+        assert!(!manager.is_aes_locked(&keyslot));
+
+        manager.record_aes_flags(&keyslot, KEYSLOT_DISABLE_READ);
+        assert_eq!(
+            manager.aes_flags(&keyslot),
+            AES_ACCESS_ALL | KEYSLOT_DISABLE_READ
+        );
+        assert_eq!(
+            manager.check_aes_writable(&keyslot, AES_ACCESS_KEY_UPDATE),
+            Ok(())
+        );
+
+        manager.record_aes_flags(&keyslot, AES_ACCESS_ALL & !AES_ACCESS_KEY_UPDATE);
+        assert!(manager.is_aes_locked(&keyslot));
+        assert_eq!(
+            manager.check_aes_writable(&keyslot, AES_ACCESS_KEY_UPDATE),
+            Err(Error::KeyslotLocked(keyslot.index()))
+        );
+
+        // Access bits can't be regained until the next reset.
+        manager.record_aes_flags(&keyslot, AES_ACCESS_ALL);
+        assert!(manager.is_aes_locked(&keyslot));
+
+        let keyslot = manager.allocate_rsa().unwrap();
+        manager.record_rsa_flags(&keyslot, RSA_DISABLE_KEY_READ | RSA_DISABLE_KEY_UPDATE);
+        assert!(manager.is_rsa_locked(&keyslot));
+        assert_eq!(manager.rsa_flags(&keyslot), RSA_ACCESS_KEY_USE);
+        assert_eq!(
+            manager.check_rsa_access(&keyslot, RSA_ACCESS_KEY_USE),
+            Ok(())
+        );
+        assert_eq!(
+            manager.check_rsa_access(&keyslot, RSA_ACCESS_KEY_UPDATE),
+            Err(Error::KeyslotLocked(keyslot.index()))
+        );
+    }
+}
@@ -1,17 +1,62 @@
 //! Tegra210 Security Engine driver.
+//!
+//! # Implementation
+//!
+//! - The [`SecurityEngine`] struct provides the keyslot management and the
+//! low-level operation handling of the SE.
+//!
+//! - Higher-level operations are implemented in submodules on top of it,
+//! e.g. the AES modes in [`aes`], AES-CMAC in [`cmac`], the hash functions
+//! in [`sha`], the RSA signature verification in [`rsa`] and the random
+//! number generator in [`rng`].
+//!
+//! - The ownership and lock state of the keyslots is tracked by the
+//! [`KeyslotManager`], which hands out typed keyslot handles. Keys, IVs
+//! and flags can only be modified through the manager.
+//!
+//! - The state of the SE can be saved to and restored from memory through
+//! the functions in [`context`].
+//!
+//! - Data is transferred from and to the SE through linked lists which
+//! describe the memory buffers to be used for DMA.
+//!
+//! - Operations never panic. Invalid arguments as well as errors reported by
+//! the hardware are returned as an [`Error`].
+//!
+//! [`SecurityEngine`]: struct.SecurityEngine.html
+//! [`Error`]: enum.Error.html
+//! [`KeyslotManager`]: keyslot/struct.KeyslotManager.html
+//! [`aes`]: aes/index.html
+//! [`cmac`]: cmac/index.html
+//! [`sha`]: sha/index.html
+//! [`rsa`]: rsa/index.html
+//! [`rng`]: rng/index.html
+//! [`context`]: context/index.html
 
 use core::convert::{TryFrom, TryInto};
 
 use mirage_mmio::{Mmio, VolatileStorage};
 
+pub mod aes;
+pub mod cmac;
+pub mod context;
+pub mod keyslot;
+pub mod rng;
+pub mod rsa;
+pub mod sha;
+
 /// Base address for SE registers.
 pub(crate) const SE_BASE: u32 = 0x7001_2000;
 
-const KEYSLOT_AES_MAX: usize = 0x10;
-const KEYSLOT_RSA_MAX: usize = 0x2;
+/// The number of AES keyslots.
+pub const KEYSLOT_AES_MAX: usize = 0x10;
+/// The number of RSA keyslots.
+pub const KEYSLOT_RSA_MAX: usize = 0x2;
 
-const KEYSIZE_AES_MAX: usize = 0x20;
-const KEYSIZE_RSA_MAX: usize = 0x100;
+/// The maximum size of an AES key.
+pub const KEYSIZE_AES_MAX: usize = 0x20;
+/// The maximum size of an RSA modulus or exponent.
+pub const KEYSIZE_RSA_MAX: usize = 0x100;
 
 pub const OP_ABORT: u32 = 0;
 pub const OP_START: u32 = 1;
@@ -19,6 +64,41 @@ pub const OP_RESTART: u32 = 2;
 pub const OP_CTX_SAVE: u32 = 3;
 pub const OP_RESTART_IN: u32 = 4;
 
+/// The size of an AES block.
+pub const AES_BLOCK_SIZE: usize = 0x10;
+
+/// The maximum number of entries in a SE LL.
+const LL_MAX_ENTRIES: usize = 4;
+
+/// The maximum size of a buffer described by a single LL entry.
+const LL_MAX_ENTRY_SIZE: usize = 0x10_0000;
+
+/// The maximum amount of data that can be transferred in a single operation.
+pub(crate) const MAX_OPERATION_SIZE: usize = LL_MAX_ENTRIES * LL_MAX_ENTRY_SIZE;
+
+pub(crate) const ALG_AES: u32 = 1;
+pub(crate) const ALG_RNG: u32 = 2;
+pub(crate) const ALG_SHA: u32 = 3;
+pub(crate) const ALG_RSA: u32 = 4;
+
+pub(crate) const DST_MEMORY: u32 = 0;
+pub(crate) const DST_HASH_REG: u32 = 1;
+pub(crate) const DST_KEYTABLE: u32 = 2;
+pub(crate) const DST_SRK: u32 = 3;
+pub(crate) const DST_RSA_REG: u32 = 4;
+
+/// Builds the value of the `CONFIG_REG` for an encryption operation.
+#[inline]
+pub(crate) const fn config_encrypt(algorithm: u32, mode: u32, destination: u32) -> u32 {
+    (mode << 24) | (algorithm << 12) | (destination << 2)
+}
+
+/// Builds the value of the `CONFIG_REG` for a decryption operation.
+#[inline]
+pub(crate) const fn config_decrypt(algorithm: u32, mode: u32, destination: u32) -> u32 {
+    (mode << 16) | (algorithm << 8) | (destination << 2)
+}
+
 /// Representation of the SE registers.
 #[allow(non_snake_case)]
 #[repr(C)]
@@ -35,19 +115,12 @@ struct Registers {
     pub OUT_LL_ADDR_REG: Mmio<u32>,
     _0x28: Mmio<u32>,
     _0x2C: Mmio<u32>,
-    pub HASH_RESULT_REG: [Mmio<u8>; 0x20],
-    _0x50: [Mmio<u8>; 0x20],
+    pub HASH_RESULT_REG: [Mmio<u32>; 0x10],
     pub CONTEXT_SAVE_CONFIG_REG: Mmio<u32>,
     _0x74: [Mmio<u8>; 0x18C],
     pub SHA_CONFIG_REG: Mmio<u32>,
-    pub SHA_MSG_LENGTH_REG: Mmio<u32>,
-    _0x208: Mmio<u32>,
-    _0x20C: Mmio<u32>,
-    _0x210: Mmio<u32>,
-    pub SHA_MSG_LEFT_REG: Mmio<u32>,
-    _0x218: Mmio<u32>,
-    _0x21C: Mmio<u32>,
-    _0x220: Mmio<u32>,
+    pub SHA_MSG_LENGTH_REG: [Mmio<u32>; 4],
+    pub SHA_MSG_LEFT_REG: [Mmio<u32>; 4],
     _0x224: Mmio<u32>,
     _0x228: [Mmio<u8>; 0x58],
     pub AES_KEY_READ_DISABLE_REG: Mmio<u32>,
@@ -77,7 +150,7 @@ struct Registers {
     _0x41C: Mmio<u32>,
     pub RSA_KEYTABLE_ADDR: Mmio<u32>,
     pub RSA_KEYTABLE_DATA: Mmio<u32>,
-    pub RSA_OUTPUT: [Mmio<u8>; 0x100],
+    pub RSA_OUTPUT: [Mmio<u32>; 0x40],
     _0x528: [Mmio<u8>; 0x2D8],
     pub FLAGS_REG: Mmio<u32>,
     pub ERR_STATUS_REG: Mmio<u32>,
@@ -96,6 +169,39 @@ impl VolatileStorage for Registers {
     }
 }
 
+/// Enumeration of possible errors of Security Engine operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The keyslot doesn't exist.
+    InvalidKeyslot(usize),
+    /// The key, IV or counter is too large for the keyslot.
+    KeyTooLarge,
+    /// No key has been set in the keyslot.
+    KeyNotSet,
+    /// The key has a size that is unsupported by the operation.
+    InvalidKeySize,
+    /// The size of a buffer is invalid for the operation.
+    InvalidLength,
+    /// A buffer is located outside of the memory addressable by the SE.
+    InvalidAddress,
+    /// No free keyslot is left for allocation.
+    NoFreeKeyslot,
+    /// The keyslot is reserved and must not be modified or released.
+    KeyslotReserved(usize),
+    /// The keyslot has been locked against the operation.
+    KeyslotLocked(usize),
+    /// The keyslot has not been handed out by the keyslot manager.
+    KeyslotNotAllocated(usize),
+    /// A saved context could not be verified.
+    InvalidContext,
+    /// The `ERR_STATUS_REG` reported an error.
+    ErrorStatus(u32),
+    /// The SE is still busy according to the `FLAGS_REG`.
+    Busy(u32),
+    /// A DMA error was reported in the `INT_STATUS_REG`.
+    DmaError(u32),
+}
+
 /// Representation of the Security Engine.
 pub struct SecurityEngine {
     /// A buffer to keep track of the modulus sizes for RSA keyslots.
@@ -104,44 +210,77 @@ pub struct SecurityEngine {
     exponent_sizes: [usize; KEYSLOT_RSA_MAX],
 }
 
-/// Simplified version of a SE LL.
+/// An entry of a SE LL, describing a single buffer.
+#[derive(Clone, Copy, Default)]
 #[repr(C)]
-struct Ll {
-    /// The number of entries. Should always be set to 0, i.e. 1 entry.
-    pub entries: u32,
+struct LlEntry {
     /// The address of the buffer to be used.
     pub address: u32,
     /// The size of the buffer.
     pub size: u32,
 }
 
+/// Representation of a SE LL.
+#[repr(C)]
+struct Ll {
+    /// The number of entries, minus one.
+    pub entries: u32,
+    /// The buffers to be used.
+    pub buffers: [LlEntry; LL_MAX_ENTRIES],
+}
+
 impl Ll {
-    /// Creates a new LL object.
-    pub fn new(buffer: &mut [u8]) -> Self {
-        Ll {
+    /// Creates a new LL object describing a buffer.
+    ///
+    /// Buffers larger than a single entry can hold are split
+    /// across multiple entries.
+    pub fn new(buffer: &[u8]) -> Result<Self, Error> {
+        let mut ll = Ll {
             entries: 0,
-            address: u32::try_from(buffer.as_mut_ptr() as usize).expect("Value must fit an u32."),
-            size: buffer.len() as u32,
+            buffers: [LlEntry::default(); LL_MAX_ENTRIES],
+        };
+
+        let base = u32::try_from(buffer.as_ptr() as usize).map_err(|_| Error::InvalidAddress)?;
+
+        if buffer.len() > MAX_OPERATION_SIZE {
+            return Err(Error::InvalidLength);
+        }
+
+        for (i, chunk) in buffer.chunks(LL_MAX_ENTRY_SIZE).enumerate() {
+            let offset = u32::try_from(i * LL_MAX_ENTRY_SIZE).map_err(|_| Error::InvalidLength)?;
+            let size = u32::try_from(chunk.len()).map_err(|_| Error::InvalidLength)?;
+
+            // Every chunk must lie within the 32-bit address space.
+            let address = base.checked_add(offset).ok_or(Error::InvalidAddress)?;
+            address.checked_add(size - 1).ok_or(Error::InvalidAddress)?;
+
+            ll.entries = i as u32;
+            ll.buffers[i] = LlEntry { address, size };
         }
+
+        Ok(ll)
     }
 }
 
-// TODO(Vale): How to design the panic handler in favor of thrown panics?
-
 impl SecurityEngine {
-    fn trigger_blocking_operation(&self, op: u32, destination: &mut [u8], source: &mut [u8]) {
+    fn trigger_blocking_operation(
+        &self,
+        op: u32,
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
         let register_base = unsafe { Registers::get() };
 
         // Create and set the LLs.
-        let mut in_ll = Ll::new(source);
-        let mut out_ll = Ll::new(destination);
+        let in_ll = Ll::new(source)?;
+        let out_ll = Ll::new(destination)?;
 
         register_base
             .IN_LL_ADDR_REG
-            .write(&mut in_ll as *mut _ as usize as u32);
+            .write(&in_ll as *const _ as usize as u32);
         register_base
             .OUT_LL_ADDR_REG
-            .write(&mut out_ll as *mut _ as usize as u32);
+            .write(&out_ll as *const _ as usize as u32);
 
         // Set registers for operation.
         register_base
@@ -156,7 +295,7 @@ impl SecurityEngine {
             // Wait.
         }
 
-        self.check_for_error();
+        self.check_for_error()
     }
 
     /// Creates a new Security Engine object.
@@ -167,22 +306,6 @@ impl SecurityEngine {
         }
     }
 
-    /// Locks the SBK from being read.
-    #[inline]
-    pub(crate) fn lock_sbk(&self) {
-        let register_base = unsafe { Registers::get() };
-
-        register_base.AES_KEYSLOT_FLAGS[0xE].write(0x7E);
-    }
-
-    /// Locks the SSK from being read.
-    #[inline]
-    pub(crate) fn lock_ssk(&self) {
-        let register_base = unsafe { Registers::get() };
-
-        register_base.AES_KEYSLOT_FLAGS[0xF].write(0x7E);
-    }
-
     /// Sets the `INT_STATUS_REG` to `0x1F`.
     #[inline]
     pub(crate) fn config_brom(&self) {
@@ -191,46 +314,55 @@ impl SecurityEngine {
         register_base.INT_STATUS_REG.write(0x1F);
     }
 
-    /// Checks the ERR_STATUS_REG and panics if the value isn't zero.
+    /// Checks the ERR_STATUS_REG and fails if the value isn't zero.
     #[inline(always)]
-    pub fn check_error_status_reg(&self) {
+    pub fn check_error_status_reg(&self) -> Result<(), Error> {
         let register_base = unsafe { Registers::get() };
 
-        if register_base.ERR_STATUS_REG.read() != 0 {
-            panic!();
+        let status = register_base.ERR_STATUS_REG.read();
+        if status != 0 {
+            return Err(Error::ErrorStatus(status));
         }
+
+        Ok(())
     }
 
-    /// Verifies that all flags are cleared and panics otherwise.
+    /// Verifies that all flags are cleared and fails otherwise.
     #[inline(always)]
-    pub fn verify_flags_cleared(&self) {
+    pub fn verify_flags_cleared(&self) -> Result<(), Error> {
         let register_base = unsafe { Registers::get() };
 
-        if register_base.FLAGS_REG.read() & 3 != 0 {
-            panic!();
+        let flags = register_base.FLAGS_REG.read();
+        if flags & 3 != 0 {
+            return Err(Error::Busy(flags));
         }
+
+        Ok(())
     }
 
-    /// Checks for general SE errors and panics in case there are any.
+    /// Checks for general SE errors and fails in case there are any.
     #[inline]
-    pub fn check_for_error(&self) {
+    pub fn check_for_error(&self) -> Result<(), Error> {
         let register_base = unsafe { Registers::get() };
 
-        self.check_error_status_reg();
+        self.check_error_status_reg()?;
 
-        self.verify_flags_cleared();
+        self.verify_flags_cleared()?;
 
-        if register_base.INT_STATUS_REG.read() & 0x10000 != 0 {
-            panic!();
+        let status = register_base.INT_STATUS_REG.read();
+        if status & 0x10000 != 0 {
+            return Err(Error::DmaError(status));
         }
+
+        Ok(())
     }
 
     /// Sets the flags for an AES keyslot.
-    pub fn set_aes_keyslot_flags(&self, keyslot: usize, flags: u32) {
+    pub(crate) fn set_aes_keyslot_flags(&self, keyslot: usize, flags: u32) -> Result<(), Error> {
         let register_base = unsafe { Registers::get() };
 
         if keyslot >= KEYSLOT_AES_MAX {
-            panic!();
+            return Err(Error::InvalidKeyslot(keyslot));
         }
 
         // Miscellaneous flags.
@@ -245,14 +377,16 @@ impl SecurityEngine {
                 .AES_KEY_READ_DISABLE_REG
                 .write(value & !(1 << keyslot as u32));
         }
+
+        Ok(())
     }
 
     /// Sets the flags for an RSA keyslot.
-    pub fn set_rsa_keyslot_flags(&self, keyslot: usize, flags: u32) {
+    pub(crate) fn set_rsa_keyslot_flags(&self, keyslot: usize, flags: u32) -> Result<(), Error> {
         let register_base = unsafe { Registers::get() };
 
         if keyslot >= KEYSLOT_RSA_MAX {
-            panic!();
+            return Err(Error::InvalidKeyslot(keyslot));
         }
 
         // Miscellaneous flags.
@@ -267,14 +401,16 @@ impl SecurityEngine {
                 .RSA_KEY_READ_DISABLE_REG
                 .write(value & !(1 << keyslot as u32));
         }
+
+        Ok(())
     }
 
     /// Clears an AES keyslot.
-    pub fn clear_aes_keyslot(&self, keyslot: usize) {
+    pub(crate) fn clear_aes_keyslot(&self, keyslot: usize) -> Result<(), Error> {
         let register_base = unsafe { Registers::get() };
 
         if keyslot >= KEYSLOT_AES_MAX {
-            panic!();
+            return Err(Error::InvalidKeyslot(keyslot));
         }
 
         // Zero out the whole keyslot and IV.
@@ -284,14 +420,16 @@ impl SecurityEngine {
                 .write(((keyslot << 4) | i) as u32);
             register_base.AES_KEYTABLE_DATA.write(0);
         }
+
+        Ok(())
     }
 
     /// Clears an RSA keyslot.
-    pub fn clear_rsa_keyslot(&self, keyslot: usize) {
+    pub(crate) fn clear_rsa_keyslot(&self, keyslot: usize) -> Result<(), Error> {
         let register_base = unsafe { Registers::get() };
 
         if keyslot >= KEYSLOT_RSA_MAX {
-            panic!();
+            return Err(Error::InvalidKeyslot(keyslot));
         }
 
         // Zero out the whole keyslot.
@@ -309,50 +447,68 @@ impl SecurityEngine {
                 .write(((keyslot << 7) | i) as u32);
             register_base.RSA_KEYTABLE_DATA.write(0);
         }
+
+        Ok(())
     }
 
     /// Sets an AES keyslot.
-    pub fn set_aes_keyslot(&self, keyslot: usize, key: &[u8]) {
+    pub(crate) fn set_aes_keyslot(&self, keyslot: usize, key: &[u8]) -> Result<(), Error> {
         let register_base = unsafe { Registers::get() };
 
         let keysize = key.len();
 
-        if keyslot >= KEYSLOT_AES_MAX || keysize > KEYSIZE_AES_MAX {
-            panic!();
+        if keyslot >= KEYSLOT_AES_MAX {
+            return Err(Error::InvalidKeyslot(keyslot));
+        }
+
+        if keysize > KEYSIZE_AES_MAX {
+            return Err(Error::KeyTooLarge);
         }
 
         for i in 0..keysize >> 2 {
             register_base
                 .AES_KEYTABLE_ADDR
                 .write(((keyslot << 4) | i) as u32);
-            register_base
-                .AES_KEYTABLE_DATA
-                .write(u32::from_le_bytes((&key[..4 * i]).try_into().unwrap()));
+            register_base.AES_KEYTABLE_DATA.write(u32::from_le_bytes(
+                (&key[4 * i..4 * i + 4]).try_into().unwrap(),
+            ));
         }
+
+        Ok(())
     }
 
     /// Sets an RSA keyslot.
-    pub fn set_rsa_keyslot(&mut self, keyslot: usize, modulus: &[u8], exponent: &[u8]) {
+    pub(crate) fn set_rsa_keyslot(
+        &mut self,
+        keyslot: usize,
+        modulus: &[u8],
+        exponent: &[u8],
+    ) -> Result<(), Error> {
         let register_base = unsafe { Registers::get() };
 
         let modulus_size = modulus.len();
         let exponent_size = exponent.len();
 
-        if keyslot >= KEYSLOT_RSA_MAX
-            || modulus_size > KEYSIZE_RSA_MAX
-            || exponent_size > KEYSIZE_RSA_MAX
-        {
-            panic!();
+        if keyslot >= KEYSLOT_RSA_MAX {
+            return Err(Error::InvalidKeyslot(keyslot));
+        }
+
+        if modulus_size > KEYSIZE_RSA_MAX || exponent_size > KEYSIZE_RSA_MAX {
+            return Err(Error::KeyTooLarge);
+        }
+
+        // The keytable is written in whole words.
+        if modulus_size % 4 != 0 || exponent_size % 4 != 0 {
+            return Err(Error::InvalidKeySize);
         }
 
         for i in 0..modulus_size >> 2 {
             register_base
                 .RSA_KEYTABLE_ADDR
                 .write(((keyslot << 7) | 0x40 | i) as u32);
+            let offset = (4 * (modulus_size >> 2)) - (4 * i) - 4;
             register_base.RSA_KEYTABLE_DATA.write(u32::from_be_bytes(
-                (&modulus[..(4 * (modulus_size >> 2)) - (4 * i) - 4])
-                    .try_into()
-                    .unwrap(),
+                (&modulus[offset..offset + 4]).try_into().unwrap(),
             ));
         }
 
@@ -360,43 +516,80 @@ impl SecurityEngine {
             register_base
                 .RSA_KEYTABLE_ADDR
                 .write(((keyslot << 7) | i) as u32);
+            let offset = (4 * (exponent_size >> 2)) - (4 * i) - 4;
             register_base.RSA_KEYTABLE_DATA.write(u32::from_be_bytes(
-                (&exponent[..(4 * (exponent_size >> 2)) - (4 * i) - 4])
-                    .try_into()
-                    .unwrap(),
+                (&exponent[offset..offset + 4]).try_into().unwrap(),
             ));
         }
 
         self.modulus_sizes[keyslot] = modulus_size;
         self.exponent_sizes[keyslot] = exponent_size;
+
+        Ok(())
     }
 
     /// Sets the IV of the AES keyslot.
-    pub fn set_aes_keyslot_iv(&self, keyslot: usize, iv: &[u8]) {
+    pub(crate) fn set_aes_keyslot_iv(&self, keyslot: usize, iv: &[u8]) -> Result<(), Error> {
         let register_base = unsafe { Registers::get() };
 
         let iv_size = iv.len();
 
-        if keyslot >= KEYSLOT_AES_MAX || iv_size > 0x10 {
-            panic!();
+        if keyslot >= KEYSLOT_AES_MAX {
+            return Err(Error::InvalidKeyslot(keyslot));
+        }
+
+        if iv_size > AES_BLOCK_SIZE {
+            return Err(Error::KeyTooLarge);
         }
 
         for i in 0..iv_size >> 2 {
             register_base
                 .AES_KEYTABLE_ADDR
                 .write(((keyslot << 4) | 8 | i) as u32);
+            register_base.AES_KEYTABLE_DATA.write(u32::from_le_bytes(
+                (&iv[4 * i..4 * i + 4]).try_into().unwrap(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Sets the updated IV of the AES keyslot.
+    pub(crate) fn set_aes_keyslot_updated_iv(
+        &self,
+        keyslot: usize,
+        iv: &[u8],
+    ) -> Result<(), Error> {
+        let register_base = unsafe { Registers::get() };
+
+        let iv_size = iv.len();
+
+        if keyslot >= KEYSLOT_AES_MAX {
+            return Err(Error::InvalidKeyslot(keyslot));
+        }
+
+        if iv_size > AES_BLOCK_SIZE {
+            return Err(Error::KeyTooLarge);
+        }
+
+        for i in 0..iv_size >> 2 {
             register_base
-                .AES_KEYTABLE_DATA
-                .write(u32::from_le_bytes((&iv[..4 * i]).try_into().unwrap()));
+                .AES_KEYTABLE_ADDR
+                .write(((keyslot << 4) | 0xC | i) as u32);
+            register_base.AES_KEYTABLE_DATA.write(u32::from_le_bytes(
+                (&iv[4 * i..4 * i + 4]).try_into().unwrap(),
+            ));
         }
+
+        Ok(())
     }
 
     /// Clears the IV of the AES keyslot.
-    pub fn clear_aes_keyslot_iv(&self, keyslot: usize) {
+    pub(crate) fn clear_aes_keyslot_iv(&self, keyslot: usize) -> Result<(), Error> {
         let register_base = unsafe { Registers::get() };
 
         if keyslot >= KEYSLOT_AES_MAX {
-            panic!();
+            return Err(Error::InvalidKeyslot(keyslot));
         }
 
         for i in 0..0x10 >> 2 {
@@ -405,54 +598,76 @@ impl SecurityEngine {
                 .write(((keyslot << 4) | 8 | i) as u32);
             register_base.AES_KEYTABLE_DATA.write(0);
         }
+
+        Ok(())
     }
 
     /// Sets the CRYPTO_CTR_REG to enable CTR mode.
-    pub fn set_ctr(&self, ctr: &[u8]) {
+    pub fn set_ctr(&self, ctr: &[u8]) -> Result<(), Error> {
         let register_base = unsafe { Registers::get() };
 
+        if ctr.len() != AES_BLOCK_SIZE {
+            return Err(Error::InvalidLength);
+        }
+
         for i in 0..4 {
-            register_base.CRYPTO_CTR_REG[i]
-                .write(u32::from_le_bytes((&ctr[..4 * i]).try_into().unwrap()));
+            register_base.CRYPTO_CTR_REG[i].write(u32::from_le_bytes(
+                (&ctr[4 * i..4 * i + 4]).try_into().unwrap(),
+            ));
         }
+
+        Ok(())
     }
 
     /// Decrypts data from a given keyslot into another keyslot.
-    pub fn decrypt_data_into_keyslot(
+    pub(crate) fn decrypt_data_into_keyslot(
         &self,
         destination: usize,
         source: usize,
-        wrapped_key: &mut [u8],
-    ) {
+        wrapped_key: &[u8],
+    ) -> Result<(), Error> {
         let register_base = unsafe { Registers::get() };
 
-        if destination >= KEYSLOT_AES_MAX
-            || source >= KEYSLOT_AES_MAX
-            || wrapped_key.len() > KEYSIZE_AES_MAX
-        {
-            panic!();
+        if destination >= KEYSLOT_AES_MAX {
+            return Err(Error::InvalidKeyslot(destination));
         }
 
-        register_base.CONFIG_REG.write(0x108);
+        if source >= KEYSLOT_AES_MAX {
+            return Err(Error::InvalidKeyslot(source));
+        }
+
+        if wrapped_key.len() > KEYSIZE_AES_MAX {
+            return Err(Error::KeyTooLarge);
+        }
+
+        register_base
+            .CONFIG_REG
+            .write(config_decrypt(ALG_AES, 0, DST_KEYTABLE));
         register_base.CRYPTO_REG.write((source << 24) as u32);
         register_base.BLOCK_COUNT_REG.write(0);
         register_base
             .CRYPTO_KEYTABLE_DST_REG
             .write((destination << 8) as u32);
 
-        self.trigger_blocking_operation(OP_START, &mut [0; 0], wrapped_key);
+        self.trigger_blocking_operation(OP_START, &mut [0; 0], wrapped_key)
     }
 
-    /// Performs a blocking AES operation.
-    pub fn perform_aes_block_operation(&self, destination: &mut [u8], source: &mut [u8]) {
+    /// Performs a blocking AES operation on a single block.
+    ///
+    /// `CONFIG_REG` and `CRYPTO_REG` must be configured by the caller.
+    pub fn perform_aes_block_operation(
+        &self,
+        destination: &mut [u8],
+        source: &[u8],
+    ) -> Result<(), Error> {
         let register_base = unsafe { Registers::get() };
 
-        if source.len() > 0x10 || destination.len() > 0x10 {
-            panic!();
+        if source.len() > AES_BLOCK_SIZE || destination.len() > AES_BLOCK_SIZE {
+            return Err(Error::InvalidLength);
         }
 
         // Trigger AES operation.
         register_base.BLOCK_COUNT_REG.write(0);
-        self.trigger_blocking_operation(OP_START, destination, source);
+        self.trigger_blocking_operation(OP_START, destination, source)
     }
 }
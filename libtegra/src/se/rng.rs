@@ -0,0 +1,170 @@
+//! Random number generation using the Security Engine.
+//!
+//! # Description
+//!
+//! The SE contains a hardware entropy source which seeds an AES-based
+//! DRBG. The DRBG output can be written to memory, or used to generate
+//! the Secure Root Key (SRK) for context save operations.
+//!
+//! # Implementation
+//!
+//! - [`init_rng`] locks the entropy source, configures the reseed interval
+//! and forces the instantiation of the DRBG. It must be called before any
+//! random numbers are generated.
+//!
+//! - [`generate_random`] fills arbitrary buffers with random bytes. Data is
+//! generated in whole blocks, a trailing partial block is generated into a
+//! temporary buffer.
+//!
+//! - [`Rng`] wraps the Security Engine and implements the `RngCore` and
+//! `CryptoRng` traits from `rand_core`.
+//!
+//! [`init_rng`]: ../struct.SecurityEngine.html#method.init_rng
+//! [`generate_random`]: ../struct.SecurityEngine.html#method.generate_random
+//! [`Rng`]: struct.Rng.html
+
+use core::num::NonZeroU32;
+use mirage_mmio::VolatileStorage;
+
+use rand_core::{impls, CryptoRng, Error as RngError, RngCore};
+
+use super::*;
+
+/// Use the random number generator as input to the AES core.
+const CRYPTO_INPUT_RANDOM: u32 = 1 << 3;
+/// Configures the AES core for encryption.
+const CRYPTO_CORE_ENCRYPT: u32 = 1 << 8;
+
+/// Generates random numbers without reseeding.
+const RNG_MODE_NORMAL: u32 = 0;
+/// Forces the instantiation of the DRBG.
+const RNG_MODE_FORCE_INSTANTIATION: u32 = 1;
+/// Forces a reseed of the DRBG.
+const RNG_MODE_FORCE_RESEED: u32 = 2;
+/// Uses the hardware entropy source to seed the DRBG.
+const RNG_SRC_ENTROPY: u32 = 1 << 2;
+
+/// Enables the entropy source.
+const RNG_SRC_CONFIG_ENTROPY_SOURCE: u32 = 1 << 1;
+/// Locks the entropy source configuration.
+const RNG_SRC_CONFIG_ENTROPY_SOURCE_LOCK: u32 = 1 << 0;
+
+/// The number of blocks after which the DRBG is reseeded.
+pub const RNG_RESEED_INTERVAL: u32 = 70001;
+
+/// The `rand_core` error code reported when an SE operation fails.
+const RNG_ERROR_CODE: u32 = RngError::CUSTOM_START;
+
+impl SecurityEngine {
+    /// Configures the SE for an RNG operation.
+    fn config_rng(&self, destination: u32, mode: u32) {
+        let register_base = unsafe { Registers::get() };
+
+        register_base
+            .CONFIG_REG
+            .write(config_encrypt(ALG_RNG, 0, destination));
+        register_base
+            .CRYPTO_REG
+            .write(CRYPTO_CORE_ENCRYPT | CRYPTO_INPUT_RANDOM);
+        register_base.RNG_CONFIG_REG.write(RNG_SRC_ENTROPY | mode);
+    }
+
+    /// Initializes the random number generator.
+    pub fn init_rng(&self) -> Result<(), Error> {
+        let register_base = unsafe { Registers::get() };
+
+        // Enable and lock the entropy source.
+        register_base
+            .RNG_SRC_CONFIG_REG
+            .write(RNG_SRC_CONFIG_ENTROPY_SOURCE | RNG_SRC_CONFIG_ENTROPY_SOURCE_LOCK);
+
+        register_base
+            .RNG_RESEED_INTERVAL_REG
+            .write(RNG_RESEED_INTERVAL);
+
+        // Generate a single block to instantiate the DRBG.
+        let mut block = [0; AES_BLOCK_SIZE];
+
+        self.config_rng(DST_MEMORY, RNG_MODE_FORCE_INSTANTIATION);
+        register_base.BLOCK_COUNT_REG.write(0);
+        self.trigger_blocking_operation(OP_START, &mut block, &[0; 0])
+    }
+
+    /// Generates a new Secure Root Key from a freshly reseeded DRBG.
+    pub fn generate_srk(&self) -> Result<(), Error> {
+        let register_base = unsafe { Registers::get() };
+
+        self.config_rng(DST_SRK, RNG_MODE_FORCE_RESEED);
+        register_base.BLOCK_COUNT_REG.write(0);
+        self.trigger_blocking_operation(OP_START, &mut [0; 0], &[0; 0])
+    }
+
+    /// Fills a buffer with random bytes.
+    pub fn generate_random(&self, destination: &mut [u8]) -> Result<(), Error> {
+        let register_base = unsafe { Registers::get() };
+
+        self.config_rng(DST_MEMORY, RNG_MODE_NORMAL);
+
+        let aligned_size = destination.len() & !(AES_BLOCK_SIZE - 1);
+        let (aligned, tail) = destination.split_at_mut(aligned_size);
+
+        for chunk in aligned.chunks_mut(MAX_OPERATION_SIZE) {
+            register_base
+                .BLOCK_COUNT_REG
+                .write((chunk.len() / AES_BLOCK_SIZE - 1) as u32);
+            self.trigger_blocking_operation(OP_START, chunk, &[0; 0])?;
+        }
+
+        // Generate the remaining bytes through a temporary block.
+        if !tail.is_empty() {
+            let mut block = [0; AES_BLOCK_SIZE];
+
+            register_base.BLOCK_COUNT_REG.write(0);
+            self.trigger_blocking_operation(OP_START, &mut block, &[0; 0])?;
+
+            tail.copy_from_slice(&block[..tail.len()]);
+        }
+
+        Ok(())
+    }
+}
+
+/// A random number generator backed by the Security Engine.
+///
+/// The RNG of the SE must have been initialized through `init_rng`.
+/// As required by `RngCore`, `fill_bytes` panics if the SE reports an
+/// error, `try_fill_bytes` should be preferred where this matters.
+pub struct Rng<'a> {
+    engine: &'a SecurityEngine,
+}
+
+impl<'a> Rng<'a> {
+    /// Creates a new random number generator.
+    pub fn new(engine: &'a SecurityEngine) -> Self {
+        Rng { engine }
+    }
+}
+
+impl<'a> RngCore for Rng<'a> {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(error) = self.engine.generate_random(dest) {
+            panic!("SE random number generation failed: {:?}", error);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RngError> {
+        self.engine
+            .generate_random(dest)
+            .map_err(|_| RngError::from(NonZeroU32::new(RNG_ERROR_CODE).unwrap()))
+    }
+}
+
+impl<'a> CryptoRng for Rng<'a> {}
@@ -0,0 +1,213 @@
+//! RSA operations on top of the Security Engine.
+//!
+//! # Description
+//!
+//! The SE is able to perform modular exponentiation with keys of up
+//! to 2048 bits stored in its RSA keyslots. This is used to verify
+//! RSA signatures, e.g. those of Nintendo-signed package headers.
+//!
+//! # Implementation
+//!
+//! - [`rsa_exp_mod`] performs the raw exponentiation with the key from
+//! a keyslot that was previously configured through `set_rsa_keyslot`.
+//!
+//! - [`verify_pss_sha256`] and [`verify_pkcs1v15_sha256`] verify signatures
+//! according to RSASSA-PSS and RSASSA-PKCS1-v1_5 as specified in RFC 8017,
+//! using SHA-256 as the hash function and MGF1 with SHA-256 for PSS.
+//!
+//! - All numbers are passed in big-endian byte order. The SE itself expects
+//! little-endian input, which is taken care of internally.
+//!
+//! [`rsa_exp_mod`]: ../struct.SecurityEngine.html#method.rsa_exp_mod
+//! [`verify_pss_sha256`]: ../struct.SecurityEngine.html#method.verify_pss_sha256
+//! [`verify_pkcs1v15_sha256`]: ../struct.SecurityEngine.html#method.verify_pkcs1v15_sha256
+
+use mirage_mmio::VolatileStorage;
+
+use super::{
+    sha::{HashAlgorithm, Hasher},
+    *,
+};
+
+/// The size of a SHA-256 digest.
+const SHA256_SIZE: usize = 0x20;
+
+/// The DER-encoded `DigestInfo` prefix for SHA-256 digests.
+const SHA256_DIGEST_INFO: [u8; 0x13] = [
+    0x30, 0x31, 0x30, 0x0D, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01, 0x05,
+    0x00, 0x04, 0x20,
+];
+
+/// Compares two buffers without exiting early on the first mismatch.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b.iter()).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+impl SecurityEngine {
+    /// Gets the modulus size of an RSA keyslot and fails if it is unusable.
+    fn rsa_modulus_size(&self, keyslot: usize) -> Result<usize, Error> {
+        if keyslot >= KEYSLOT_RSA_MAX {
+            return Err(Error::InvalidKeyslot(keyslot));
+        }
+
+        let modulus_size = self.modulus_sizes[keyslot];
+        if modulus_size == 0 {
+            return Err(Error::KeyNotSet);
+        }
+
+        if modulus_size % 0x40 != 0 {
+            return Err(Error::InvalidKeySize);
+        }
+
+        Ok(modulus_size)
+    }
+
+    /// Computes `input ^ exponent mod modulus` with the key from an RSA keyslot.
+    ///
+    /// `input` and `output` must both be exactly as large as the modulus.
+    pub fn rsa_exp_mod(
+        &self,
+        keyslot: usize,
+        input: &[u8],
+        output: &mut [u8],
+    ) -> Result<(), Error> {
+        let register_base = unsafe { Registers::get() };
+
+        let modulus_size = self.rsa_modulus_size(keyslot)?;
+
+        if input.len() != modulus_size || output.len() != modulus_size {
+            return Err(Error::InvalidLength);
+        }
+
+        register_base
+            .CONFIG_REG
+            .write(config_encrypt(ALG_RSA, 0, DST_RSA_REG));
+        register_base
+            .RSA_KEY_SIZE_REG
+            .write((modulus_size / 0x40 - 1) as u32);
+        register_base
+            .RSA_EXP_SIZE_REG
+            .write((self.exponent_sizes[keyslot] >> 2) as u32);
+        register_base.RSA_CONFIG.write(((keyslot & 1) << 24) as u32);
+
+        // The SE expects the input in little-endian byte order.
+        let mut buffer = [0; KEYSIZE_RSA_MAX];
+        for (dst, src) in buffer.iter_mut().zip(input.iter().rev()) {
+            *dst = *src;
+        }
+
+        self.trigger_blocking_operation(OP_START, &mut [0; 0], &buffer[..modulus_size])?;
+
+        // The result is stored in little-endian byte order as well.
+        for (chunk, reg) in output.rchunks_mut(4).zip(register_base.RSA_OUTPUT.iter()) {
+            chunk.copy_from_slice(&reg.read().to_be_bytes());
+        }
+
+        Ok(())
+    }
+
+    /// Verifies an RSASSA-PSS signature over a SHA-256 message digest.
+    ///
+    /// The salt length is derived from the encoded message. Returns whether
+    /// the signature is valid, or an error if the SE operation failed.
+    pub fn verify_pss_sha256(
+        &self,
+        keyslot: usize,
+        signature: &[u8],
+        message_hash: &[u8; SHA256_SIZE],
+    ) -> Result<bool, Error> {
+        let modulus_size = self.rsa_modulus_size(keyslot)?;
+
+        if signature.len() != modulus_size {
+            return Ok(false);
+        }
+
+        let mut message = [0; KEYSIZE_RSA_MAX];
+        let message = &mut message[..modulus_size];
+        self.rsa_exp_mod(keyslot, signature, message)?;
+
+        // The encoded message must end with the trailer field.
+        if message[modulus_size - 1] != 0xBC {
+            return Ok(false);
+        }
+
+        let (db, rest) = message.split_at_mut(modulus_size - SHA256_SIZE - 1);
+        let h = &rest[..SHA256_SIZE];
+
+        // The leftmost bit of the encoded message must be cleared.
+        if db[0] & 0x80 != 0 {
+            return Ok(false);
+        }
+
+        // Unmask the data block using MGF1 with SHA-256.
+        for (counter, chunk) in db.chunks_mut(SHA256_SIZE).enumerate() {
+            let mut mask = [0; SHA256_SIZE];
+            let mut hasher = Hasher::new(self, HashAlgorithm::Sha256);
+
+            hasher.update(h)?;
+            hasher.update(&(counter as u32).to_be_bytes())?;
+            hasher.finalize(&mut mask)?;
+
+            for (byte, mask) in chunk.iter_mut().zip(mask.iter()) {
+                *byte ^= *mask;
+            }
+        }
+        db[0] &= 0x7F;
+
+        // The data block consists of zero padding, a 0x01 separator and the salt.
+        let separator = match db.iter().position(|byte| *byte != 0) {
+            Some(position) if db[position] == 0x01 => position,
+            _ => return Ok(false),
+        };
+        let salt = &db[separator + 1..];
+
+        // Compute the hash of the reconstructed message.
+        let mut expected = [0; SHA256_SIZE];
+        let mut hasher = Hasher::new(self, HashAlgorithm::Sha256);
+
+        hasher.update(&[0; 8])?;
+        hasher.update(message_hash)?;
+        hasher.update(salt)?;
+        hasher.finalize(&mut expected)?;
+
+        Ok(constant_time_eq(&expected, h))
+    }
+
+    /// Verifies an RSASSA-PKCS1-v1_5 signature over a SHA-256 message digest.
+    ///
+    /// Returns whether the signature is valid, or an error if the SE operation failed.
+    pub fn verify_pkcs1v15_sha256(
+        &self,
+        keyslot: usize,
+        signature: &[u8],
+        message_hash: &[u8; SHA256_SIZE],
+    ) -> Result<bool, Error> {
+        let modulus_size = self.rsa_modulus_size(keyslot)?;
+
+        if signature.len() != modulus_size {
+            return Ok(false);
+        }
+
+        let mut message = [0; KEYSIZE_RSA_MAX];
+        let message = &mut message[..modulus_size];
+        self.rsa_exp_mod(keyslot, signature, message)?;
+
+        // Build the expected encoded message: 00 01 FF .. FF 00 || DigestInfo || hash.
+        let mut expected = [0xFF; KEYSIZE_RSA_MAX];
+        let expected = &mut expected[..modulus_size];
+        let digest_info_offset = modulus_size - SHA256_SIZE - SHA256_DIGEST_INFO.len();
+
+        expected[0] = 0x00;
+        expected[1] = 0x01;
+        expected[digest_info_offset - 1] = 0x00;
+        expected[digest_info_offset..modulus_size - SHA256_SIZE]
+            .copy_from_slice(&SHA256_DIGEST_INFO);
+        expected[modulus_size - SHA256_SIZE..].copy_from_slice(message_hash);
+
+        Ok(constant_time_eq(expected, message))
+    }
+}
@@ -0,0 +1,313 @@
+//! SHA hash functions on top of the Security Engine.
+//!
+//! # Description
+//!
+//! The SE implements the SHA-1 and SHA-2 family of hash functions in
+//! hardware. This module exposes SHA-1, SHA-256 and SHA-512 both as
+//! one-shot functions and as a streaming [`Hasher`].
+//!
+//! # Implementation
+//!
+//! - The SE pads the message once the amount of bits left to process
+//! reaches zero. Intermediate operations announce the maximum message
+//! length, so that no padding is applied before the final operation.
+//!
+//! - Between operations, the intermediate hash state is saved from the
+//! `HASH_RESULT_REG` and restored into it before the next operation.
+//!
+//! - The SE is unable to process empty messages, so the digests of those
+//! are provided as constants.
+//!
+//! [`Hasher`]: struct.Hasher.html
+
+use mirage_mmio::VolatileStorage;
+
+use super::*;
+
+/// The `HW_INIT_HASH` bit of the `SHA_CONFIG_REG`.
+const SHA_INIT_HASH: u32 = 1;
+
+/// The maximum block size of the supported hash algorithms.
+const MAX_BLOCK_SIZE: usize = 0x80;
+
+/// The maximum digest size of the supported hash algorithms.
+pub const MAX_DIGEST_SIZE: usize = 0x40;
+
+/// The SHA-1 digest of an empty message.
+const SHA1_EMPTY: [u8; 0x14] = [
+    0xDA, 0x39, 0xA3, 0xEE, 0x5E, 0x6B, 0x4B, 0x0D, 0x32, 0x55, 0xBF, 0xEF, 0x95, 0x60, 0x18, 0x90,
+    0xAF, 0xD8, 0x07, 0x09,
+];
+
+/// The SHA-256 digest of an empty message.
+const SHA256_EMPTY: [u8; 0x20] = [
+    0xE3, 0xB0, 0xC4, 0x42, 0x98, 0xFC, 0x1C, 0x14, 0x9A, 0xFB, 0xF4, 0xC8, 0x99, 0x6F, 0xB9, 0x24,
+    0x27, 0xAE, 0x41, 0xE4, 0x64, 0x9B, 0x93, 0x4C, 0xA4, 0x95, 0x99, 0x1B, 0x78, 0x52, 0xB8, 0x55,
+];
+
+/// The SHA-512 digest of an empty message.
+const SHA512_EMPTY: [u8; 0x40] = [
+    0xCF, 0x83, 0xE1, 0x35, 0x7E, 0xEF, 0xB8, 0xBD, 0xF1, 0x54, 0x28, 0x50, 0xD6, 0x6D, 0x80, 0x07,
+    0xD6, 0x20, 0xE4, 0x05, 0x0B, 0x57, 0x15, 0xDC, 0x83, 0xF4, 0xA9, 0x21, 0xD3, 0x6C, 0xE9, 0xCE,
+    0x47, 0xD0, 0xD1, 0x3C, 0x5D, 0x85, 0xF2, 0xB0, 0xFF, 0x83, 0x18, 0xD2, 0x87, 0x7E, 0xEC, 0x2F,
+    0x63, 0xB9, 0x31, 0xBD, 0x47, 0x41, 0x7A, 0x81, 0xA5, 0x38, 0x32, 0x7A, 0xF9, 0x27, 0xDA, 0x3E,
+];
+
+/// Hash algorithms supported by the SE.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    /// SHA-1.
+    Sha1,
+    /// SHA-256.
+    Sha256,
+    /// SHA-512.
+    Sha512,
+}
+
+impl HashAlgorithm {
+    /// Gets the size of the digest in bytes.
+    pub fn digest_size(self) -> usize {
+        match self {
+            HashAlgorithm::Sha1 => 0x14,
+            HashAlgorithm::Sha256 => 0x20,
+            HashAlgorithm::Sha512 => 0x40,
+        }
+    }
+
+    /// Gets the size of an input block in bytes.
+    pub fn block_size(self) -> usize {
+        match self {
+            HashAlgorithm::Sha1 | HashAlgorithm::Sha256 => 0x40,
+            HashAlgorithm::Sha512 => 0x80,
+        }
+    }
+
+    /// Gets the mode value of the `CONFIG_REG` for this algorithm.
+    #[inline]
+    fn mode(self) -> u32 {
+        match self {
+            HashAlgorithm::Sha1 => 0,
+            HashAlgorithm::Sha256 => 5,
+            HashAlgorithm::Sha512 => 7,
+        }
+    }
+
+    /// Gets the digest of an empty message.
+    fn empty_digest(self) -> &'static [u8] {
+        match self {
+            HashAlgorithm::Sha1 => &SHA1_EMPTY,
+            HashAlgorithm::Sha256 => &SHA256_EMPTY,
+            HashAlgorithm::Sha512 => &SHA512_EMPTY,
+        }
+    }
+}
+
+/// Writes a 128-bit bit count into one of the SHA length registers.
+fn write_bit_count(register: &[Mmio<u32>; 4], bits: u128) {
+    for (i, reg) in register.iter().enumerate() {
+        reg.write((bits >> (32 * i)) as u32);
+    }
+}
+
+impl SecurityEngine {
+    /// Runs a single SE hash operation on a buffer.
+    ///
+    /// `state` holds the intermediate hash state and is updated in place.
+    /// If `total_length` is given, this is the final operation and the
+    /// message is padded according to the given total length in bytes.
+    fn perform_sha_operation(
+        &self,
+        algorithm: HashAlgorithm,
+        state: &mut [u32; MAX_DIGEST_SIZE / 4],
+        first: bool,
+        source: &[u8],
+        total_length: Option<u64>,
+    ) -> Result<(), Error> {
+        let register_base = unsafe { Registers::get() };
+
+        if source.is_empty() || source.len() > MAX_OPERATION_SIZE {
+            return Err(Error::InvalidLength);
+        }
+
+        register_base
+            .CONFIG_REG
+            .write(config_encrypt(ALG_SHA, algorithm.mode(), DST_HASH_REG));
+        register_base
+            .SHA_CONFIG_REG
+            .write(if first { SHA_INIT_HASH } else { 0 });
+
+        // Announce the maximum length for intermediate operations to prevent padding.
+        let total_bits = match total_length {
+            Some(length) => u128::from(length) << 3,
+            None => u128::max_value(),
+        };
+        write_bit_count(&register_base.SHA_MSG_LENGTH_REG, total_bits);
+        write_bit_count(&register_base.SHA_MSG_LEFT_REG, (source.len() as u128) << 3);
+
+        // Restore the intermediate hash state.
+        if !first {
+            for (reg, word) in register_base.HASH_RESULT_REG.iter().zip(state.iter()) {
+                reg.write(*word);
+            }
+        }
+
+        self.trigger_blocking_operation(OP_START, &mut [0; 0], source)?;
+
+        // Save the (intermediate) hash state.
+        for (word, reg) in state.iter_mut().zip(register_base.HASH_RESULT_REG.iter()) {
+            *word = reg.read();
+        }
+
+        Ok(())
+    }
+
+    /// Calculates the digest of a message in a single call.
+    ///
+    /// `output` must be able to hold at least `algorithm.digest_size()` bytes.
+    pub fn hash(
+        &self,
+        algorithm: HashAlgorithm,
+        data: &[u8],
+        output: &mut [u8],
+    ) -> Result<(), Error> {
+        let mut hasher = Hasher::new(self, algorithm);
+
+        hasher.update(data)?;
+        hasher.finalize(output)
+    }
+
+    /// Calculates the SHA-256 digest of a message.
+    pub fn sha256(&self, data: &[u8]) -> Result<[u8; 0x20], Error> {
+        let mut digest = [0; 0x20];
+
+        self.hash(HashAlgorithm::Sha256, data, &mut digest)?;
+
+        Ok(digest)
+    }
+}
+
+/// A streaming hash context using the Security Engine.
+///
+/// The SE must not be used for other operations while data
+/// is fed into the hasher.
+pub struct Hasher<'a> {
+    /// The Security Engine to use.
+    engine: &'a SecurityEngine,
+    /// The hash algorithm to use.
+    algorithm: HashAlgorithm,
+    /// The intermediate hash state.
+    state: [u32; MAX_DIGEST_SIZE / 4],
+    /// Whether the hash state has been initialized by the SE.
+    started: bool,
+    /// Input that has not been processed yet.
+    buffer: [u8; MAX_BLOCK_SIZE],
+    /// The amount of bytes in the buffer.
+    buffered: usize,
+    /// The total length of the message in bytes.
+    length: u64,
+}
+
+impl<'a> Hasher<'a> {
+    /// Creates a new hasher for the given algorithm.
+    pub fn new(engine: &'a SecurityEngine, algorithm: HashAlgorithm) -> Self {
+        Hasher {
+            engine,
+            algorithm,
+            state: [0; MAX_DIGEST_SIZE / 4],
+            started: false,
+            buffer: [0; MAX_BLOCK_SIZE],
+            buffered: 0,
+            length: 0,
+        }
+    }
+
+    /// Processes a block-aligned buffer as an intermediate operation.
+    fn process(&mut self, data: &[u8]) -> Result<(), Error> {
+        self.engine.perform_sha_operation(
+            self.algorithm,
+            &mut self.state,
+            !self.started,
+            data,
+            None,
+        )?;
+        self.started = true;
+
+        Ok(())
+    }
+
+    /// Processes the internal buffer as an intermediate operation.
+    fn process_buffer(&mut self) -> Result<(), Error> {
+        let buffer = self.buffer;
+
+        self.process(&buffer[..self.buffered])?;
+        self.buffered = 0;
+
+        Ok(())
+    }
+
+    /// Feeds data into the hasher.
+    pub fn update(&mut self, mut data: &[u8]) -> Result<(), Error> {
+        let block_size = self.algorithm.block_size();
+
+        self.length += data.len() as u64;
+
+        // Fill up a partial block from previous calls first.
+        if self.buffered != 0 {
+            let size = core::cmp::min(block_size - self.buffered, data.len());
+
+            self.buffer[self.buffered..self.buffered + size].copy_from_slice(&data[..size]);
+            self.buffered += size;
+            data = &data[size..];
+
+            if data.is_empty() {
+                return Ok(());
+            }
+
+            self.process_buffer()?;
+        }
+
+        // Process full blocks directly, but always keep the last block for finalization.
+        while data.len() > block_size {
+            let size = core::cmp::min((data.len() - 1) & !(block_size - 1), MAX_OPERATION_SIZE);
+
+            self.process(&data[..size])?;
+            data = &data[size..];
+        }
+
+        self.buffer[..data.len()].copy_from_slice(data);
+        self.buffered = data.len();
+
+        Ok(())
+    }
+
+    /// Finishes the hash calculation and writes the digest to `output`.
+    ///
+    /// `output` must be able to hold at least `algorithm.digest_size()` bytes.
+    pub fn finalize(mut self, output: &mut [u8]) -> Result<(), Error> {
+        let digest_size = self.algorithm.digest_size();
+
+        if output.len() < digest_size {
+            return Err(Error::InvalidLength);
+        }
+
+        if self.length == 0 {
+            output[..digest_size].copy_from_slice(self.algorithm.empty_digest());
+            return Ok(());
+        }
+
+        let buffer = self.buffer;
+        self.engine.perform_sha_operation(
+            self.algorithm,
+            &mut self.state,
+            !self.started,
+            &buffer[..self.buffered],
+            Some(self.length),
+        )?;
+
+        for (chunk, word) in output[..digest_size].chunks_mut(4).zip(self.state.iter()) {
+            chunk.copy_from_slice(&word.to_be_bytes()[..chunk.len()]);
+        }
+
+        Ok(())
+    }
+}
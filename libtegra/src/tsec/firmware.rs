@@ -0,0 +1,366 @@
+//! TSEC firmware image parsing.
+//!
+//! # Description
+//!
+//! The TSEC firmware is a single image which consists of multiple stages.
+//! The unencrypted Boot stage is followed by a key table, which describes
+//! the sizes and authentication hashes of the remaining stages:
+//!
+//! - KeygenLdr, which is authenticated and executed in Heavy Secure mode.
+//!
+//! - Keygen, which is encrypted and derives the TSEC key.
+//!
+//! - The SecureBoot stages, which are only present starting with firmware
+//! 6.2.0 and derive the TSEC root key.
+//!
+//! # Implementation
+//!
+//! - [`Firmware::parse`] validates the layout of the image before it is handed
+//! to the DMA engine of the Falcon. The image must be padded to the DMA block
+//! size and all stages must be within its bounds. The alignment of the buffer
+//! itself is checked by the Falcon driver when the image is loaded, so images
+//! can also be inspected on the host.
+//!
+//! - The image doesn't carry an explicit version, so the [`FirmwareRevision`]
+//! is inferred from the stages that are present. The SecureBoot stages are
+//! either all present or all absent, anything else is rejected.
+//!
+//! - The authentication hashes and signatures in the [`KeyTable`] are parsed,
+//! but not checked. They are verified by the TSEC itself, with keys that
+//! are not accessible to the CPU.
+//!
+//! - The firmware is always passed [`KEY_VERSION`] as `rev`, since all known
+//! revisions of the Keygen stage expect the same key version.
+//!
+//! [`Firmware::parse`]: struct.Firmware.html#method.parse
+//! [`FirmwareRevision`]: enum.FirmwareRevision.html
+//! [`KeyTable`]: struct.KeyTable.html
+//! [`KEY_VERSION`]: constant.KEY_VERSION.html
+
+use core::{convert::TryInto, ops::Range};
+
+use crate::falcon::DMA_BLOCK_SIZE;
+
+/// The alignment and granularity of firmware DMA transfers.
+pub const FIRMWARE_ALIGNMENT: usize = DMA_BLOCK_SIZE;
+
+/// The size of the Boot stage.
+pub const BOOT_SIZE: usize = 0x300;
+
+/// The size of the key table that follows the Boot stage.
+pub const KEY_TABLE_SIZE: usize = 0x100;
+
+/// The key version that is passed to the firmware through `MAILBOX0`.
+///
+/// This value is fixed for all known firmware revisions.
+pub const KEY_VERSION: u32 = 1;
+
+/// The size of a hash or key in the key table.
+const KEY_TABLE_ENTRY_SIZE: usize = 0x10;
+
+/// The offset of the stage sizes in the key table.
+const KEY_TABLE_SIZES_OFFSET: usize = 0x70;
+
+/// The number of stages described by the key table.
+const KEY_TABLE_BLOBS: usize = 5;
+
+/// Enumeration of possible firmware validation errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The image is too small to hold the Boot stage and the key table.
+    TooSmall,
+    /// The image size is not a multiple of the DMA block size.
+    UnalignedSize,
+    /// The size of a stage is not a multiple of the DMA block size.
+    UnalignedStage(usize),
+    /// The stages exceed the bounds of the image.
+    StagesOutOfBounds,
+    /// The KeygenLdr or Keygen stage is missing, or only some of the
+    /// SecureBoot stages are present.
+    MissingStage,
+}
+
+/// The stages of the TSEC firmware.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    /// The unencrypted stage that authenticates and starts KeygenLdr.
+    Boot,
+    /// The authenticated stage that decrypts and starts Keygen.
+    KeygenLdr,
+    /// The encrypted stage that derives the TSEC key.
+    Keygen,
+    /// The stages that are present starting with firmware 6.2.0.
+    SecureBoot,
+}
+
+/// The known revisions of the TSEC firmware.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FirmwareRevision {
+    /// Firmware 1.0.0 up to 6.0.0, which only derives the TSEC key.
+    Keygen,
+    /// Firmware 6.2.0 and later, which additionally derives the TSEC root key.
+    SecureBoot,
+}
+
+impl FirmwareRevision {
+    /// Whether the firmware derives the TSEC root key.
+    pub fn has_root_key(self) -> bool {
+        self == FirmwareRevision::SecureBoot
+    }
+}
+
+/// The key table which follows the Boot stage.
+///
+/// The hashes and keys are provided for inspection only. They are neither
+/// checked by the parser nor needed to load the firmware.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyTable {
+    /// The key that is used for debug-signed firmware.
+    pub debug_key: [u8; KEY_TABLE_ENTRY_SIZE],
+    /// The authentication hash (signature) of KeygenLdr.
+    pub keygen_ldr_hash: [u8; KEY_TABLE_ENTRY_SIZE],
+    /// The authentication hash (signature) of Keygen.
+    pub keygen_hash: [u8; KEY_TABLE_ENTRY_SIZE],
+    /// The authentication hash (signature) of the first SecureBoot stage.
+    pub secure_boot_hash: [u8; KEY_TABLE_ENTRY_SIZE],
+    /// The AES IV that Keygen is encrypted with.
+    pub keygen_iv: [u8; KEY_TABLE_ENTRY_SIZE],
+    /// The seed of the HOVI EKS key.
+    pub hovi_eks_seed: [u8; KEY_TABLE_ENTRY_SIZE],
+    /// The seed of the HOVI common key.
+    pub hovi_common_seed: [u8; KEY_TABLE_ENTRY_SIZE],
+    /// The sizes of KeygenLdr, Keygen and the SecureBoot stages.
+    pub blob_sizes: [u32; KEY_TABLE_BLOBS],
+}
+
+impl KeyTable {
+    /// Parses the key table from its raw bytes.
+    fn parse(data: &[u8]) -> Self {
+        let entry = |index: usize| {
+            let offset = index * KEY_TABLE_ENTRY_SIZE;
+
+            let mut entry = [0; KEY_TABLE_ENTRY_SIZE];
+            entry.copy_from_slice(&data[offset..offset + KEY_TABLE_ENTRY_SIZE]);
+
+            entry
+        };
+
+        let mut blob_sizes = [0; KEY_TABLE_BLOBS];
+        for (i, size) in blob_sizes.iter_mut().enumerate() {
+            let offset = KEY_TABLE_SIZES_OFFSET + 4 * i;
+
+            *size = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+        }
+
+        KeyTable {
+            debug_key: entry(0),
+            keygen_ldr_hash: entry(1),
+            keygen_hash: entry(2),
+            secure_boot_hash: entry(3),
+            keygen_iv: entry(4),
+            hovi_eks_seed: entry(5),
+            hovi_common_seed: entry(6),
+            blob_sizes,
+        }
+    }
+}
+
+/// A validated TSEC firmware image.
+#[derive(Clone, Copy, Debug)]
+pub struct Firmware<'a> {
+    /// The raw firmware image.
+    data: &'a [u8],
+    /// The key table of the image.
+    key_table: KeyTable,
+    /// The revision of the firmware.
+    revision: FirmwareRevision,
+}
+
+impl<'a> Firmware<'a> {
+    /// Parses and validates a firmware image.
+    pub fn parse(data: &'a [u8]) -> Result<Self, Error> {
+        if data.len() < BOOT_SIZE + KEY_TABLE_SIZE {
+            return Err(Error::TooSmall);
+        }
+
+        if data.len() % FIRMWARE_ALIGNMENT != 0 {
+            return Err(Error::UnalignedSize);
+        }
+
+        let key_table = KeyTable::parse(&data[BOOT_SIZE..BOOT_SIZE + KEY_TABLE_SIZE]);
+
+        let mut end = BOOT_SIZE + KEY_TABLE_SIZE;
+        for (i, &size) in key_table.blob_sizes.iter().enumerate() {
+            let size = size as usize;
+
+            if size % FIRMWARE_ALIGNMENT != 0 {
+                return Err(Error::UnalignedStage(i));
+            }
+
+            end = end.checked_add(size).ok_or(Error::StagesOutOfBounds)?;
+        }
+
+        if end > data.len() {
+            return Err(Error::StagesOutOfBounds);
+        }
+
+        if key_table.blob_sizes[0] == 0 || key_table.blob_sizes[1] == 0 {
+            return Err(Error::MissingStage);
+        }
+
+        let secure_boot = &key_table.blob_sizes[2..];
+        let revision = if secure_boot.iter().all(|&size| size != 0) {
+            FirmwareRevision::SecureBoot
+        } else if secure_boot.iter().all(|&size| size == 0) {
+            FirmwareRevision::Keygen
+        } else {
+            return Err(Error::MissingStage);
+        };
+
+        Ok(Firmware {
+            data,
+            key_table,
+            revision,
+        })
+    }
+
+    /// Gets the raw firmware image.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Gets the key table of the image.
+    pub fn key_table(&self) -> &KeyTable {
+        &self.key_table
+    }
+
+    /// Gets the revision of the firmware.
+    pub fn revision(&self) -> FirmwareRevision {
+        self.revision
+    }
+
+    /// Gets the data of a stage, if it is present.
+    pub fn stage(&self, stage: Stage) -> Option<&'a [u8]> {
+        self.stage_range(stage).map(|range| &self.data[range])
+    }
+
+    /// Gets the location of a stage within the image, if it is present.
+    pub fn stage_range(&self, stage: Stage) -> Option<Range<usize>> {
+        let sizes = &self.key_table.blob_sizes;
+        let blobs_start = BOOT_SIZE + KEY_TABLE_SIZE;
+        let blob_end = |count: usize| {
+            blobs_start
+                + sizes[..count]
+                    .iter()
+                    .map(|&size| size as usize)
+                    .sum::<usize>()
+        };
+
+        let (start, end) = match stage {
+            Stage::Boot => (0, BOOT_SIZE),
+            Stage::KeygenLdr => (blobs_start, blob_end(1)),
+            Stage::Keygen => (blob_end(1), blob_end(2)),
+            Stage::SecureBoot => (blob_end(2), blob_end(KEY_TABLE_BLOBS)),
+        };
+
+        if start == end {
+            None
+        } else {
+            Some(start..end)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The size of the buffer test images are created in.
+    const IMAGE_CAPACITY: usize = 0x1000;
+
+    /// Creates an image whose key table describes stages of the given sizes.
+    fn image(sizes: [u32; KEY_TABLE_BLOBS]) -> [u8; IMAGE_CAPACITY] {
+        let mut image = [0; IMAGE_CAPACITY];
+
+        for (i, size) in sizes.iter().enumerate() {
+            let offset = BOOT_SIZE + KEY_TABLE_SIZES_OFFSET + 4 * i;
+            image[offset..offset + 4].copy_from_slice(&size.to_le_bytes());
+        }
+
+        image
+    }
+
+    /// Tests that images with an invalid size are rejected.
+    #[test]
+    fn invalid_image_size() {
+        let image = image([0x100, 0x100, 0, 0, 0]);
+
+        assert_eq!(
+            Firmware::parse(&image[..BOOT_SIZE + KEY_TABLE_SIZE - 1]).err(),
+            Some(Error::TooSmall)
+        );
+        assert_eq!(
+            Firmware::parse(&image[..0x610]).err(),
+            Some(Error::UnalignedSize)
+        );
+    }
+
+    /// Tests that invalid stage sizes are rejected.
+    #[test]
+    fn invalid_stages() {
+        assert_eq!(
+            Firmware::parse(&image([0x100, 0x180, 0, 0, 0])).err(),
+            Some(Error::UnalignedStage(1))
+        );
+        assert_eq!(
+            Firmware::parse(&image([0x100, 0x100, 0, 0, 0])[..0x500]).err(),
+            Some(Error::StagesOutOfBounds)
+        );
+        assert_eq!(
+            Firmware::parse(&image([0x100, 0x100, 0xFFFF_FF00, 0x100, 0x100])).err(),
+            Some(Error::StagesOutOfBounds)
+        );
+        assert_eq!(
+            Firmware::parse(&image([0, 0x100, 0, 0, 0])).err(),
+            Some(Error::MissingStage)
+        );
+        assert_eq!(
+            Firmware::parse(&image([0x100, 0x100, 0x100, 0, 0x100])).err(),
+            Some(Error::MissingStage)
+        );
+    }
+
+    /// Tests the revision detection and the stage layout of a Keygen firmware.
+    #[test]
+    fn keygen_firmware() {
+        let image = image([0x200, 0x300, 0, 0, 0]);
+        let firmware = Firmware::parse(&image[..0x900]).unwrap();
+
+        assert_eq!(firmware.revision(), FirmwareRevision::Keygen);
+        assert!(!firmware.revision().has_root_key());
+        assert_eq!(firmware.stage_range(Stage::Boot), Some(0..0x300));
+        assert_eq!(firmware.stage_range(Stage::KeygenLdr), Some(0x400..0x600));
+        assert_eq!(firmware.stage_range(Stage::Keygen), Some(0x600..0x900));
+        assert_eq!(firmware.stage_range(Stage::SecureBoot), None);
+        assert_eq!(
+            firmware.stage(Stage::Keygen).map(|stage| stage.len()),
+            Some(0x300)
+        );
+    }
+
+    /// Tests the revision detection and the stage layout of a SecureBoot firmware.
+    #[test]
+    fn secure_boot_firmware() {
+        let image = image([0x100, 0x100, 0x200, 0x100, 0x100]);
+        let firmware = Firmware::parse(&image).unwrap();
+
+        assert_eq!(firmware.revision(), FirmwareRevision::SecureBoot);
+        assert!(firmware.revision().has_root_key());
+        assert_eq!(
+            firmware.key_table().blob_sizes,
+            [0x100, 0x100, 0x200, 0x100, 0x100]
+        );
+        assert_eq!(firmware.stage_range(Stage::Keygen), Some(0x500..0x600));
+        assert_eq!(firmware.stage_range(Stage::SecureBoot), Some(0x600..0xA00));
+    }
+}
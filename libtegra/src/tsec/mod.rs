@@ -18,15 +18,23 @@
 //! - The [`Registers`] struct represents the TSEC registers
 //! that are mapped to address `0x54500000`.
 //!
-//! - The [`Tsec`] struct holds an instance of [`Registers`] and
-//! provides further hardware abstractions. It allows for loading
-//! and executing Falcon firmware and finally deriving the TSEC
-//! key.
+//! - The [`Registers`] embed the generic Falcon registers, which are
+//! driven through [`Falcon::TSEC`].
+//!
+//! - The [`Tsec`] struct provides further hardware abstractions on
+//! top of the [`Falcon`] driver. It allows for loading and executing
+//! Falcon firmware and finally deriving the TSEC key.
+//!
+//! - Firmware images are validated by [`Firmware::parse`] before
+//! they are loaded, which also identifies the firmware revision.
+//!
+//! - Failures are reported as [`Error`], which carries the underlying
+//! Falcon error or the unexpected result reported by the firmware.
 //!
 //! # Example
 //!
 //! ```
-//! use mirage_libtegra::tsec::Tsec;
+//! use mirage_libtegra::tsec::{firmware::Firmware, Tsec};
 //!
 //! // Global instance of the TSEC.
 //! const TSEC: Tsec = Tsec::new();
@@ -35,22 +43,36 @@
 //! include!("falcon_fw.rs");
 //!
 //! fn main() {
+//!     // Validate the firmware.
+//!     let firmware = Firmware::parse(FALCON_FIRMWARE).unwrap();
+//!
 //!     // Load and execute the firmware.
-//!     TSEC.load_firmware(FALCON_FIRMWARE);
+//!     TSEC.load_firmware(&firmware).unwrap();
 //!     TSEC.execute_firmware(None);
 //!
 //!     // Derive the TSEC key.
-//!     let key = TSEC.get_key(1, FALCON_FIRMWARE).unwrap();
+//!     let key = TSEC.get_key(&firmware).unwrap();
 //! }
 //! ```
 //!
 //! [`Registers`]: struct.Registers.html
 //! [`Registers::get`]: struct.Registers.html#method.get
 //! [`Tsec`]: struct.Tsec.html
+//! [`Error`]: enum.Error.html
+//! [`Falcon`]: ../falcon/struct.Falcon.html
+//! [`Falcon::TSEC`]: ../falcon/struct.Falcon.html#associatedconstant.TSEC
+//! [`Firmware::parse`]: firmware/struct.Firmware.html#method.parse
 
 use mirage_mmio::{Mmio, VolatileStorage};
 
-use crate::{clock::Clock, timer::get_milliseconds};
+use crate::{
+    clock::Clock,
+    falcon::{self, Falcon, Registers as FalconRegisters},
+};
+
+use self::firmware::Firmware;
+
+pub mod firmware;
 
 /// Base address for the TSEC registers.
 pub(crate) const TSEC_BASE: u32 = 0x5450_0000;
@@ -61,6 +83,24 @@ pub(crate) const SOR1_BASE: u32 = 0x5458_0000;
 /// Base address for HOST1X registers.
 pub(crate) const HOST1X_BASE: u32 = 0x5000_0000;
 
+/// The value the Keygen firmware writes to `MAILBOX1` on success.
+const KEYGEN_SUCCESS: u32 = 0xB0B0_B0B0;
+
+/// Enumeration of possible TSEC errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// Loading or running the firmware on the Falcon failed.
+    Falcon(falcon::Error),
+    /// The firmware reported an unexpected result through `MAILBOX1`.
+    UnexpectedResult(u32),
+}
+
+impl From<falcon::Error> for Error {
+    fn from(error: falcon::Error) -> Self {
+        Error::Falcon(error)
+    }
+}
+
 /// Representation of the TSEC registers.
 #[repr(C)]
 pub struct Registers {
@@ -91,153 +131,7 @@ pub struct Registers {
     _reserved20: [Mmio<u8>; 0xD70],
     pub tsec_thi_clk_override: Mmio<u32>, // 0xe00
     _reserved21: [Mmio<u8>; 0x1FC],
-    pub falcon_irqsset: Mmio<u32>,   // 0x1000
-    pub falcon_irqsclr: Mmio<u32>,   // 0x1004
-    pub falcon_irqstat: Mmio<u32>,   // 0x1008
-    pub falcon_irqmode: Mmio<u32>,   // 0x100c
-    pub falcon_irqmset: Mmio<u32>,   // 0x1010
-    pub falcon_irqmclr: Mmio<u32>,   // 0x1014
-    pub falcon_irqmask: Mmio<u32>,   // 0x1018
-    pub falcon_irqdest: Mmio<u32>,   // 0x101c
-    pub falcon_gptmrint: Mmio<u32>,  // 0x1020
-    pub falcon_gptmrval: Mmio<u32>,  // 0x1024
-    pub falcon_gptmrctl: Mmio<u32>,  // 0x1028
-    pub falcon_ptimer0: Mmio<u32>,   // 0x102c
-    pub falcon_ptimer1: Mmio<u32>,   // 0x1030
-    pub falcon_wdtmrval: Mmio<u32>,  // 0x1034
-    pub falcon_wdtmrctl: Mmio<u32>,  // 0x1038
-    pub falcon_irqdest2: Mmio<u32>,  // 0x103c
-    pub falcon_mailbox0: Mmio<u32>,  // 0x1040
-    pub falcon_mailbox1: Mmio<u32>,  // 0x1044
-    pub falcon_itfen: Mmio<u32>,     // 0x1048
-    pub falcon_idlestate: Mmio<u32>, // 0x104c
-    pub falcon_curctx: Mmio<u32>,    // 0x1050
-    pub falcon_nxtctx: Mmio<u32>,    // 0x1054
-    pub falcon_ctxack: Mmio<u32>,    // 0x1058
-    pub falcon_fhstate: Mmio<u32>,   // 0x105c
-    pub falcon_privstate: Mmio<u32>, // 0x1060
-    pub falcon_mthddata: Mmio<u32>,  // 0x1064
-    pub falcon_mthdid: Mmio<u32>,    // 0x1068
-    pub falcon_mthdwdat: Mmio<u32>,  // 0x106c
-    pub falcon_mthdcount: Mmio<u32>, // 0x1070
-    pub falcon_mthdpop: Mmio<u32>,   // 0x1074
-    pub falcon_mthdramsz: Mmio<u32>, // 0x1078
-    pub falcon_sftreset: Mmio<u32>,  // 0x107c
-    pub falcon_os: Mmio<u32>,        // 0x1080
-    pub falcon_rm: Mmio<u32>,        // 0x1084
-    pub falcon_soft_pm: Mmio<u32>,   // 0x1088
-    pub falcon_soft_mode: Mmio<u32>, // 0x108c
-    pub falcon_debug1: Mmio<u32>,    // 0x1090
-    pub falcon_debuginfo: Mmio<u32>, // 0x1094
-    pub falcon_ibrkpt1: Mmio<u32>,   // 0x1098
-    pub falcon_ibrkpt2: Mmio<u32>,   // 0x109c
-    pub falcon_cgctl: Mmio<u32>,     // 0x10a0
-    pub falcon_engctl: Mmio<u32>,    // 0x10a4
-    pub falcon_pmm: Mmio<u32>,       // 0x10a8
-    pub falcon_addr: Mmio<u32>,      // 0x10ac
-    pub falcon_ibrkpt3: Mmio<u32>,   // 0x10b0
-    pub falcon_ibrkpt4: Mmio<u32>,   // 0x10b4
-    pub falcon_ibrkpt5: Mmio<u32>,   // 0x10b8
-    _reserved68: [Mmio<u8>; 0x14],
-    pub falcon_exci: Mmio<u32>,     // 0x10d0
-    pub falcon_svec_spr: Mmio<u32>, // 0x10d4
-    pub falcon_rstat0: Mmio<u32>,   // 0x10d8
-    pub falcon_rstat3: Mmio<u32>,   // 0x10dc
-    pub falcon_unk_e0: Mmio<u32>,   // 0x10e0
-    _reserved73: [Mmio<u8>; 0x1C],
-    pub falcon_cpuctl: Mmio<u32>,       // 0x1100
-    pub falcon_bootvec: Mmio<u32>,      // 0x1104
-    pub falcon_hwcfg: Mmio<u32>,        // 0x1108
-    pub falcon_dmactl: Mmio<u32>,       // 0x110c
-    pub falcon_dmatrfbase: Mmio<u32>,   // 0x1110
-    pub falcon_dmatrfmoffs: Mmio<u32>,  // 0x1114
-    pub falcon_dmatrfcmd: Mmio<u32>,    // 0x1118
-    pub falcon_dmatrffboffs: Mmio<u32>, // 0x111c
-    pub falcon_dmapoll_fb: Mmio<u32>,   // 0x1120
-    pub falcon_dmapoll_cp: Mmio<u32>,   // 0x1124
-    pub falcon_dbg_state: Mmio<u32>,    // 0x1128
-    pub falcon_hwcfg1: Mmio<u32>,       // 0x112c
-    pub falcon_cpuctl_alias: Mmio<u32>, // 0x1130
-    _reserved86: [Mmio<u8>; 0x4],
-    pub falcon_stackcfg: Mmio<u32>, // 0x1138
-    _reserved87: [Mmio<u8>; 0x4],
-    pub falcon_imctl: Mmio<u32>,       // 0x1140
-    pub falcon_imstat: Mmio<u32>,      // 0x1144
-    pub falcon_traceidx: Mmio<u32>,    // 0x1148
-    pub falcon_tracepc: Mmio<u32>,     // 0x114c
-    pub falcon_imfillrng0: Mmio<u32>,  // 0x1150
-    pub falcon_imfillrng1: Mmio<u32>,  // 0x1154
-    pub falcon_imfillctl: Mmio<u32>,   // 0x1158
-    pub falcon_imctl_debug: Mmio<u32>, // 0x115c
-    pub falcon_cmembase: Mmio<u32>,    // 0x1160
-    pub falcon_dmemapert: Mmio<u32>,   // 0x1164
-    pub falcon_exterraddr: Mmio<u32>,  // 0x1168
-    pub falcon_exterrstat: Mmio<u32>,  // 0x116c
-    _reserved99: [Mmio<u8>; 0xC],
-    pub falcon_cg2: Mmio<u32>,    // 0x117c
-    pub falcon_imemc0: Mmio<u32>, // 0x1180
-    pub falcon_imemd0: Mmio<u32>, // 0x1184
-    pub falcon_imemt0: Mmio<u32>, // 0x1188
-    _reserved103: [Mmio<u8>; 0x4],
-    pub falcon_imemc1: Mmio<u32>, // 0x1190
-    pub falcon_imemd1: Mmio<u32>, // 0x1194
-    pub falcon_imemt1: Mmio<u32>, // 0x1198
-    _reserved106: [Mmio<u8>; 0x4],
-    pub falcon_imemc2: Mmio<u32>, // 0x11a0
-    pub falcon_imemd2: Mmio<u32>, // 0x11a4
-    pub falcon_imemt2: Mmio<u32>, // 0x11a8
-    _reserved109: [Mmio<u8>; 0x4],
-    pub falcon_imemc3: Mmio<u32>, // 0x11b0
-    pub falcon_imemd3: Mmio<u32>, // 0x11b4
-    pub falcon_imemt3: Mmio<u32>, // 0x11b8
-    _reserved112: [Mmio<u8>; 0x4],
-    pub falcon_dmemc0: Mmio<u32>,    // 0x11c0
-    pub falcon_dmemd0: Mmio<u32>,    // 0x11c4
-    pub falcon_dmemc1: Mmio<u32>,    // 0x11c8
-    pub falcon_dmemd1: Mmio<u32>,    // 0x11cc
-    pub falcon_dmemc2: Mmio<u32>,    // 0x11d0
-    pub falcon_dmemd2: Mmio<u32>,    // 0x11d4
-    pub falcon_dmemc3: Mmio<u32>,    // 0x11d8
-    pub falcon_dmemd3: Mmio<u32>,    // 0x11dc
-    pub falcon_dmemc4: Mmio<u32>,    // 0x11e0
-    pub falcon_dmemd4: Mmio<u32>,    // 0x11e4
-    pub falcon_dmemc5: Mmio<u32>,    // 0x11e8
-    pub falcon_dmemd5: Mmio<u32>,    // 0x11ec
-    pub falcon_dmemc6: Mmio<u32>,    // 0x11f0
-    pub falcon_dmemd6: Mmio<u32>,    // 0x11f4
-    pub falcon_dmemc7: Mmio<u32>,    // 0x11f8
-    pub falcon_dmemd7: Mmio<u32>,    // 0x11fc
-    pub falcon_icd_cmd: Mmio<u32>,   // 0x1200
-    pub falcon_icd_addr: Mmio<u32>,  // 0x1204
-    pub falcon_icd_wdata: Mmio<u32>, // 0x1208
-    pub falcon_icd_rdata: Mmio<u32>, // 0x120c
-    _reserved132: [Mmio<u8>; 0x30],
-    pub falcon_sctl: Mmio<u32>,    // 0x1240
-    pub falcon_sstat: Mmio<u32>,   // 0x1244
-    pub falcon_unk_248: Mmio<u32>, // 0x1248
-    pub falcon_unk_24c: Mmio<u32>, // 0x124c
-    pub falcon_unk_250: Mmio<u32>, // 0x1250
-    _reserved137: [Mmio<u8>; 0xC],
-    pub falcon_unk_260: Mmio<u32>, // 0x1260
-    _reserved138: [Mmio<u8>; 0x1C],
-    pub falcon_sprot_imem: Mmio<u32>,   // 0x1280
-    pub falcon_sprot_dmem: Mmio<u32>,   // 0x1284
-    pub falcon_sprot_cpuctl: Mmio<u32>, // 0x1288
-    pub falcon_sprot_misc: Mmio<u32>,   // 0x128c
-    pub falcon_sprot_irq: Mmio<u32>,    // 0x1290
-    pub falcon_sprot_mthd: Mmio<u32>,   // 0x1294
-    pub falcon_sprot_sctl: Mmio<u32>,   // 0x1298
-    pub falcon_sprot_wdtmr: Mmio<u32>,  // 0x129c
-    _reserved146: [Mmio<u8>; 0x20],
-    pub falcon_dmainfo_finished_fbrd_low: Mmio<u32>, // 0x12c0
-    pub falcon_dmainfo_finished_fbrd_high: Mmio<u32>, // 0x12c4
-    pub falcon_dmainfo_finished_fbwr_low: Mmio<u32>, // 0x12c8
-    pub falcon_dmainfo_finished_fbwr_high: Mmio<u32>, // 0x12cc
-    pub falcon_dmainfo_current_fbrd_low: Mmio<u32>,  // 0x12d0
-    pub falcon_dmainfo_current_fbrd_high: Mmio<u32>, // 0x12d4
-    pub falcon_dmainfo_current_fbwr_low: Mmio<u32>,  // 0x12d8
-    pub falcon_dmainfo_current_fbwr_high: Mmio<u32>, // 0x12dc
-    pub falcon_dmainfo_ctl: Mmio<u32>,               // 0x12e0
+    pub falcon: FalconRegisters, // 0x1000
     _reserved155: [Mmio<u8>; 0x11C],
     pub tsec_scp_ctl0: Mmio<u32>,     // 0x1400
     pub tsec_scp_ctl1: Mmio<u32>,     // 0x1404
@@ -338,43 +232,13 @@ impl VolatileStorage for Registers {
     }
 }
 
+/// The Falcon that powers the TSEC.
+const FALCON: Falcon = Falcon::TSEC;
+
 /// Representation of the TSEC.
 pub struct Tsec;
 
 impl Tsec {
-    /// Waits until DMA has entered an idle state.
-    fn dma_wait_idle(&self) -> Result<(), ()> {
-        let registers = unsafe { Registers::get() };
-
-        let timeout = get_milliseconds() + 10000;
-
-        while (registers.falcon_dmatrfcmd.read() & (1 << 1)) == 0 {
-            if get_milliseconds() > timeout {
-                return Err(());
-            }
-        }
-
-        Ok(())
-    }
-
-    /// Configures physical DMA transfers to Falcon.
-    fn dma_phys_to_flcn(
-        &self,
-        is_imem: bool,
-        flcn_offset: u32,
-        phys_offset: u32,
-    ) -> Result<(), ()> {
-        let registers = unsafe { Registers::get() };
-
-        let cmd = if is_imem { 0x10 } else { 0x600 };
-
-        registers.falcon_dmatrfmoffs.write(flcn_offset);
-        registers.falcon_dmatrffboffs.write(phys_offset);
-        registers.falcon_dmatrfcmd.write(cmd);
-
-        self.dma_wait_idle()
-    }
-
     /// Creates a new TSEC object.
     pub const fn new() -> Self {
         Tsec
@@ -401,47 +265,16 @@ impl Tsec {
     }
 
     /// Retrieves the TSEC key.
-    pub fn get_key(&self, rev: u32, firmware: &mut [u8]) -> Result<[u32; 4], ()> {
-        let registers = unsafe { Registers::get() };
-
+    ///
+    /// The firmware is passed the fixed [`KEY_VERSION`].
+    ///
+    /// [`KEY_VERSION`]: firmware/constant.KEY_VERSION.html
+    pub fn get_key(&self, firmware: &Firmware<'_>) -> Result<[u32; 4], Error> {
         self.enable_clocks();
 
-        // Configure Falcon.
-        registers.falcon_dmactl.write(0);
-        registers.falcon_irqmset.write(0xFFF2);
-        registers.falcon_irqdest.write(0xFFF0);
-        registers.falcon_itfen.write(3);
-
-        if self.dma_wait_idle().is_err() {
-            self.disable_clocks();
-            return Err(());
-        }
-
-        // Load firmware.
-        if self.load_firmware(firmware).is_err() {
-            self.disable_clocks();
-            return Err(());
-        }
-
-        // Execute firmware.
-        self.execute_firmware(Some(rev));
-
-        if self.dma_wait_idle().is_err() {
-            self.disable_clocks();
-            return Err(());
-        }
-
-        let timeout = get_milliseconds() + 2000;
-        while registers.falcon_mailbox1.read() == 0 {
-            if get_milliseconds() > timeout {
-                self.disable_clocks();
-                return Err(());
-            }
-        }
-
-        if registers.falcon_mailbox1.read() != 0xB0B0_B0B0 {
+        if let Err(err) = self.run_keygen(firmware) {
             self.disable_clocks();
-            return Err(());
+            return Err(err);
         }
 
         // Unknown HOST1X write.
@@ -481,56 +314,35 @@ impl Tsec {
         Ok(key)
     }
 
-    /// Loads the TSEC firmware.
-    pub fn load_firmware(&self, firmware: &[u8]) -> Result<(), ()> {
-        let registers = unsafe { Registers::get() };
-
-        let mut res = Ok(());
-
-        // Configure Falcon.
-        registers.falcon_dmactl.write(0);
-        registers.falcon_irqmset.write(0xFFF2);
-        registers.falcon_irqdest.write(0xFFF0);
-        registers.falcon_itfen.write(3);
-
-        if self.dma_wait_idle().is_ok() {
-            // Load firmware.
-            registers
-                .falcon_dmatrfbase
-                .write(firmware.as_ptr() as usize as u32 >> 8);
-
-            let mut addr = 0;
-            while addr < firmware.len() {
-                if self
-                    .dma_phys_to_flcn(true, addr as u32, addr as u32)
-                    .is_err()
-                {
-                    res = Err(());
-                    break;
-                }
-
-                addr += 0x100;
-            }
-        } else {
-            res = Err(());
+    /// Loads and executes the firmware and waits for the Keygen stage to finish.
+    fn run_keygen(&self, firmware: &Firmware<'_>) -> Result<(), Error> {
+        self.load_firmware(firmware)?;
+        self.execute_firmware(Some(firmware::KEY_VERSION));
+
+        FALCON.dma_wait_idle()?;
+
+        match FALCON.wait_mailbox1(2000)? {
+            KEYGEN_SUCCESS => Ok(()),
+            value => Err(Error::UnexpectedResult(value)),
         }
+    }
 
-        res
+    /// Loads the TSEC firmware.
+    pub fn load_firmware(&self, firmware: &Firmware<'_>) -> Result<(), falcon::Error> {
+        FALCON.init();
+
+        FALCON.load_imem(firmware.data(), 0, false)
     }
 
     /// Executes the loaded TSEC firmware.
     pub fn execute_firmware(&self, rev: Option<u32>) {
-        let registers = unsafe { Registers::get() };
-
         // Unknown HOST1X write.
         unsafe {
             (*((HOST1X_BASE + 0x3300) as *const Mmio<u32>)).write(0x34C2_E1DA);
         }
 
         // Execute the firmware.
-        registers.falcon_mailbox1.write(0);
-        registers.falcon_mailbox0.write(rev.unwrap_or(0));
-        registers.falcon_bootvec.write(0);
-        registers.falcon_cpuctl.write(2);
+        FALCON.set_mailboxes(rev.unwrap_or(0), 0);
+        FALCON.boot(0);
     }
 }